@@ -8,9 +8,20 @@
 #ifdef __cplusplus
 extern "C" {
 #endif // __cplusplus
+    /******** Account ********/
+
+    unsigned char *c_account_xsk(const unsigned char *seed, size_t seed_len, uint32_t coin_type, uint32_t account, size_t *xsk_len);
+    unsigned char *c_account_xfvk(const unsigned char *seed, size_t seed_len, uint32_t coin_type, uint32_t account, size_t *xfvk_len);
+    unsigned char *c_account_default_address(const unsigned char *seed, size_t seed_len, uint32_t coin_type, uint32_t account, size_t *address_len);
+    // Returns the serialized default addresses (54 bytes each) of the accounts `start..start + count` concatenated.
+    unsigned char *c_account_default_addresses(const unsigned char *seed, size_t seed_len, uint32_t coin_type, uint32_t start, size_t count, size_t *addresses_len);
+    unsigned char *c_account_path(uint32_t coin_type, uint32_t account, size_t *path_len);
+    bool c_validate_zip32_path(const char *derivation_path);
+
     /******** Authorizing Key ********/
 
     unsigned char *c_pak_from_xsk(const unsigned char *xsk, size_t xsk_len, size_t *pak_len);
+    unsigned char *c_ask_from_xsk(const unsigned char *xsk, size_t xsk_len, size_t *ask_len);
 
     /******** Commitment ********/
 
@@ -23,6 +34,126 @@ extern "C" {
             size_t *cmu_len
     );
 
+    /******** Frontier ********/
+
+    unsigned char *c_frontier_from_commitments(const unsigned char *commitments, size_t commitments_len, size_t *frontier_len);
+
+    unsigned char *c_frontier_from_rightmost_path(
+            uint64_t size,
+            const unsigned char *leaf,
+            size_t leaf_len,
+            const unsigned char *path,
+            size_t path_len,
+            size_t *frontier_len
+    );
+
+    unsigned char *c_frontier_append(
+            const unsigned char *frontier,
+            size_t frontier_len,
+            const unsigned char *commitments,
+            size_t commitments_len,
+            size_t *new_frontier_len
+    );
+
+    unsigned char *c_frontier_root(const unsigned char *frontier, size_t frontier_len, size_t *root_len);
+
+    bool c_frontier_validate_tezos_state(
+            const unsigned char *frontier,
+            size_t frontier_len,
+            uint64_t size,
+            const unsigned char *root,
+            size_t root_len
+    );
+
+    /******** Frost ********/
+
+    unsigned char *c_frost_dkg_round1(uint16_t identifier, uint16_t min_signers, uint16_t max_signers, size_t *secret_len);
+
+    unsigned char *c_frost_dkg_round1_package(const unsigned char *secret, size_t secret_len, size_t *package_len);
+
+    unsigned char *c_frost_dkg_round2(
+            const unsigned char *secret,
+            size_t secret_len,
+            const unsigned char *round1_packages,
+            size_t round1_packages_len,
+            uint16_t receiver,
+            size_t *package_len
+    );
+
+    unsigned char *c_frost_dkg_round3(
+            const unsigned char *secret,
+            size_t secret_len,
+            const unsigned char *round1_packages,
+            size_t round1_packages_len,
+            const unsigned char *round2_packages,
+            size_t round2_packages_len,
+            size_t *key_package_len
+    );
+
+    unsigned char *c_frost_dkg_public_key_package(
+            const unsigned char *secret,
+            size_t secret_len,
+            const unsigned char *round1_packages,
+            size_t round1_packages_len,
+            size_t *public_key_package_len
+    );
+
+    unsigned char *c_frost_group_ak(const unsigned char *public_key_package, size_t public_key_package_len, size_t *ak_len);
+
+    unsigned char *c_frost_proof_generation_key(
+            const unsigned char *public_key_package,
+            size_t public_key_package_len,
+            const unsigned char *nsk,
+            size_t nsk_len,
+            size_t *pak_len
+    );
+
+    void *c_frost_signing_nonces(const unsigned char *key_package, size_t key_package_len);
+    void c_drop_frost_signing_nonces(void *nonces);
+
+    unsigned char *c_frost_signing_commitments(void *nonces, size_t *commitments_len);
+
+    unsigned char *c_frost_signing_package(
+            const unsigned char *ar,
+            size_t ar_len,
+            const unsigned char *sighash,
+            size_t sighash_len,
+            const unsigned char *commitments,
+            size_t commitments_len,
+            size_t *package_len
+    );
+
+    unsigned char *c_frost_sign(
+            const unsigned char *key_package,
+            size_t key_package_len,
+            void *nonces,
+            const unsigned char *package,
+            size_t package_len,
+            size_t *share_len
+    );
+
+    unsigned char *c_frost_aggregate(
+            const unsigned char *package,
+            size_t package_len,
+            const unsigned char *shares,
+            size_t shares_len,
+            const unsigned char *public_key_package,
+            size_t public_key_package_len,
+            size_t *signature_len
+    );
+
+    unsigned char *c_frost_sign_spend_description(
+            const unsigned char *spend_description,
+            size_t spend_description_len,
+            const unsigned char *package,
+            size_t package_len,
+            const unsigned char *shares,
+            size_t shares_len,
+            const unsigned char *public_key_package,
+            size_t public_key_package_len,
+            size_t *description_len
+    );
+
     /******** Init ********/
 
     bool c_init_params(
@@ -42,6 +173,42 @@ extern "C" {
             size_t *ka_len
     );
 
+    /******** Keystore ********/
+
+    unsigned char *c_keystore_encrypt_xsk(
+            const unsigned char *xsk,
+            size_t xsk_len,
+            const unsigned char *password,
+            size_t password_len,
+            size_t *keystore_len
+    );
+
+    unsigned char *c_keystore_encrypt_seed(
+            const unsigned char *seed,
+            size_t seed_len,
+            const unsigned char *password,
+            size_t password_len,
+            size_t *keystore_len
+    );
+
+    unsigned char *c_keystore_decrypt_xsk(
+            const unsigned char *keystore,
+            size_t keystore_len,
+            const unsigned char *password,
+            size_t password_len,
+            size_t *xsk_len
+    );
+
+    unsigned char *c_keystore_decrypt_seed(
+            const unsigned char *keystore,
+            size_t keystore_len,
+            const unsigned char *password,
+            size_t password_len,
+            size_t *seed_len
+    );
+
+    unsigned char *c_keystore_fingerprint(const unsigned char *keystore, size_t keystore_len, size_t *fingerprint_len);
+
     /******** Merkle Tree ********/
 
     unsigned char *c_merkle_hash(
@@ -53,6 +220,32 @@ extern "C" {
             size_t *merkle_hash_len
     );
 
+    /******** Message Encryption ********/
+
+    unsigned char *c_encrypt_message(
+            const unsigned char *address,
+            size_t address_len,
+            const unsigned char *message,
+            size_t message_len,
+            size_t *encrypted_len
+    );
+
+    unsigned char *c_decrypt_message_with_xfvk(
+            const unsigned char *xfvk,
+            size_t xfvk_len,
+            const unsigned char *encrypted,
+            size_t encrypted_len,
+            size_t *message_len
+    );
+
+    unsigned char *c_decrypt_message_with_ivk(
+            const unsigned char *ivk,
+            size_t ivk_len,
+            const unsigned char *encrypted,
+            size_t encrypted_len,
+            size_t *message_len
+    );
+
     /******** Nullifier ********/
 
     unsigned char *c_compute_nullifier_with_xfvk(
@@ -95,6 +288,38 @@ extern "C" {
             size_t *description_len
     );
 
+    unsigned char *c_output_description_from_xfvk_with_memo_size(
+            void *ctx,
+            const unsigned char *xfvk,
+            size_t xfvk_len,
+            const unsigned char *to,
+            size_t to_len,
+            const unsigned char *rcm,
+            size_t rcm_len,
+            uint64_t value,
+            const unsigned char *memo,
+            size_t memo_len,
+            size_t memo_size,
+            size_t *description_len
+    );
+
+    unsigned char *c_output_description_from_xfvk_with_esk(
+            void *ctx,
+            const unsigned char *xfvk,
+            size_t xfvk_len,
+            const unsigned char *to,
+            size_t to_len,
+            const unsigned char *rcm,
+            size_t rcm_len,
+            const unsigned char *esk,
+            size_t esk_len,
+            uint64_t value,
+            const unsigned char *memo,
+            size_t memo_len,
+            size_t memo_size,
+            size_t *description_len
+    );
+
     unsigned char *c_output_description_from_ovk(
             void *ctx,
             const unsigned char *ovk,
@@ -127,6 +352,54 @@ extern "C" {
             size_t *epk_len
     );
 
+    unsigned char *c_decrypt_output_description_with_xfvk(
+            const unsigned char *xfvk,
+            size_t xfvk_len,
+            const unsigned char *description,
+            size_t description_len,
+            size_t *note_len
+    );
+
+    unsigned char *c_decrypt_output_description_with_ivk(
+            const unsigned char *ivk,
+            size_t ivk_len,
+            const unsigned char *description,
+            size_t description_len,
+            size_t *note_len
+    );
+
+    /******** Memo ********/
+
+    unsigned char *c_empty_memo(
+            size_t memo_size,
+            size_t *memo_len
+    );
+
+    unsigned char *c_text_memo(
+            const char *text,
+            size_t memo_size,
+            size_t *memo_len
+    );
+
+    unsigned char *c_arbitrary_memo(
+            const unsigned char *data,
+            size_t data_len,
+            size_t memo_size,
+            size_t *memo_len
+    );
+
+    unsigned char *c_memo_type(
+            const unsigned char *memo,
+            size_t memo_len,
+            size_t *type_len
+    );
+
+    unsigned char *c_memo_content(
+            const unsigned char *memo,
+            size_t memo_len,
+            size_t *content_len
+    );
+
     /******** Payment Address ********/
 
     unsigned char *c_default_payment_address_from_xfvk(
@@ -151,6 +424,23 @@ extern "C" {
             size_t *address_len
     );
 
+    unsigned char *c_payment_addresses_from_xfvk(
+            const unsigned char *xfvk,
+            size_t xfvk_len,
+            const unsigned char *index,
+            size_t index_len,
+            size_t count,
+            size_t *addresses_len
+    );
+
+    unsigned char *c_diversifier_index_from_xfvk(
+            const unsigned char *xfvk,
+            size_t xfvk_len,
+            const unsigned char *diversifier,
+            size_t diversifier_len,
+            size_t *index_len
+    );
+
     unsigned char *c_payment_address_from_ivk(
             const unsigned char *ivk,
             size_t ivk_len,
@@ -171,6 +461,169 @@ extern "C" {
             size_t *pkd_len
     );
 
+    unsigned char *c_sign_address_ownership(
+            const unsigned char *xsk,
+            size_t xsk_len,
+            const unsigned char *address,
+            size_t address_len,
+            const unsigned char *message,
+            size_t message_len,
+            size_t *proof_len
+    );
+
+    bool c_verify_address_ownership(
+            const unsigned char *address,
+            size_t address_len,
+            const unsigned char *message,
+            size_t message_len,
+            const unsigned char *proof,
+            size_t proof_len
+    );
+
+    /******** Payment Disclosure ********/
+
+    unsigned char *c_payment_disclosure_from_xsk(
+            const unsigned char *xsk,
+            size_t xsk_len,
+            const unsigned char *ar,
+            size_t ar_len,
+            const unsigned char *output_description,
+            size_t output_description_len,
+            const unsigned char *rcv,
+            size_t rcv_len,
+            const unsigned char *message,
+            size_t message_len,
+            size_t *disclosure_len
+    );
+
+    unsigned char *c_verify_payment_disclosure(
+            const unsigned char *disclosure,
+            size_t disclosure_len,
+            const unsigned char *output_description,
+            size_t output_description_len,
+            const unsigned char *spend_rks,
+            size_t spend_rks_len,
+            size_t *note_len
+    );
+
+    /******** Payment URI ********/
+
+    void *c_init_payment_request();
+    void c_drop_payment_request(void *request);
+
+    bool c_payment_request_add_payment(
+            void *request,
+            const unsigned char *address,
+            size_t address_len,
+            const uint64_t *amount,
+            const unsigned char *memo,
+            size_t memo_len,
+            const char *label,
+            const char *message
+    );
+
+    unsigned char *c_payment_request_to_uri(
+            void *request,
+            size_t *uri_len
+    );
+
+    unsigned char *c_parse_payment_uri(
+            const char *uri,
+            size_t *json_len
+    );
+
+    unsigned char *c_encode_payment_address(
+            const unsigned char *address,
+            size_t address_len,
+            size_t *encoded_len
+    );
+
+    unsigned char *c_decode_payment_address(
+            const char *encoded,
+            size_t *address_len
+    );
+
+    /******** Proving Builder ********/
+
+    typedef void (*proving_progress_callback)(size_t completed, size_t total, void *user_data);
+
+    void *c_init_proving_builder();
+    void c_drop_proving_builder(void *builder);
+
+    bool c_proving_builder_add_spend(
+            void *builder,
+            const unsigned char *pak,
+            size_t pak_len,
+            const unsigned char *address,
+            size_t address_len,
+            const unsigned char *rcm,
+            size_t rcm_len,
+            const unsigned char *ar,
+            size_t ar_len,
+            uint64_t value,
+            const unsigned char *anchor,
+            size_t anchor_len,
+            const unsigned char *merkle_path,
+            size_t merkle_path_len
+    );
+
+    bool c_proving_builder_add_output(
+            void *builder,
+            const unsigned char *ovk,
+            size_t ovk_len,
+            const unsigned char *address,
+            size_t address_len,
+            const unsigned char *rcm,
+            size_t rcm_len,
+            uint64_t value,
+            const unsigned char *esk,
+            size_t esk_len,
+            const unsigned char *memo,
+            size_t memo_len
+    );
+
+    void *c_proving_builder_prove(
+            void *builder,
+            proving_progress_callback on_progress,
+            void *user_data,
+            void *cancellation_token
+    );
+
+    // requires the `parallel_proving` feature, proves on the rayon thread pool and may call `on_progress` from its threads,
+    // like `c_proving_builder_prove` the builder keeps its inputs
+    void *c_proving_builder_prove_parallel(
+            void *builder,
+            proving_progress_callback on_progress,
+            void *user_data,
+            void *cancellation_token
+    );
+
+    void c_drop_proved_descriptions(void *proved);
+
+    void *c_init_cancellation_token();
+    void c_cancel_cancellation_token(void *cancellation_token);
+    void c_drop_cancellation_token(void *cancellation_token);
+
+    unsigned char *c_proved_descriptions_spend_description(
+            void *proved,
+            size_t index,
+            size_t *description_len
+    );
+
+    unsigned char *c_proved_descriptions_output_description(
+            void *proved,
+            size_t index,
+            size_t *description_len
+    );
+
+    unsigned char *c_proved_descriptions_binding_signature(
+            void *proved,
+            int64_t value_balance,
+            const unsigned char *sighash,
+            size_t sighash_len,
+            size_t *signature_len
+    );
+
     /******** Proving Context ********/
 
     void *c_init_proving_context();
@@ -180,6 +633,70 @@ extern "C" {
 
     unsigned char *c_rand_r(size_t *r_len);
 
+    unsigned char *c_derive_rcm(
+            const unsigned char *xsk,
+            size_t xsk_len,
+            const unsigned char *context,
+            size_t context_len,
+            uint32_t index,
+            size_t *rcm_len
+    );
+
+    unsigned char *c_derive_esk(
+            const unsigned char *xsk,
+            size_t xsk_len,
+            const unsigned char *context,
+            size_t context_len,
+            uint32_t index,
+            size_t *esk_len
+    );
+
+    unsigned char *c_derive_ar(
+            const unsigned char *xsk,
+            size_t xsk_len,
+            const unsigned char *context,
+            size_t context_len,
+            uint32_t index,
+            size_t *ar_len
+    );
+
+    /******** Sapling State ********/
+
+    unsigned char *c_sapling_state_empty(uint16_t memo_size, size_t *state_len);
+
+    unsigned char *c_sapling_state_root(const unsigned char *state, size_t state_len, size_t *root_len);
+
+    unsigned char *c_sapling_verify_update(
+            const unsigned char *state,
+            size_t state_len,
+            const unsigned char *transaction,
+            size_t transaction_len,
+            const unsigned char *anti_replay,
+            size_t anti_replay_len,
+            size_t *new_state_len
+    );
+
+    /******** Sapling Transaction ********/
+
+    unsigned char *c_inspect_sapling_transaction(
+            const unsigned char *transaction,
+            size_t transaction_len,
+            size_t *json_len
+    );
+
+    unsigned char *c_shielded_pool_parameters(
+            const unsigned char *transaction,
+            size_t transaction_len,
+            const char *unshield_to,
+            size_t *parameters_len
+    );
+
+    unsigned char *c_inspect_shielded_pool_parameters(
+            const unsigned char *parameters,
+            size_t parameters_len,
+            size_t *json_len
+    );
+
     /******** Signature ********/
 
     unsigned char *c_binding_signature(
@@ -190,6 +707,14 @@ extern "C" {
             size_t *signature_len
     );
 
+    unsigned char *c_binding_signature_with_bsk(
+            const unsigned char *bsk,
+            size_t bsk_len,
+            const unsigned char *sighash,
+            size_t sighash_len,
+            size_t *signature_len
+    );
+
     /******** Spend Description ********/
 
     unsigned char *c_spend_description_from_xsk(
@@ -240,16 +765,69 @@ extern "C" {
             size_t *description_len
     );
 
+    unsigned char *c_sign_spend_description_with_ask(
+            const unsigned char *spend_description,
+            size_t spend_description_len,
+            const unsigned char *ask,
+            size_t ask_len,
+            const unsigned char *ar,
+            size_t ar_len,
+            const unsigned char *sighash,
+            size_t sighash_len,
+            size_t *description_len
+    );
+
+    typedef bool (*spend_auth_sign_callback)(const unsigned char *ar, const unsigned char *sighash, unsigned char *signature, void *user_data);
+
+    unsigned char *c_sign_spend_description_with_signer(
+            const unsigned char *spend_description,
+            size_t spend_description_len,
+            spend_auth_sign_callback sign,
+            void *user_data,
+            const unsigned char *ar,
+            size_t ar_len,
+            const unsigned char *sighash,
+            size_t sighash_len,
+            size_t *description_len
+    );
+
     /******** Spending Key ********/
 
     unsigned char *c_xsk(const unsigned char *seed, size_t seed_len, const char *derivation_path, size_t *xsk_len);
 
+    /******** Value Commitment ********/
+
+    unsigned char *c_compute_value_commitment(uint64_t value, const unsigned char *rcv, size_t rcv_len, size_t *cv_len);
+
+    unsigned char *c_sum_value_commitments(const unsigned char *cvs, size_t cvs_len, size_t *cv_len);
+
+    unsigned char *c_sum_trapdoors(const unsigned char *rcvs, size_t rcvs_len, size_t *rcv_len);
+
+    unsigned char *c_compute_bsk(
+            const unsigned char *spend_rcvs,
+            size_t spend_rcvs_len,
+            const unsigned char *output_rcvs,
+            size_t output_rcvs_len,
+            size_t *bsk_len
+    );
+
+    unsigned char *c_compute_bvk(
+            const unsigned char *spend_cvs,
+            size_t spend_cvs_len,
+            const unsigned char *output_cvs,
+            size_t output_cvs_len,
+            int64_t value_balance,
+            size_t *bvk_len
+    );
+
     /******** Viewing Key ********/
 
     unsigned char *c_xfvk(const unsigned char *seed, size_t seed_len, const char *derivation_path, size_t *xfvk_len);
     unsigned char *c_xfvk_from_xsk(const unsigned char *xsk, size_t xsk_len, size_t *xfvk_len);
     unsigned char *c_ovk_from_xfvk(const unsigned char *xfvk, size_t xfvk_len, size_t *ovk_len);
     unsigned char *c_xfvk_to_ivk(const unsigned char *xfvk, size_t xfvk_len, size_t *ivk_len);
+    unsigned char *c_ivk_to_bech32(const unsigned char *ivk, size_t ivk_len, size_t *encoded_len);
+    unsigned char *c_ivk_from_bech32(const char *encoded, size_t *ivk_len);
 #ifdef __cplusplus
 };
 #endif // __cplusplus
@@ -5,7 +5,11 @@ use crate::common::errors::DetailedError;
 #[derive(Debug)]
 pub enum SaplingAddressError {
     DiversifierSpaceExhausted,
+    InvalidDiversifier,
+    InvalidDiversifierKey,
+    TooManyAddresses(usize, usize),
     InvalidAddressLength(usize),
+    InvalidEncoding(String, String),
     SerializationFailed(io::Error),
 }
 
@@ -15,7 +19,11 @@ impl DetailedError for SaplingAddressError {
 
         match self {
             DiversifierSpaceExhausted => String::from("diversifier space is exhausted"),
+            InvalidDiversifier => String::from("diversifier is invalid"),
+            InvalidDiversifierKey => String::from("diversifier key is invalid"),
+            TooManyAddresses(count, max) => format!("cannot generate {} addresses at once, the maximum is {}", count, max),
             InvalidAddressLength(len) => format!("invalid address length, expected 43, got {}", len),
+            InvalidEncoding(address, cause) => format!("invalid address {}: {}", address, cause),
             SerializationFailed(err) => err.to_string(),
         }
     }
@@ -27,7 +35,11 @@ impl PartialEq for SaplingAddressError {
 
         match (self, other) {
             (DiversifierSpaceExhausted, DiversifierSpaceExhausted) => true,
+            (InvalidDiversifier, InvalidDiversifier) => true,
+            (InvalidDiversifierKey, InvalidDiversifierKey) => true,
+            (TooManyAddresses(count, max), TooManyAddresses(other_count, other_max)) => count == other_count && max == other_max,
             (InvalidAddressLength(size), InvalidAddressLength(other_size)) => size == other_size,
+            (InvalidEncoding(address, cause), InvalidEncoding(other_address, other_cause)) => address == other_address && cause == other_cause,
             (SerializationFailed(err), SerializationFailed(other_err)) => err.to_string() == other_err.to_string(),
             _ => false
         }
@@ -45,7 +57,7 @@ impl DetailedError for IndexedAddressError {
         use IndexedAddressError::*;
 
         match self {
-            InvalidAddressLength(len) => format!("invalid address length, expected 43, got {}", len),
+            InvalidAddressLength(len) => format!("invalid address length, expected 54, got {}", len),
             SerializationFailed(err) => err.to_string(),
         }
     }
@@ -61,4 +73,24 @@ impl PartialEq for IndexedAddressError {
             _ => false
         }
     }
-}
\ No newline at end of file
+}
+#[derive(Debug, PartialEq)]
+pub enum OwnershipProofError {
+    AddressNotOwned,
+    UnsupportedVersion(u8),
+    InvalidIvkSignature,
+    InvalidSpendAuthSig,
+}
+
+impl DetailedError for OwnershipProofError {
+    fn details(&self) -> String {
+        use OwnershipProofError::*;
+
+        match self {
+            AddressNotOwned => String::from("address does not belong to the spending key"),
+            UnsupportedVersion(version) => format!("unsupported ownership proof version {}", version),
+            InvalidIvkSignature => String::from("invalid ownership proof, the address signature does not match"),
+            InvalidSpendAuthSig => String::from("invalid ownership proof, the spend authorization signature does not match"),
+        }
+    }
+}
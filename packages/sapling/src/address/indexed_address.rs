@@ -1,4 +1,3 @@
-use std::convert::TryInto;
 use std::io::Write;
 
 use zcash_primitives::primitives::PaymentAddress;
@@ -8,6 +7,7 @@ use crate::address::errors::IndexedAddressError;
 use crate::common::errors::{CausedBy, SaplingError};
 use crate::common::traits::Serializable;
 use crate::common::utils::assert_utils::assert_value_or_error;
+use crate::common::utils::deserialize_utils::to_array;
 
 #[derive(Debug, PartialEq)]
 pub struct IndexedAddress(pub [u8; 11], pub PaymentAddress);
@@ -22,7 +22,7 @@ impl Serializable<Vec<u8>, SaplingError> for IndexedAddress {
     fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
         assert_byte_length(&serialized).map_err(SaplingError::caused_by)?;
 
-        let diversifier_index = serialized[..11].try_into().unwrap();
+        let diversifier_index = to_array::<11>("diversifier index", &serialized[..11]).map_err(SaplingError::caused_by)?;
         let address = PaymentAddress::deserialize(serialized[11..].to_vec())?;
 
         Ok(IndexedAddress(diversifier_index, address))
@@ -44,5 +44,34 @@ impl Serializable<Vec<u8>, SaplingError> for IndexedAddress {
 
 fn assert_byte_length(bytes: &[u8]) -> Result<(), IndexedAddressError> {
     let len = bytes.len();
-    assert_value_or_error(len == 43, IndexedAddressError::InvalidAddressLength(len))
-}
\ No newline at end of file
+    assert_value_or_error(len == 54, IndexedAddressError::InvalidAddressLength(len))
+}
+#[cfg(test)]
+mod tests {
+    use zcash_primitives::zip32::ExtendedSpendingKey;
+
+    use crate::common::errors::DeserializationError;
+    use crate::key::SaplingKey;
+
+    use super::*;
+
+    #[test]
+    fn serializes_and_deserializes_indexed_address() {
+        let xsk = ExtendedSpendingKey::from_seed(&[1u8; 32], "m/").unwrap();
+        let (index, address) = xsk.default_address().unwrap();
+        let indexed_address = IndexedAddress::new(index, address);
+
+        let serialized = indexed_address.serialize().unwrap();
+        assert_eq!(serialized.len(), 54);
+        assert_eq!(IndexedAddress::deserialize(serialized).unwrap(), indexed_address);
+
+        assert_eq!(
+            IndexedAddress::deserialize(vec![0u8; 43]).unwrap_err(),
+            SaplingError::caused_by(IndexedAddressError::InvalidAddressLength(43))
+        );
+        assert_eq!(
+            IndexedAddress::deserialize(vec![0u8; 54]).unwrap_err(),
+            SaplingError::caused_by(DeserializationError::InvalidEncoding("payment address"))
+        );
+    }
+}
@@ -1,13 +1,22 @@
 pub use errors::SaplingAddressError;
+pub use indexed_address::IndexedAddress;
 pub use ivk_address::get_ivk_address;
+pub use ownership_proof::{OwnershipProof, sign_address_ownership, verify_address_ownership};
+pub use payment_uri::{Payment, PaymentRequest};
+pub use tezos_address::{decode_payment_address, encode_payment_address};
 pub use xfvk_address::{
     get_next_xfvk_address,
     get_xfvk_address,
+    get_xfvk_addresses,
+    get_xfvk_diversifier_index,
 };
 
 mod payment_address;
 mod indexed_address;
 mod ivk_address;
+mod ownership_proof;
+mod payment_uri;
+mod tezos_address;
 mod xfvk_address;
 mod errors;
 
@@ -0,0 +1,186 @@
+use std::convert::TryInto;
+
+use blake2b_simd::Params as Blake2bParams;
+use group::GroupEncoding;
+use rand_core::OsRng;
+use zcash_primitives::constants::SPENDING_KEY_GENERATOR;
+use zcash_primitives::primitives::PaymentAddress;
+use zcash_primitives::redjubjub::{PrivateKey, PublicKey, Signature};
+use zcash_primitives::zip32::{ExtendedFullViewingKey, ExtendedSpendingKey};
+
+use crate::address::errors::OwnershipProofError;
+use crate::common::errors::{CausedBy, DeserializationError, SaplingError};
+use crate::common::traits::Serializable;
+use crate::common::utils::assert_utils::assert_value_or_error;
+use crate::transaction::{create_spend_sig, rand_scalar, verify_spend_auth_sig};
+
+const OWNERSHIP_PROOF_PERSONALIZATION: &[u8; 16] = b"AirGapSaplingOwn";
+const OWNERSHIP_PROOF_VERSION: u8 = 0x01;
+const OWNERSHIP_PROOF_SIZE: usize = 1 + // version
+    32 + // rk
+    64 + // ivk signature
+    64; // spend authorization signature
+
+// Signs a message for a payment address with two RedJubjub signatures over the same digest:
+// one with `ivk` on the diversified base `g_d`, verified against `pk_d`, which only the owner of the address can create,
+// and one with a randomized spend authorizing key, verified against `rk`, like the signature of a spend description.
+// `rk` can't be linked to `ak`, so the second signature shows that the signer can authorize spends,
+// but not that its key belongs to the address, that would take a Spend proof.
+// Neither `ivk` nor `(ak, nk)` is revealed, the verifier can't decrypt notes or compute nullifiers of the account.
+pub struct OwnershipProof {
+    pub rk: PublicKey,
+    pub ivk_sig: Signature,
+    pub spend_auth_sig: Signature,
+}
+
+pub fn sign_address_ownership(xsk: &ExtendedSpendingKey, address: &PaymentAddress, message: &[u8]) -> Result<OwnershipProof, SaplingError> {
+    let xfvk = ExtendedFullViewingKey::from(xsk);
+    let owned_address = xfvk.fvk.vk.to_payment_address(*address.diversifier());
+    assert_value_or_error(owned_address.as_ref() == Some(address), OwnershipProofError::AddressNotOwned).map_err(SaplingError::caused_by)?;
+
+    let ar = rand_scalar();
+    let rk = PublicKey(xfvk.fvk.vk.ak.into()).randomize(ar, SPENDING_KEY_GENERATOR);
+    let digest = ownership_digest(address, &rk, message);
+
+    let g_d = address.g_d().ok_or(OwnershipProofError::AddressNotOwned).map_err(SaplingError::caused_by)?;
+    let ivk_sig = PrivateKey(xfvk.fvk.vk.ivk().0).sign(&ivk_sig_message(address, digest), &mut OsRng, g_d);
+    let spend_auth_sig = create_spend_sig(xsk, ar, digest)?;
+
+    Ok(OwnershipProof { rk, ivk_sig, spend_auth_sig })
+}
+
+pub fn verify_address_ownership(address: &PaymentAddress, message: &[u8], proof: &OwnershipProof) -> Result<(), SaplingError> {
+    let digest = ownership_digest(address, &proof.rk, message);
+
+    let g_d = address.g_d().ok_or(OwnershipProofError::InvalidIvkSignature).map_err(SaplingError::caused_by)?;
+    let pk_d = PublicKey((*address.pk_d()).into());
+    assert_value_or_error(pk_d.verify(&ivk_sig_message(address, digest), &proof.ivk_sig, g_d), OwnershipProofError::InvalidIvkSignature)
+        .map_err(SaplingError::caused_by)?;
+
+    assert_value_or_error(verify_spend_auth_sig(&proof.rk, digest, &proof.spend_auth_sig), OwnershipProofError::InvalidSpendAuthSig)
+        .map_err(SaplingError::caused_by)
+}
+
+// BLAKE2b-256("AirGapSaplingOwn", address || rk || message)
+fn ownership_digest(address: &PaymentAddress, rk: &PublicKey, message: &[u8]) -> [u8; 32] {
+    let hash = Blake2bParams::new()
+        .hash_length(32)
+        .personal(OWNERSHIP_PROOF_PERSONALIZATION)
+        .to_state()
+        .update(&address.to_bytes())
+        .update(&rk.0.to_bytes())
+        .update(message)
+        .finalize();
+
+    hash.as_bytes().try_into().unwrap()
+}
+
+// Like the spend authorization signature, the ivk signature is bound to its public key, `pk_d || digest`.
+fn ivk_sig_message(address: &PaymentAddress, digest: [u8; 32]) -> [u8; 64] {
+    let mut message = [0u8; 64];
+    message[..32].copy_from_slice(&address.pk_d().to_bytes());
+    message[32..].copy_from_slice(&digest);
+
+    message
+}
+
+// version (1 byte) || rk (32 bytes) || ivk signature (64 bytes) || spend authorization signature (64 bytes)
+impl Serializable<Vec<u8>, SaplingError> for OwnershipProof {
+    fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
+        assert_value_or_error(
+            serialized.len() == OWNERSHIP_PROOF_SIZE,
+            DeserializationError::InvalidLength("ownership proof", OWNERSHIP_PROOF_SIZE, serialized.len()),
+        ).map_err(SaplingError::caused_by)?;
+        assert_value_or_error(serialized[0] == OWNERSHIP_PROOF_VERSION, OwnershipProofError::UnsupportedVersion(serialized[0]))
+            .map_err(SaplingError::caused_by)?;
+
+        let rk = jubjub::ExtendedPoint::deserialize(serialized[1..33].to_vec())?;
+        let ivk_sig = Signature::deserialize(serialized[33..97].to_vec())?;
+        let spend_auth_sig = Signature::deserialize(serialized[97..161].to_vec())?;
+
+        Ok(OwnershipProof { rk: PublicKey(rk), ivk_sig, spend_auth_sig })
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
+        let rk = self.rk.0.serialize()?;
+        let ivk_sig = self.ivk_sig.serialize()?;
+        let spend_auth_sig = self.spend_auth_sig.serialize()?;
+
+        Ok([vec![OWNERSHIP_PROOF_VERSION], rk, ivk_sig, spend_auth_sig].concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::key::SaplingKey;
+
+    use super::*;
+
+    fn prepare_keys() -> (ExtendedSpendingKey, PaymentAddress) {
+        let xsk = ExtendedSpendingKey::from_seed(&[0u8; 32], "m/").unwrap();
+        let (_, address) = ExtendedFullViewingKey::from(&xsk).default_address().unwrap();
+
+        (xsk, address)
+    }
+
+    #[test]
+    fn signs_and_verifies_address_ownership() {
+        let (xsk, address) = prepare_keys();
+
+        let proof = sign_address_ownership(&xsk, &address, b"deposit 1234").unwrap();
+        let serialized = proof.serialize().unwrap();
+        assert_eq!(serialized.len(), OWNERSHIP_PROOF_SIZE);
+
+        let vk = ExtendedFullViewingKey::from(&xsk).fvk.vk;
+        for secret in [vk.ak.to_bytes(), vk.nk.to_bytes(), vk.ivk().to_repr()].iter() {
+            assert!(!serialized.windows(32).any(|window| window == secret));
+        }
+
+        let proof = OwnershipProof::deserialize(serialized).unwrap();
+        assert_eq!(verify_address_ownership(&address, b"deposit 1234", &proof), Ok(()));
+        assert_eq!(
+            verify_address_ownership(&address, b"deposit 1235", &proof).err(),
+            Some(SaplingError::caused_by(OwnershipProofError::InvalidIvkSignature)),
+        );
+    }
+
+    #[test]
+    fn rejects_address_of_other_key() {
+        let (xsk, address) = prepare_keys();
+        let (other_xsk, other_address) = {
+            let xsk = ExtendedSpendingKey::from_seed(&[1u8; 32], "m/").unwrap();
+            let (_, address) = ExtendedFullViewingKey::from(&xsk).default_address().unwrap();
+            (xsk, address)
+        };
+
+        let actual = sign_address_ownership(&xsk, &other_address, b"message");
+        assert_eq!(actual.err(), Some(SaplingError::caused_by(OwnershipProofError::AddressNotOwned)));
+
+        let proof = sign_address_ownership(&other_xsk, &other_address, b"message").unwrap();
+        assert_eq!(
+            verify_address_ownership(&address, b"message", &proof).err(),
+            Some(SaplingError::caused_by(OwnershipProofError::InvalidIvkSignature)),
+        );
+    }
+
+    #[test]
+    fn rejects_mixed_signatures() {
+        let (xsk, address) = prepare_keys();
+
+        let proof = sign_address_ownership(&xsk, &address, b"message").unwrap();
+        let mut serialized = proof.serialize().unwrap();
+        let other_proof = sign_address_ownership(&xsk, &address, b"message").unwrap();
+        let mixed = OwnershipProof { rk: proof.rk, ivk_sig: proof.ivk_sig, spend_auth_sig: other_proof.spend_auth_sig };
+
+        assert_eq!(
+            verify_address_ownership(&address, b"message", &mixed).err(),
+            Some(SaplingError::caused_by(OwnershipProofError::InvalidSpendAuthSig)),
+        );
+
+        serialized[0] = 0x02;
+        assert_eq!(
+            OwnershipProof::deserialize(serialized).err(),
+            Some(SaplingError::caused_by(OwnershipProofError::UnsupportedVersion(0x02))),
+        );
+    }
+}
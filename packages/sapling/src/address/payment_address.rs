@@ -1,6 +1,6 @@
 use zcash_primitives::primitives::PaymentAddress;
 
-use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::errors::{CausedBy, DeserializationError, SaplingError};
 use crate::common::traits::Serializable;
 use crate::common::utils::assert_utils::assert_value_or_error;
 
@@ -13,7 +13,7 @@ impl Serializable<Vec<u8>, SaplingError> for PaymentAddress {
         let mut bytes = [0u8; 43];
         bytes.copy_from_slice(&serialized[..]);
 
-        PaymentAddress::from_bytes(&bytes).ok_or_else(SaplingError::new)
+        PaymentAddress::from_bytes(&bytes).ok_or_else(|| SaplingError::caused_by(DeserializationError::InvalidEncoding("payment address")))
     }
 
     fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
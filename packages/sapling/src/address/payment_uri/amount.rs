@@ -0,0 +1,76 @@
+use crate::address::payment_uri::errors::PaymentUriError;
+
+const MUTEZ_DECIMALS: usize = 6;
+const MUTEZ_PER_TEZ: u64 = 1_000_000;
+
+// Tezos amounts are signed 64-bit integers.
+pub(super) const MAX_MUTEZ: u64 = i64::MAX as u64;
+
+// Parses a tez amount with at most 6 decimal places into mutez, e.g. `1.5` -> `1500000`.
+pub(super) fn parse_amount(amount: &str) -> Result<u64, PaymentUriError> {
+    let invalid_amount = || PaymentUriError::InvalidAmount(amount.to_string());
+
+    let (integer, fraction) = match amount.split_once('.') {
+        Some((integer, fraction)) if !fraction.is_empty() && fraction.len() <= MUTEZ_DECIMALS => (integer, fraction),
+        Some(_) => return Err(invalid_amount()),
+        None => (amount, ""),
+    };
+
+    let is_digits = |value: &str| value.bytes().all(|byte| byte.is_ascii_digit());
+    if integer.is_empty() || !is_digits(integer) || !is_digits(fraction) {
+        return Err(invalid_amount());
+    }
+
+    let integer: u64 = integer.parse().map_err(|_| invalid_amount())?;
+    let fraction: u64 = format!("{:0<width$}", fraction, width = MUTEZ_DECIMALS).parse().map_err(|_| invalid_amount())?;
+
+    integer.checked_mul(MUTEZ_PER_TEZ)
+        .and_then(|mutez| mutez.checked_add(fraction))
+        .filter(|mutez| *mutez <= MAX_MUTEZ)
+        .ok_or_else(invalid_amount)
+}
+
+pub(super) fn format_amount(mutez: u64) -> String {
+    let integer = mutez / MUTEZ_PER_TEZ;
+    let fraction = mutez % MUTEZ_PER_TEZ;
+
+    if fraction == 0 {
+        integer.to_string()
+    } else {
+        let fraction = format!("{:0>width$}", fraction, width = MUTEZ_DECIMALS);
+        format!("{}.{}", integer, fraction.trim_end_matches('0'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_formats_amount() {
+        let test_data = [
+            ("0", 0u64),
+            ("1", 1_000_000),
+            ("1.5", 1_500_000),
+            ("0.000001", 1),
+            ("123.456789", 123_456_789),
+            ("9223372036854.775807", MAX_MUTEZ),
+        ];
+
+        for (amount, mutez) in test_data.iter() {
+            assert_eq!(parse_amount(amount).unwrap(), *mutez);
+            assert_eq!(&format_amount(*mutez), amount);
+        }
+
+        assert_eq!(parse_amount("1.500").unwrap(), 1_500_000);
+    }
+
+    #[test]
+    fn fails_to_parse_invalid_amount() {
+        let test_data = ["", ".5", "1.", "1.0000001", "-1", "+1", "1e6", "1,5", " 1", "9223372036854.775808", "18446744073709.551615", "99999999999999999999"];
+
+        for amount in test_data.iter() {
+            assert_eq!(parse_amount(amount), Err(PaymentUriError::InvalidAmount(amount.to_string())));
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use std::str;
+
+use crate::address::payment_uri::errors::PaymentUriError;
+use crate::common::utils::hex_utils::from_hex;
+
+// `qchar` of [ZIP-321](https://zips.z.cash/zip-0321) without `pct-encoded`
+fn is_qchar(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || b"-._~!$'()*+,;:@".contains(&byte)
+}
+
+pub(super) fn percent_encode(value: &str) -> String {
+    value.bytes()
+        .map(|byte| if is_qchar(byte) { (byte as char).to_string() } else { format!("%{:02X}", byte) })
+        .collect()
+}
+
+pub(super) fn percent_decode(value: &str) -> Result<String, PaymentUriError> {
+    let invalid_encoding = || PaymentUriError::InvalidEncoding(value.to_string());
+
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut position = 0;
+    while position < bytes.len() {
+        match bytes[position] {
+            b'%' => {
+                let hex = bytes.get(position + 1..position + 3).ok_or_else(invalid_encoding)?;
+                let hex = str::from_utf8(hex).map_err(|_| invalid_encoding())?;
+                decoded.extend(from_hex(hex).ok_or_else(invalid_encoding)?);
+                position += 3;
+            },
+            byte if is_qchar(byte) => {
+                decoded.push(byte);
+                position += 1;
+            },
+            _ => return Err(invalid_encoding()),
+        }
+    }
+
+    String::from_utf8(decoded).map_err(|_| invalid_encoding())
+}
+
+pub(super) fn base64_encode(bytes: &[u8]) -> String {
+    base64::encode_config(bytes, base64::URL_SAFE_NO_PAD)
+}
+
+pub(super) fn base64_decode(value: &str) -> Result<Vec<u8>, PaymentUriError> {
+    base64::decode_config(value, base64::URL_SAFE_NO_PAD).map_err(|err| PaymentUriError::InvalidMemo(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percent_encodes_and_decodes() {
+        let test_data = [
+            ("Coffee", "Coffee"),
+            ("Thank you!", "Thank%20you!"),
+            ("a&b=c?d#e", "a%26b%3Dc%3Fd%23e"),
+            ("50%", "50%25"),
+            ("Café", "Caf%C3%A9"),
+        ];
+
+        for (value, encoded) in test_data.iter() {
+            assert_eq!(&percent_encode(value), encoded);
+            assert_eq!(&percent_decode(encoded).unwrap(), value);
+        }
+
+        for invalid in ["%", "%2", "%zz", "%+1", "%-1", "a b", "a&b", "%C3"].iter() {
+            assert!(percent_decode(invalid).is_err());
+        }
+    }
+}
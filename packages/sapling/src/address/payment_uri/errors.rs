@@ -0,0 +1,32 @@
+use crate::common::errors::DetailedError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaymentUriError {
+    InvalidScheme,
+    InvalidParameter(String),
+    InvalidAmount(String),
+    InvalidMemo(String),
+    InvalidEncoding(String),
+    DuplicateParameter(String),
+    UnsupportedRequirement(String),
+    MissingAddress(usize),
+    NoPayments,
+}
+
+impl DetailedError for PaymentUriError {
+    fn details(&self) -> String {
+        use PaymentUriError::*;
+
+        match self {
+            InvalidScheme => format!("Payment URI must start with `{}:`", super::request::SCHEME),
+            InvalidParameter(parameter) => format!("Invalid payment URI parameter `{}`", parameter),
+            InvalidAmount(amount) => format!("Invalid amount `{}`", amount),
+            InvalidMemo(cause) => format!("Invalid memo: {}", cause),
+            InvalidEncoding(value) => format!("Invalid percent-encoding of `{}`", value),
+            DuplicateParameter(parameter) => format!("Duplicate payment URI parameter `{}`", parameter),
+            UnsupportedRequirement(parameter) => format!("Unsupported required parameter `{}`", parameter),
+            MissingAddress(index) => format!("Payment {} has no address", index),
+            NoPayments => String::from("Payment URI contains no payments"),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+pub use request::{Payment, PaymentRequest};
+
+mod request;
+
+mod amount;
+mod encoding;
+
+mod errors;
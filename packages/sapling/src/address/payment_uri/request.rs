@@ -0,0 +1,314 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use zcash_primitives::primitives::PaymentAddress;
+
+use crate::address::payment_uri::amount::{format_amount, MAX_MUTEZ, parse_amount};
+use crate::address::payment_uri::encoding::{base64_decode, base64_encode, percent_decode, percent_encode};
+use crate::address::payment_uri::errors::PaymentUriError;
+use crate::address::tezos_address::{decode_payment_address, encode_payment_address};
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::transaction::Memo;
+
+pub(super) const SCHEME: &str = "tezos-sapling";
+
+const MAX_PARAMETER_INDEX: usize = 9999;
+
+// The amount is in mutez, the memo is not padded to the memo size.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Payment {
+    pub address: PaymentAddress,
+    pub amount: Option<u64>,
+    pub memo: Option<Vec<u8>>,
+    pub label: Option<String>,
+    pub message: Option<String>,
+}
+
+impl Payment {
+    pub fn new(address: PaymentAddress) -> Payment {
+        Payment { address, amount: None, memo: None, label: None, message: None }
+    }
+}
+
+// Modelled on [ZIP-321](https://zips.z.cash/zip-0321): `tezos-sapling:<zet1 address>?amount=1.5&memo=<base64url>&label=...`,
+// further payments are described by parameters with an index suffix, e.g. `address.1=...&amount.1=...`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PaymentRequest {
+    pub payments: Vec<Payment>,
+}
+
+impl PaymentRequest {
+    pub fn new() -> PaymentRequest {
+        PaymentRequest::default()
+    }
+
+    pub fn add_payment(&mut self, payment: Payment) -> Result<(), SaplingError> {
+        if let Some(memo) = &payment.memo {
+            validate_memo(memo).map_err(SaplingError::caused_by)?;
+        }
+        if let Some(amount) = payment.amount.filter(|amount| *amount > MAX_MUTEZ) {
+            return Err(SaplingError::caused_by(PaymentUriError::InvalidAmount(format_amount(amount))));
+        }
+        if self.payments.len() > MAX_PARAMETER_INDEX {
+            return Err(SaplingError::caused_by(PaymentUriError::InvalidParameter(format!("address.{}", self.payments.len()))));
+        }
+
+        self.payments.push(payment);
+
+        Ok(())
+    }
+
+    // A URI without payments can't be parsed, so a request needs at least one payment to be encoded.
+    pub fn to_uri(&self) -> Result<String, SaplingError> {
+        if self.payments.is_empty() {
+            return Err(SaplingError::caused_by(PaymentUriError::NoPayments));
+        }
+
+        let mut uri = format!("{}:", SCHEME);
+        let mut parameters: Vec<String> = vec![];
+        for (index, payment) in self.payments.iter().enumerate() {
+            let suffix = if index == 0 { String::new() } else { format!(".{}", index) };
+
+            if index == 0 {
+                uri.push_str(&encode_payment_address(&payment.address));
+            } else {
+                parameters.push(format!("address{}={}", suffix, encode_payment_address(&payment.address)));
+            }
+            if let Some(amount) = payment.amount {
+                parameters.push(format!("amount{}={}", suffix, format_amount(amount)));
+            }
+            if let Some(memo) = &payment.memo {
+                parameters.push(format!("memo{}={}", suffix, base64_encode(memo)));
+            }
+            if let Some(label) = &payment.label {
+                parameters.push(format!("label{}={}", suffix, percent_encode(label)));
+            }
+            if let Some(message) = &payment.message {
+                parameters.push(format!("message{}={}", suffix, percent_encode(message)));
+            }
+        }
+
+        if !parameters.is_empty() {
+            uri.push('?');
+            uri.push_str(&parameters.join("&"));
+        }
+
+        Ok(uri)
+    }
+}
+
+#[derive(Default)]
+struct PaymentParameters {
+    address: Option<PaymentAddress>,
+    amount: Option<u64>,
+    memo: Option<Vec<u8>>,
+    label: Option<String>,
+    message: Option<String>,
+}
+
+impl FromStr for PaymentRequest {
+    type Err = SaplingError;
+
+    fn from_str(uri: &str) -> Result<Self, Self::Err> {
+        parse_uri(uri).map_err(SaplingError::caused_by)
+    }
+}
+
+fn parse_uri(uri: &str) -> Result<PaymentRequest, PaymentUriError> {
+    let prefix_len = SCHEME.len() + 1;
+    let rest = match uri.get(..prefix_len) {
+        Some(prefix) if prefix.eq_ignore_ascii_case(&format!("{}:", SCHEME)) => &uri[prefix_len..],
+        _ => return Err(PaymentUriError::InvalidScheme),
+    };
+    let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+
+    let mut payments: BTreeMap<usize, PaymentParameters> = BTreeMap::new();
+    if !path.is_empty() {
+        payments.entry(0).or_default().address = Some(parse_address(path)?);
+    }
+
+    let parameters = if query.is_empty() { vec![] } else { query.split('&').collect() };
+    for parameter in parameters {
+        let invalid_parameter = || PaymentUriError::InvalidParameter(parameter.to_string());
+
+        let (name, value) = parameter.split_once('=').ok_or_else(invalid_parameter)?;
+        let (key, index) = match name.split_once('.') {
+            Some((key, index)) => (key, parse_index(index).ok_or_else(invalid_parameter)?),
+            None => (name, 0),
+        };
+
+        match key {
+            "address" | "amount" | "memo" | "label" | "message" => {},
+            key if key.starts_with("req-") => return Err(PaymentUriError::UnsupportedRequirement(name.to_string())),
+            _ => continue,
+        }
+
+        let payment = payments.entry(index).or_default();
+        let duplicate = || PaymentUriError::DuplicateParameter(name.to_string());
+        match key {
+            "address" => set_once(&mut payment.address, parse_address(value)?).ok_or_else(duplicate)?,
+            "amount" => set_once(&mut payment.amount, parse_amount(value)?).ok_or_else(duplicate)?,
+            "memo" => set_once(&mut payment.memo, parse_memo(value)?).ok_or_else(duplicate)?,
+            "label" => set_once(&mut payment.label, percent_decode(value)?).ok_or_else(duplicate)?,
+            _ => set_once(&mut payment.message, percent_decode(value)?).ok_or_else(duplicate)?,
+        }
+    }
+
+    if payments.is_empty() {
+        return Err(PaymentUriError::NoPayments);
+    }
+
+    let payments = payments.into_iter()
+        .map(|(index, parameters)| Ok(Payment {
+            address: parameters.address.ok_or(PaymentUriError::MissingAddress(index))?,
+            amount: parameters.amount,
+            memo: parameters.memo,
+            label: parameters.label,
+            message: parameters.message,
+        }))
+        .collect::<Result<Vec<Payment>, PaymentUriError>>()?;
+
+    Ok(PaymentRequest { payments })
+}
+
+fn parse_address(address: &str) -> Result<PaymentAddress, PaymentUriError> {
+    decode_payment_address(address).map_err(|err| PaymentUriError::InvalidParameter(format!("address={} ({})", address, err)))
+}
+
+fn parse_memo(memo: &str) -> Result<Vec<u8>, PaymentUriError> {
+    let memo = base64_decode(memo)?;
+    validate_memo(&memo)?;
+
+    Ok(memo)
+}
+
+fn validate_memo(memo: &[u8]) -> Result<(), PaymentUriError> {
+    Memo::decode(memo).map(|_| ()).map_err(|err| PaymentUriError::InvalidMemo(err.to_string()))
+}
+
+// `1*4DIGIT` without leading zeros, `0` is reserved for the unindexed parameters
+fn parse_index(index: &str) -> Option<usize> {
+    if index.is_empty() || index.len() > 4 || index.starts_with('0') || !index.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    index.parse().ok()
+}
+
+fn set_once<T>(field: &mut Option<T>, value: T) -> Option<()> {
+    match field {
+        Some(_) => None,
+        None => {
+            *field = Some(value);
+            Some(())
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use zcash_primitives::zip32::ExtendedFullViewingKey;
+
+    use crate::address::get_xfvk_addresses;
+    use crate::key::SaplingKey;
+
+    use super::*;
+
+    fn addresses() -> (PaymentAddress, PaymentAddress) {
+        let xfvk = ExtendedFullViewingKey::from_seed(&[0u8; 32], "m/").unwrap();
+        let addresses = get_xfvk_addresses(&xfvk, None, 2).unwrap();
+
+        (addresses[0].1.clone(), addresses[1].1.clone())
+    }
+
+    #[test]
+    fn formats_and_parses_payment_request() {
+        let (first, second) = addresses();
+
+        let mut request = PaymentRequest::new();
+        request.add_payment(Payment {
+            address: first.clone(),
+            amount: Some(1_500_000),
+            memo: Some(b"Thank you".to_vec()),
+            label: Some(String::from("Coffee & cake")),
+            message: None,
+        }).unwrap();
+        request.add_payment(Payment { amount: Some(1), ..Payment::new(second.clone()) }).unwrap();
+
+        let uri = request.to_uri().unwrap();
+        assert_eq!(uri, format!(
+            "tezos-sapling:{}?amount=1.5&memo=VGhhbmsgeW91&label=Coffee%20%26%20cake&address.1={}&amount.1=0.000001",
+            encode_payment_address(&first),
+            encode_payment_address(&second),
+        ));
+        assert_eq!(uri.parse::<PaymentRequest>().unwrap(), request);
+
+        let single = PaymentRequest { payments: vec![Payment::new(first.clone())] };
+        assert_eq!(single.to_uri().unwrap(), format!("tezos-sapling:{}", encode_payment_address(&first)));
+        assert_eq!(single.to_uri().unwrap().parse::<PaymentRequest>().unwrap(), single);
+    }
+
+    #[test]
+    fn fails_to_format_request_that_does_not_parse() {
+        let (first, _) = addresses();
+
+        let empty = PaymentRequest::new();
+        assert_eq!(empty.to_uri().unwrap_err(), SaplingError::caused_by(PaymentUriError::NoPayments));
+        assert_eq!("tezos-sapling:".parse::<PaymentRequest>().unwrap_err(), SaplingError::caused_by(PaymentUriError::NoPayments));
+
+        let max = PaymentRequest { payments: vec![Payment { amount: Some(MAX_MUTEZ), ..Payment::new(first.clone()) }] };
+        assert_eq!(max.to_uri().unwrap().parse::<PaymentRequest>().unwrap(), max);
+
+        let mut request = PaymentRequest::new();
+        let actual = request.add_payment(Payment { amount: Some(MAX_MUTEZ + 1), ..Payment::new(first) });
+        assert_eq!(actual.unwrap_err(), SaplingError::caused_by(PaymentUriError::InvalidAmount(String::from("9223372036854.775808"))));
+    }
+
+    #[test]
+    fn parses_payment_request_with_indexed_parameters_only() {
+        let (first, second) = addresses();
+        let uri = format!(
+            "TEZOS-SAPLING:?address.2={}&amount.2=2&address.1={}&message.1=Invoice%201&other=ignored",
+            encode_payment_address(&second),
+            encode_payment_address(&first),
+        );
+
+        let request: PaymentRequest = uri.parse().unwrap();
+
+        assert_eq!(request.payments.len(), 2);
+        assert_eq!(request.payments[0].address, first);
+        assert_eq!(request.payments[0].message, Some(String::from("Invoice 1")));
+        assert_eq!(request.payments[1].address, second);
+        assert_eq!(request.payments[1].amount, Some(2_000_000));
+    }
+
+    #[test]
+    fn fails_to_parse_invalid_payment_request() {
+        let (first, _) = addresses();
+        let address = encode_payment_address(&first);
+
+        let test_data = [
+            (String::from("tezos:tz1burnburnburnburnburnburnburjAYjjX"), PaymentUriError::InvalidScheme),
+            (String::from("tezos-sapling:"), PaymentUriError::NoPayments),
+            (format!("tezos-sapling:{}?amount=1&amount=2", address), PaymentUriError::DuplicateParameter(String::from("amount"))),
+            (format!("tezos-sapling:{}?address={}", address, address), PaymentUriError::DuplicateParameter(String::from("address"))),
+            (format!("tezos-sapling:{}?amount=1.0000001", address), PaymentUriError::InvalidAmount(String::from("1.0000001"))),
+            (format!("tezos-sapling:{}?amount.1=1", address), PaymentUriError::MissingAddress(1)),
+            (format!("tezos-sapling:{}?address.01={}", address, address), PaymentUriError::InvalidParameter(format!("address.01={}", address))),
+            (format!("tezos-sapling:{}?amount", address), PaymentUriError::InvalidParameter(String::from("amount"))),
+            (format!("tezos-sapling:{}?req-fee=1", address), PaymentUriError::UnsupportedRequirement(String::from("req-fee"))),
+            (format!("tezos-sapling:{}?label=a%2", address), PaymentUriError::InvalidEncoding(String::from("a%2"))),
+        ];
+
+        for (uri, expected) in test_data.iter() {
+            assert_eq!(uri.parse::<PaymentRequest>().unwrap_err(), SaplingError::caused_by(expected.clone()), "{}", uri);
+        }
+
+        let invalid_memo = format!("tezos-sapling:{}?memo={}", address, base64_encode(&[0xC3, 0x28]));
+        assert!(invalid_memo.parse::<PaymentRequest>().is_err());
+
+        let invalid_address = format!("tezos-sapling:{}x", address);
+        assert!(invalid_address.parse::<PaymentRequest>().is_err());
+    }
+}
@@ -0,0 +1,64 @@
+use zcash_primitives::primitives::PaymentAddress;
+
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::traits::Serializable;
+
+use super::errors::SaplingAddressError;
+
+// `zet1` prefix of the Tezos [`sapling_address`](https://gitlab.com/tezos/tezos/-/blob/master/src/lib_crypto/base58.ml) encoding
+const ZET1_PREFIX: [u8; 4] = [18, 71, 40, 223];
+
+pub fn encode_payment_address(address: &PaymentAddress) -> String {
+    let mut bytes = ZET1_PREFIX.to_vec();
+    bytes.extend_from_slice(&address.to_bytes());
+
+    bs58::encode(bytes).with_check().into_string()
+}
+
+pub fn decode_payment_address(encoded: &str) -> Result<PaymentAddress, SaplingError> {
+    let invalid_address = |cause: String| SaplingError::caused_by(SaplingAddressError::InvalidEncoding(encoded.to_string(), cause));
+
+    let bytes = bs58::decode(encoded).with_check(None).into_vec().map_err(|err| invalid_address(err.to_string()))?;
+    match bytes.strip_prefix(&ZET1_PREFIX[..]) {
+        Some(address) => PaymentAddress::deserialize(address.to_vec()).map_err(|_| invalid_address(String::from("invalid address bytes"))),
+        None => Err(invalid_address(String::from("invalid prefix"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zcash_primitives::zip32::ExtendedFullViewingKey;
+
+    use crate::key::SaplingKey;
+
+    use super::*;
+
+    #[test]
+    fn encodes_and_decodes_payment_address() {
+        let xfvk = ExtendedFullViewingKey::from_seed(&[0u8; 32], "m/").unwrap();
+        let (_, address) = xfvk.default_address().unwrap();
+
+        let encoded = encode_payment_address(&address);
+        assert!(encoded.starts_with("zet1"));
+        assert_eq!(encoded.len(), 69);
+
+        assert_eq!(decode_payment_address(&encoded).unwrap(), address);
+    }
+
+    #[test]
+    fn fails_to_decode_invalid_payment_address() {
+        let xfvk = ExtendedFullViewingKey::from_seed(&[0u8; 32], "m/").unwrap();
+        let (_, address) = xfvk.default_address().unwrap();
+        let encoded = encode_payment_address(&address);
+
+        let mut tampered = encoded.clone().into_bytes();
+        tampered[10] = if tampered[10] == b'a' { b'b' } else { b'a' };
+        let tampered = String::from_utf8(tampered).unwrap();
+
+        let key_hash = "tz1burnburnburnburnburnburnburjAYjjX";
+
+        for invalid in [tampered.as_str(), key_hash, "", "zet1"].iter() {
+            assert!(decode_payment_address(invalid).is_err());
+        }
+    }
+}
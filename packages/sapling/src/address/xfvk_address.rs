@@ -1,10 +1,20 @@
+use std::convert::TryInto;
+
+use aes::Aes256;
+use fpe::ff1::{BinaryNumeralString, FF1};
+use zcash_primitives::primitives::Diversifier;
 use zcash_primitives::zip32::{DiversifierIndex, ExtendedFullViewingKey};
 
 use crate::address::indexed_address::IndexedAddress;
 use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::traits::Serializable;
+use crate::common::utils::assert_utils::assert_value_or_error;
 
 use super::errors::SaplingAddressError;
 
+// The count comes from the bindings, every address needs a few diversifier trials.
+const MAX_ADDRESS_COUNT: usize = 1000;
+
 pub fn get_xfvk_address(
     xfvk: &ExtendedFullViewingKey,
     index: Option<[u8; 11]>
@@ -27,6 +37,48 @@ pub fn get_next_xfvk_address(
     get_xfvk_address(xfvk, Some(index.0))
 }
 
+// Returns `count` consecutive valid addresses, the first one at or after `index`.
+pub fn get_xfvk_addresses(
+    xfvk: &ExtendedFullViewingKey,
+    index: Option<[u8; 11]>,
+    count: usize
+) -> Result<Vec<IndexedAddress>, SaplingError> {
+    assert_value_or_error(count <= MAX_ADDRESS_COUNT, SaplingAddressError::TooManyAddresses(count, MAX_ADDRESS_COUNT))
+        .map_err(SaplingError::caused_by)?;
+
+    let mut addresses: Vec<IndexedAddress> = vec![];
+    for _ in 0..count {
+        let address = match addresses.last() {
+            Some(previous) => get_next_xfvk_address(xfvk, previous.0)?,
+            None => get_xfvk_address(xfvk, index)?,
+        };
+        addresses.push(address);
+    }
+
+    Ok(addresses)
+}
+
+// Based on [`DiversifierKey::diversifier`](https://github.com/zcash/librustzcash/blob/master/zcash_primitives/src/zip32.rs#L146),
+// the diversifier is the FF1-AES256 encryption of the index under the diversifier key, so decryption reverses it.
+pub fn get_xfvk_diversifier_index(
+    xfvk: &ExtendedFullViewingKey,
+    diversifier: &Diversifier
+) -> Result<[u8; 11], SaplingError> {
+    if diversifier.g_d().is_none() {
+        return Err(SaplingError::caused_by(SaplingAddressError::InvalidDiversifier));
+    }
+
+    // `dk` is not exposed by `ExtendedFullViewingKey`, it's the last 32 bytes of its encoding
+    let xfvk = xfvk.serialize()?;
+    let dk = &xfvk[xfvk.len() - 32..];
+
+    let ff = FF1::<Aes256>::new(dk, 2).map_err(|_| SaplingError::caused_by(SaplingAddressError::InvalidDiversifierKey))?;
+    let index = ff.decrypt(&[], &BinaryNumeralString::from_bytes_le(&diversifier.0))
+        .map_err(|_| SaplingError::caused_by(SaplingAddressError::InvalidDiversifier))?;
+
+    Ok(index.to_bytes_le().try_into().unwrap())
+}
+
 #[cfg(test)]
 mod tests {
     use group::Group;
@@ -130,4 +182,38 @@ mod tests {
 
         assert_eq!(error, SaplingError::caused_by(SaplingAddressError::DiversifierSpaceExhausted))
     }
+
+    #[test]
+    fn gets_consecutive_addresses_from_extended_full_viewing_key() {
+        let xfvk = ExtendedFullViewingKey::from_seed(&SEED, "m/1/2h/3").unwrap();
+
+        let addresses = get_xfvk_addresses(&xfvk, None, 5).unwrap();
+
+        assert_eq!(addresses.len(), 5);
+        assert_eq!(addresses[0], get_xfvk_address(&xfvk, None).unwrap());
+        for window in addresses.windows(2) {
+            assert_eq!(window[1], get_next_xfvk_address(&xfvk, window[0].0).unwrap());
+        }
+
+        assert!(get_xfvk_addresses(&xfvk, None, 0).unwrap().is_empty());
+        assert_eq!(
+            get_xfvk_addresses(&xfvk, None, usize::MAX).unwrap_err(),
+            SaplingError::caused_by(SaplingAddressError::TooManyAddresses(usize::MAX, MAX_ADDRESS_COUNT)),
+        );
+    }
+
+    #[test]
+    fn gets_diversifier_index_from_extended_full_viewing_key() {
+        let xfvk = ExtendedFullViewingKey::from_seed(&SEED, "m/1/2h/3").unwrap();
+
+        for address in get_xfvk_addresses(&xfvk, Some([7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]), 3).unwrap() {
+            let index = get_xfvk_diversifier_index(&xfvk, address.1.diversifier()).unwrap();
+            assert_eq!(index, address.0);
+        }
+
+        let invalid = (0u8..).map(|byte| Diversifier([byte; 11])).find(|diversifier| diversifier.g_d().is_none()).unwrap();
+        let error = get_xfvk_diversifier_index(&xfvk, &invalid).unwrap_err();
+
+        assert_eq!(error, SaplingError::caused_by(SaplingAddressError::InvalidDiversifier));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,14 @@
+use std::env;
+use std::process;
+
+use airgap_sapling::cli::{run, USAGE};
+
+fn main() {
+    match run(env::args().skip(1)) {
+        Ok(output) => println!("{}", output),
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, USAGE);
+            process::exit(1);
+        }
+    }
+}
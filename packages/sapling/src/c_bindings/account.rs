@@ -0,0 +1,110 @@
+use std::convert::TryFrom;
+
+use libc::{c_char, c_uchar, size_t};
+
+use crate::c_init_lib;
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::traits::Serializable;
+use crate::common::utils::c_utils::{c_bool_catch_result, c_deserialize_slice, c_deserialize_str, c_get_result_res, c_ptr_catch_result, c_serialize, c_serialize_res};
+use crate::key::{Account, account_path, CoinType, get_accounts, split_bip32_path, validate_zip32_path};
+
+#[no_mangle]
+pub extern "C" fn c_account_xsk(
+    seed: *const c_uchar,
+    seed_len: size_t,
+    coin_type: u32,
+    account: u32,
+    xsk_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let seed: &[u8] = unsafe { c_deserialize_slice(seed, seed_len) };
+        let account = Account::from_seed(seed, CoinType::try_from(coin_type).map_err(SaplingError::caused_by)?, account)?;
+
+        unsafe { c_serialize(account.xsk, xsk_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_account_xfvk(
+    seed: *const c_uchar,
+    seed_len: size_t,
+    coin_type: u32,
+    account: u32,
+    xfvk_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let seed: &[u8] = unsafe { c_deserialize_slice(seed, seed_len) };
+        let account = Account::from_seed(seed, CoinType::try_from(coin_type).map_err(SaplingError::caused_by)?, account)?;
+
+        unsafe { c_serialize(account.xfvk(), xfvk_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_account_default_address(
+    seed: *const c_uchar,
+    seed_len: size_t,
+    coin_type: u32,
+    account: u32,
+    address_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let seed: &[u8] = unsafe { c_deserialize_slice(seed, seed_len) };
+        let account = Account::from_seed(seed, CoinType::try_from(coin_type).map_err(SaplingError::caused_by)?, account)?;
+
+        unsafe { c_serialize_res(account.default_address(), address_len) }
+    })
+}
+
+// Returns the serialized default addresses (54 bytes each) of the accounts `start..start + count` concatenated.
+#[no_mangle]
+pub extern "C" fn c_account_default_addresses(
+    seed: *const c_uchar,
+    seed_len: size_t,
+    coin_type: u32,
+    start: u32,
+    count: size_t,
+    addresses_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let seed: &[u8] = unsafe { c_deserialize_slice(seed, seed_len) };
+        let accounts = get_accounts(seed, CoinType::try_from(coin_type).map_err(SaplingError::caused_by)?, start, count)?;
+        let addresses = accounts.iter()
+            .map(|account| account.default_address()?.serialize())
+            .collect::<Result<Vec<Vec<u8>>, SaplingError>>()?
+            .concat();
+
+        unsafe { c_get_result_res::<SaplingError>(addresses, addresses_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_account_path(coin_type: u32, account: u32, path_len: *mut size_t) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let path = account_path(CoinType::try_from(coin_type).map_err(SaplingError::caused_by)?, account);
+
+        unsafe { c_get_result_res::<SaplingError>(path.into_bytes(), path_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_validate_zip32_path(derivation_path: *const c_char) -> bool {
+    c_init_lib();
+
+    c_bool_catch_result(|| {
+        let derivation_path: &str = unsafe { c_deserialize_str(derivation_path) };
+        let path = split_bip32_path(derivation_path)?;
+
+        validate_zip32_path(&path).map_err(SaplingError::caused_by)
+    })
+}
@@ -13,4 +13,15 @@ pub extern "C" fn c_pak_from_xsk(xsk: *const c_uchar, xsk_len: size_t, pak_len:
 
         unsafe { c_serialize(pak, pak_len) }
     })
+}
+
+#[no_mangle]
+pub extern "C" fn c_ask_from_xsk(xsk: *const c_uchar, xsk_len: size_t, ask_len: *mut size_t) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let xsk: ExtendedSpendingKey = unsafe { c_deserialize(xsk, xsk_len) }?;
+
+        unsafe { c_serialize(xsk.expsk.ask, ask_len) }
+    })
 }
\ No newline at end of file
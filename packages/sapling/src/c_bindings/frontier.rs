@@ -0,0 +1,85 @@
+use libc::{c_uchar, size_t};
+
+use crate::c_init_lib;
+use crate::common::errors::SaplingError;
+use crate::common::utils::c_utils::{c_bool_catch_result, c_deserialize, c_deserialize_slice, c_get_result_res, c_ptr_catch_result, c_serialize, c_serialize_res};
+use crate::transaction::{CommitmentTreeFrontier, node_bytes, node_from_bytes, nodes_from_bytes};
+
+#[no_mangle]
+pub extern "C" fn c_frontier_from_commitments(commitments: *const c_uchar, commitments_len: size_t, frontier_len: *mut size_t) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let commitments = nodes_from_bytes(unsafe { c_deserialize_slice(commitments, commitments_len) })?;
+
+        unsafe { c_serialize_res(CommitmentTreeFrontier::from_commitments(&commitments), frontier_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_frontier_from_rightmost_path(
+    size: u64,
+    leaf: *const c_uchar,
+    leaf_len: size_t,
+    path: *const c_uchar,
+    path_len: size_t,
+    frontier_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let leaf = node_from_bytes(unsafe { c_deserialize_slice(leaf, leaf_len) })?;
+        let path = nodes_from_bytes(unsafe { c_deserialize_slice(path, path_len) })?;
+
+        unsafe { c_serialize_res(CommitmentTreeFrontier::from_rightmost_path(size, leaf, &path), frontier_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_frontier_append(
+    frontier: *const c_uchar,
+    frontier_len: size_t,
+    commitments: *const c_uchar,
+    commitments_len: size_t,
+    new_frontier_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let mut frontier: CommitmentTreeFrontier = unsafe { c_deserialize(frontier, frontier_len) }?;
+        let commitments = nodes_from_bytes(unsafe { c_deserialize_slice(commitments, commitments_len) })?;
+
+        commitments.into_iter().try_for_each(|commitment| frontier.append(commitment))?;
+
+        unsafe { c_serialize(frontier, new_frontier_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_frontier_root(frontier: *const c_uchar, frontier_len: size_t, root_len: *mut size_t) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let frontier: CommitmentTreeFrontier = unsafe { c_deserialize(frontier, frontier_len) }?;
+
+        unsafe { c_get_result_res::<SaplingError>(node_bytes(&frontier.root()).to_vec(), root_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_frontier_validate_tezos_state(
+    frontier: *const c_uchar,
+    frontier_len: size_t,
+    size: u64,
+    root: *const c_uchar,
+    root_len: size_t,
+) -> bool {
+    c_init_lib();
+
+    c_bool_catch_result(|| {
+        let frontier: CommitmentTreeFrontier = unsafe { c_deserialize(frontier, frontier_len) }?;
+        let root = node_from_bytes(unsafe { c_deserialize_slice(root, root_len) })?;
+
+        frontier.validate(size, root)
+    })
+}
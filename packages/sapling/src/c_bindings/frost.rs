@@ -0,0 +1,271 @@
+use std::convert::TryInto;
+
+use libc::{c_uchar, size_t};
+
+use crate::c_init_lib;
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::utils::c_utils::{c_dereference, c_deserialize, c_deserialize_slice, c_drop_reference, c_ptr_catch_result, c_ref_catch_result, c_reference, c_serialize, c_serialize_res, c_take_reference};
+use crate::key::{
+    dkg_public_key_package,
+    dkg_round1,
+    dkg_round2,
+    dkg_round3,
+    DkgRound1Secret,
+    FrostError,
+    FrostSpendAuthSigner,
+    frost_aggregate,
+    frost_sign,
+    KeyPackage,
+    PublicKeyPackage,
+    read_frost_messages,
+    signing_nonces,
+    SigningNonces,
+    SigningPackage,
+};
+use crate::transaction::{sign_spend_description, UnsignedSpendDescription};
+
+#[no_mangle]
+pub extern "C" fn c_frost_dkg_round1(identifier: u16, min_signers: u16, max_signers: u16, secret_len: *mut size_t) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let secret = dkg_round1(identifier, min_signers, max_signers);
+
+        unsafe { c_serialize_res(secret, secret_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_frost_dkg_round1_package(secret: *const c_uchar, secret_len: size_t, package_len: *mut size_t) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let secret: DkgRound1Secret = unsafe { c_deserialize(secret, secret_len) }?;
+
+        unsafe { c_serialize(secret.package(), package_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_frost_dkg_round2(
+    secret: *const c_uchar,
+    secret_len: size_t,
+    round1_packages: *const c_uchar,
+    round1_packages_len: size_t,
+    receiver: u16,
+    package_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let secret: DkgRound1Secret = unsafe { c_deserialize(secret, secret_len) }?;
+        let round1_packages = read_frost_messages(unsafe { c_deserialize_slice(round1_packages, round1_packages_len) })?;
+
+        let package = dkg_round2(&secret, &round1_packages)?
+            .into_iter()
+            .find(|package| package.receiver() == receiver)
+            .ok_or(FrostError::InvalidIdentifier(receiver))
+            .map_err(SaplingError::caused_by)?;
+
+        unsafe { c_serialize(package, package_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_frost_dkg_round3(
+    secret: *const c_uchar,
+    secret_len: size_t,
+    round1_packages: *const c_uchar,
+    round1_packages_len: size_t,
+    round2_packages: *const c_uchar,
+    round2_packages_len: size_t,
+    key_package_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let secret: DkgRound1Secret = unsafe { c_deserialize(secret, secret_len) }?;
+        let round1_packages = read_frost_messages(unsafe { c_deserialize_slice(round1_packages, round1_packages_len) })?;
+        let round2_packages = read_frost_messages(unsafe { c_deserialize_slice(round2_packages, round2_packages_len) })?;
+
+        let key_package = dkg_round3(&secret, &round1_packages, &round2_packages);
+
+        unsafe { c_serialize_res(key_package, key_package_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_frost_dkg_public_key_package(
+    secret: *const c_uchar,
+    secret_len: size_t,
+    round1_packages: *const c_uchar,
+    round1_packages_len: size_t,
+    public_key_package_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let secret: DkgRound1Secret = unsafe { c_deserialize(secret, secret_len) }?;
+        let round1_packages = read_frost_messages(unsafe { c_deserialize_slice(round1_packages, round1_packages_len) })?;
+
+        let public_key_package = dkg_public_key_package(&secret, &round1_packages);
+
+        unsafe { c_serialize_res(public_key_package, public_key_package_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_frost_group_ak(public_key_package: *const c_uchar, public_key_package_len: size_t, ak_len: *mut size_t) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let public_key_package: PublicKeyPackage = unsafe { c_deserialize(public_key_package, public_key_package_len) }?;
+
+        unsafe { c_serialize(public_key_package.ak(), ak_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_frost_proof_generation_key(
+    public_key_package: *const c_uchar,
+    public_key_package_len: size_t,
+    nsk: *const c_uchar,
+    nsk_len: size_t,
+    pak_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let public_key_package: PublicKeyPackage = unsafe { c_deserialize(public_key_package, public_key_package_len) }?;
+        let nsk: jubjub::Scalar = unsafe { c_deserialize(nsk, nsk_len) }?;
+
+        unsafe { c_serialize(public_key_package.proof_generation_key(nsk), pak_len) }
+    })
+}
+
+// The nonces are freed by `c_frost_sign`, `c_drop_frost_signing_nonces` is only needed if signing is abandoned.
+#[no_mangle]
+pub extern "C" fn c_frost_signing_nonces(key_package: *const c_uchar, key_package_len: size_t) -> *mut SigningNonces {
+    c_init_lib();
+
+    c_ref_catch_result(|| {
+        let key_package: Result<KeyPackage, SaplingError> = unsafe { c_deserialize(key_package, key_package_len) };
+
+        key_package.map(|key_package| c_reference(signing_nonces(&key_package)))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_drop_frost_signing_nonces(nonces: *mut SigningNonces) {
+    c_init_lib();
+    unsafe { c_drop_reference::<SigningNonces>(nonces) }
+}
+
+#[no_mangle]
+pub extern "C" fn c_frost_signing_commitments(nonces: *mut SigningNonces, commitments_len: *mut size_t) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let nonces: &mut SigningNonces = unsafe { c_dereference(nonces) };
+
+        unsafe { c_serialize(nonces.commitments(), commitments_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_frost_signing_package(
+    ar: *const c_uchar,
+    ar_len: size_t,
+    sighash: *const c_uchar,
+    sighash_len: size_t,
+    commitments: *const c_uchar,
+    commitments_len: size_t,
+    package_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let ar: jubjub::Scalar = unsafe { c_deserialize(ar, ar_len) }?;
+        let sighash: [u8; 32] = unsafe { c_deserialize_slice(sighash, sighash_len) }.try_into()
+            .map_err(|_| SaplingError::caused_by("frostSigningPackage: sighash must be an array of 32 bytes"))?;
+        let commitments = read_frost_messages(unsafe { c_deserialize_slice(commitments, commitments_len) })?;
+
+        let package = SigningPackage::new(ar, sighash, commitments);
+
+        unsafe { c_serialize_res(package, package_len) }
+    })
+}
+
+// Frees `nonces`, whether signing succeeds or not.
+#[no_mangle]
+pub extern "C" fn c_frost_sign(
+    key_package: *const c_uchar,
+    key_package_len: size_t,
+    nonces: *mut SigningNonces,
+    package: *const c_uchar,
+    package_len: size_t,
+    share_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    let nonces: SigningNonces = unsafe { c_take_reference(nonces) };
+    c_ptr_catch_result(|| {
+        let key_package: KeyPackage = unsafe { c_deserialize(key_package, key_package_len) }?;
+        let package: SigningPackage = unsafe { c_deserialize(package, package_len) }?;
+
+        let share = frost_sign(&key_package, nonces, &package);
+
+        unsafe { c_serialize_res(share, share_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_frost_aggregate(
+    package: *const c_uchar,
+    package_len: size_t,
+    shares: *const c_uchar,
+    shares_len: size_t,
+    public_key_package: *const c_uchar,
+    public_key_package_len: size_t,
+    signature_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let package: SigningPackage = unsafe { c_deserialize(package, package_len) }?;
+        let shares = read_frost_messages(unsafe { c_deserialize_slice(shares, shares_len) })?;
+        let public_key_package: PublicKeyPackage = unsafe { c_deserialize(public_key_package, public_key_package_len) }?;
+
+        let signature = frost_aggregate(&package, &shares, &public_key_package);
+
+        unsafe { c_serialize_res(signature, signature_len) }
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub extern "C" fn c_frost_sign_spend_description(
+    spend_description: *const c_uchar,
+    spend_description_len: size_t,
+    package: *const c_uchar,
+    package_len: size_t,
+    shares: *const c_uchar,
+    shares_len: size_t,
+    public_key_package: *const c_uchar,
+    public_key_package_len: size_t,
+    description_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let spend_description: UnsignedSpendDescription = unsafe { c_deserialize(spend_description, spend_description_len) }?;
+        let package: SigningPackage = unsafe { c_deserialize(package, package_len) }?;
+        let shares = read_frost_messages(unsafe { c_deserialize_slice(shares, shares_len) })?;
+        let public_key_package: PublicKeyPackage = unsafe { c_deserialize(public_key_package, public_key_package_len) }?;
+
+        let signer = FrostSpendAuthSigner::new(&package, &shares, &public_key_package);
+        let spend_description = sign_spend_description(spend_description, &signer, package.ar(), package.sighash());
+
+        unsafe { c_serialize_res(spend_description, description_len) }
+    })
+}
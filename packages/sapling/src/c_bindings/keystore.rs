@@ -0,0 +1,97 @@
+use libc::{c_uchar, size_t};
+
+use zcash_primitives::zip32::ExtendedSpendingKey;
+
+use crate::c_init_lib;
+use crate::common::errors::SaplingError;
+use crate::common::utils::c_utils::{c_deserialize, c_deserialize_slice, c_get_result_res, c_ptr_catch_result, c_serialize_res};
+use crate::key::{EncryptedKeystore, KeystoreContent};
+
+#[no_mangle]
+pub extern "C" fn c_keystore_encrypt_xsk(
+    xsk: *const c_uchar,
+    xsk_len: size_t,
+    password: *const c_uchar,
+    password_len: size_t,
+    keystore_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let xsk: ExtendedSpendingKey = unsafe { c_deserialize(xsk, xsk_len) }?;
+        let password: &[u8] = unsafe { c_deserialize_slice(password, password_len) };
+
+        let keystore = EncryptedKeystore::encrypt(&KeystoreContent::Xsk(xsk), password);
+
+        unsafe { c_serialize_res(keystore, keystore_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_keystore_encrypt_seed(
+    seed: *const c_uchar,
+    seed_len: size_t,
+    password: *const c_uchar,
+    password_len: size_t,
+    keystore_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let seed: &[u8] = unsafe { c_deserialize_slice(seed, seed_len) };
+        let password: &[u8] = unsafe { c_deserialize_slice(password, password_len) };
+
+        let keystore = EncryptedKeystore::encrypt(&KeystoreContent::Seed(seed.to_vec()), password);
+
+        unsafe { c_serialize_res(keystore, keystore_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_keystore_decrypt_xsk(
+    keystore: *const c_uchar,
+    keystore_len: size_t,
+    password: *const c_uchar,
+    password_len: size_t,
+    xsk_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let keystore: EncryptedKeystore = unsafe { c_deserialize(keystore, keystore_len) }?;
+        let password: &[u8] = unsafe { c_deserialize_slice(password, password_len) };
+
+        unsafe { c_serialize_res(keystore.decrypt_xsk(password), xsk_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_keystore_decrypt_seed(
+    keystore: *const c_uchar,
+    keystore_len: size_t,
+    password: *const c_uchar,
+    password_len: size_t,
+    seed_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let keystore: EncryptedKeystore = unsafe { c_deserialize(keystore, keystore_len) }?;
+        let password: &[u8] = unsafe { c_deserialize_slice(password, password_len) };
+
+        let seed = keystore.decrypt_seed(password)?;
+
+        unsafe { c_get_result_res::<SaplingError>(seed, seed_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_keystore_fingerprint(keystore: *const c_uchar, keystore_len: size_t, fingerprint_len: *mut size_t) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let keystore: EncryptedKeystore = unsafe { c_deserialize(keystore, keystore_len) }?;
+
+        unsafe { c_get_result_res::<SaplingError>(keystore.fingerprint.to_vec(), fingerprint_len) }
+    })
+}
@@ -0,0 +1,84 @@
+use libc::{c_char, c_uchar, size_t};
+
+use crate::c_init_lib;
+use crate::common::errors::SaplingError;
+use crate::common::utils::c_utils::{c_deserialize_slice, c_deserialize_str, c_get_result_res, c_ptr_catch_result};
+use crate::transaction::Memo;
+
+#[no_mangle]
+pub extern "C" fn c_empty_memo(
+    memo_size: size_t,
+    memo_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let memo = Memo::Empty.encode(memo_size)?;
+
+        unsafe { c_get_result_res::<SaplingError>(memo, memo_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_text_memo(
+    text: *const c_char,
+    memo_size: size_t,
+    memo_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let text = unsafe { c_deserialize_str(text) };
+        let memo = Memo::Text(String::from(text)).encode(memo_size)?;
+
+        unsafe { c_get_result_res::<SaplingError>(memo, memo_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_arbitrary_memo(
+    data: *const c_uchar,
+    data_len: size_t,
+    memo_size: size_t,
+    memo_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let data = unsafe { c_deserialize_slice(data, data_len) };
+        let memo = Memo::Arbitrary(data.to_vec()).encode(memo_size)?;
+
+        unsafe { c_get_result_res::<SaplingError>(memo, memo_len) }
+    })
+}
+
+// Returns one of `empty`, `text`, `arbitrary` or `future` as UTF-8 bytes.
+#[no_mangle]
+pub extern "C" fn c_memo_type(
+    memo: *const c_uchar,
+    memo_len: size_t,
+    type_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let memo = Memo::decode(unsafe { c_deserialize_slice(memo, memo_len) })?;
+
+        unsafe { c_get_result_res::<SaplingError>(memo.type_name().as_bytes().to_vec(), type_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_memo_content(
+    memo: *const c_uchar,
+    memo_len: size_t,
+    content_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let memo = Memo::decode(unsafe { c_deserialize_slice(memo, memo_len) })?;
+
+        unsafe { c_get_result_res::<SaplingError>(memo.content().to_vec(), content_len) }
+    })
+}
@@ -0,0 +1,69 @@
+use libc::{c_uchar, size_t};
+use zcash_primitives::primitives::PaymentAddress;
+use zcash_primitives::zip32::ExtendedFullViewingKey;
+
+use crate::c_init_lib;
+use crate::common::errors::SaplingError;
+use crate::common::utils::c_utils::{c_deserialize, c_deserialize_slice, c_get_result_res, c_ptr_catch_result, c_serialize_res};
+use crate::key::IncomingViewingKey;
+use crate::transaction::{decrypt_message, EncryptedMessage, encrypt_message};
+
+#[no_mangle]
+pub extern "C" fn c_encrypt_message(
+    address: *const c_uchar,
+    address_len: size_t,
+    message: *const c_uchar,
+    message_len: size_t,
+    encrypted_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let address: PaymentAddress = unsafe { c_deserialize(address, address_len) }?;
+        let message = unsafe { c_deserialize_slice(message, message_len) };
+
+        let encrypted = encrypt_message(&address, message);
+
+        unsafe { c_serialize_res(encrypted, encrypted_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_decrypt_message_with_xfvk(
+    xfvk: *const c_uchar,
+    xfvk_len: size_t,
+    encrypted: *const c_uchar,
+    encrypted_len: size_t,
+    message_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let xfvk: ExtendedFullViewingKey = unsafe { c_deserialize(xfvk, xfvk_len) }?;
+        let encrypted: EncryptedMessage = unsafe { c_deserialize(encrypted, encrypted_len) }?;
+
+        let message = decrypt_message(&xfvk.fvk.vk.ivk(), &encrypted)?;
+
+        unsafe { c_get_result_res::<SaplingError>(message, message_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_decrypt_message_with_ivk(
+    ivk: *const c_uchar,
+    ivk_len: size_t,
+    encrypted: *const c_uchar,
+    encrypted_len: size_t,
+    message_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let ivk: IncomingViewingKey = unsafe { c_deserialize(ivk, ivk_len) }?;
+        let encrypted: EncryptedMessage = unsafe { c_deserialize(encrypted, encrypted_len) }?;
+
+        let message = decrypt_message(&ivk.to_sapling_ivk(), &encrypted)?;
+
+        unsafe { c_get_result_res::<SaplingError>(message, message_len) }
+    })
+}
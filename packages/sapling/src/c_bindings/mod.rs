@@ -1,14 +1,26 @@
+pub mod account;
 pub mod authorizing_key;
 pub mod commitment;
+pub mod frontier;
+pub mod frost;
 pub mod init;
 pub mod key_agreement;
+pub mod keystore;
+pub mod memo;
 pub mod merkle_tree;
+pub mod message_encryption;
 pub mod nullifier;
 pub mod output_description;
 pub mod payment_address;
+pub mod payment_disclosure;
+pub mod payment_uri;
+pub mod proving_builder;
 pub mod proving_context;
 pub mod rand;
+pub mod sapling_state;
+pub mod sapling_transaction;
 pub mod signature;
 pub mod spend_description;
 pub mod spending_key;
+pub mod value_commitment;
 pub mod viewing_key;
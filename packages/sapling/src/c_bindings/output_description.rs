@@ -12,7 +12,8 @@ use zcash_proofs::ZcashParameters;
 use crate::common::errors::{CausedBy, SaplingError};
 use crate::common::utils::c_utils::{c_dereference, c_deserialize, c_deserialize_slice, c_serialize_res, c_ptr_catch_result};
 use crate::{c_init_lib, State};
-use crate::transaction::{derive_epk, OutputDetails, prepare_output_description, prepare_partial_output_description};
+use crate::key::IncomingViewingKey;
+use crate::transaction::{decrypt_note_ciphertext, derive_epk, OutputDetails, prepare_output_description, prepare_partial_output_description, prepare_sized_output_description, prepare_sized_output_description_with_esk, SizedOutputDescription};
 
 #[no_mangle]
 pub extern "C" fn c_output_description_from_xfvk(
@@ -89,6 +90,96 @@ pub extern "C" fn c_output_description_from_xfvk_with_memo(
     })
 }
 
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub extern "C" fn c_output_description_from_xfvk_with_memo_size(
+    ctx: *mut SaplingProvingContext,
+    xfvk: *const c_uchar,
+    xfvk_len: size_t,
+    to: *const c_uchar,
+    to_len: size_t,
+    rcm: *const c_uchar,
+    rcm_len: size_t,
+    value: u64,
+    memo: *const c_uchar,
+    memo_len: size_t,
+    memo_size: size_t,
+    description_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let xfvk: ExtendedFullViewingKey = unsafe { c_deserialize(xfvk, xfvk_len) }?;
+        let address: PaymentAddress = unsafe { c_deserialize(to, to_len) }?;
+        let rcm: jubjub::Scalar = unsafe { c_deserialize(rcm, rcm_len) }?;
+        let memo: &[u8] = unsafe { c_deserialize_slice(memo, memo_len) };
+
+        let ctx: &mut SaplingProvingContext = unsafe { c_dereference(ctx) };
+
+        let params: &ZcashParameters = State::proof_params()?;
+        let proving_key: &Parameters<Bls12> = &params.output_params;
+
+        let output_description = prepare_sized_output_description(
+            ctx,
+            xfvk.fvk.ovk,
+            OutputDetails { to_address: address, value },
+            rcm,
+            Some(memo).filter(|memo| !memo.is_empty()),
+            memo_size,
+            proving_key,
+        );
+
+        unsafe { c_serialize_res(output_description, description_len) }
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub extern "C" fn c_output_description_from_xfvk_with_esk(
+    ctx: *mut SaplingProvingContext,
+    xfvk: *const c_uchar,
+    xfvk_len: size_t,
+    to: *const c_uchar,
+    to_len: size_t,
+    rcm: *const c_uchar,
+    rcm_len: size_t,
+    esk: *const c_uchar,
+    esk_len: size_t,
+    value: u64,
+    memo: *const c_uchar,
+    memo_len: size_t,
+    memo_size: size_t,
+    description_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let xfvk: ExtendedFullViewingKey = unsafe { c_deserialize(xfvk, xfvk_len) }?;
+        let address: PaymentAddress = unsafe { c_deserialize(to, to_len) }?;
+        let rcm: jubjub::Scalar = unsafe { c_deserialize(rcm, rcm_len) }?;
+        let esk: jubjub::Scalar = unsafe { c_deserialize(esk, esk_len) }?;
+        let memo: &[u8] = unsafe { c_deserialize_slice(memo, memo_len) };
+
+        let ctx: &mut SaplingProvingContext = unsafe { c_dereference(ctx) };
+
+        let params: &ZcashParameters = State::proof_params()?;
+        let proving_key: &Parameters<Bls12> = &params.output_params;
+
+        let output_description = prepare_sized_output_description_with_esk(
+            ctx,
+            xfvk.fvk.ovk,
+            OutputDetails { to_address: address, value },
+            rcm,
+            esk,
+            Some(memo).filter(|memo| !memo.is_empty()),
+            memo_size,
+            proving_key,
+        );
+
+        unsafe { c_serialize_res(output_description, description_len) }
+    })
+}
+
 #[no_mangle]
 pub extern "C" fn c_output_description_from_ovk(
     ctx: *mut SaplingProvingContext,
@@ -176,4 +267,56 @@ pub extern "C" fn c_derive_epk_from_esk(
 
         unsafe { c_serialize_res(epk, epk_len) }
     })
+}
+
+#[no_mangle]
+pub extern "C" fn c_decrypt_output_description_with_xfvk(
+    xfvk: *const c_uchar,
+    xfvk_len: size_t,
+    description: *const c_uchar,
+    description_len: size_t,
+    note_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let xfvk: ExtendedFullViewingKey = unsafe { c_deserialize(xfvk, xfvk_len) }?;
+        let description: SizedOutputDescription = unsafe { c_deserialize(description, description_len) }?;
+
+        let note = decrypt_note_ciphertext(
+            &xfvk.fvk.vk.ivk(),
+            &description.ephemeral_key,
+            &description.cmu,
+            &description.enc_ciphertext,
+            description.memo_size(),
+        );
+
+        unsafe { c_serialize_res(note, note_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_decrypt_output_description_with_ivk(
+    ivk: *const c_uchar,
+    ivk_len: size_t,
+    description: *const c_uchar,
+    description_len: size_t,
+    note_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let ivk: IncomingViewingKey = unsafe { c_deserialize(ivk, ivk_len) }?;
+        let description: SizedOutputDescription = unsafe { c_deserialize(description, description_len) }?;
+
+        let note = decrypt_note_ciphertext(
+            &ivk.to_sapling_ivk(),
+            &description.ephemeral_key,
+            &description.cmu,
+            &description.enc_ciphertext,
+            description.memo_size(),
+        );
+
+        unsafe { c_serialize_res(note, note_len) }
+    })
 }
\ No newline at end of file
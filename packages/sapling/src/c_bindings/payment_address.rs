@@ -2,12 +2,13 @@ use std::convert::TryInto;
 
 use libc::{c_uchar, size_t};
 use zcash_primitives::primitives::{Diversifier, PaymentAddress};
-use zcash_primitives::zip32::ExtendedFullViewingKey;
+use zcash_primitives::zip32::{ExtendedFullViewingKey, ExtendedSpendingKey};
 
-use crate::address::{get_ivk_address, get_next_xfvk_address, get_xfvk_address};
+use crate::address::{get_ivk_address, get_next_xfvk_address, get_xfvk_address, get_xfvk_addresses, get_xfvk_diversifier_index, OwnershipProof, sign_address_ownership, verify_address_ownership};
 use crate::c_init_lib;
 use crate::common::errors::{CausedBy, SaplingError};
-use crate::common::utils::c_utils::{c_get_result_res, c_deserialize, c_deserialize_slice, c_serialize, c_serialize_res, c_ptr_catch_result};
+use crate::common::traits::Serializable;
+use crate::common::utils::c_utils::{c_bool_catch_result, c_get_result_res, c_deserialize, c_deserialize_slice, c_serialize, c_serialize_res, c_ptr_catch_result};
 
 #[no_mangle]
 pub extern "C" fn c_default_payment_address_from_xfvk(
@@ -67,6 +68,54 @@ pub extern "C" fn c_payment_address_from_xfvk(
     })
 }
 
+// Returns the serialized indexed addresses (54 bytes each) concatenated, `count` is at most 1000.
+#[no_mangle]
+pub extern "C" fn c_payment_addresses_from_xfvk(
+    xfvk: *const c_uchar,
+    xfvk_len: size_t,
+    index: *const c_uchar,
+    index_len: size_t,
+    count: size_t,
+    addresses_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let index: [u8; 11] = unsafe { c_deserialize_slice(index, index_len) }.try_into()
+            .map_err(|_| SaplingError::caused_by("paymentAddressesFromXfvk: index must be an array of 11 bytes"))?;
+
+        let xfvk: ExtendedFullViewingKey = unsafe { c_deserialize(xfvk, xfvk_len) }?;
+        let addresses = get_xfvk_addresses(&xfvk, Some(index), count)?;
+        let addresses = addresses.iter()
+            .map(|address| address.serialize())
+            .collect::<Result<Vec<Vec<u8>>, SaplingError>>()?
+            .concat();
+
+        unsafe { c_get_result_res::<SaplingError>(addresses, addresses_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_diversifier_index_from_xfvk(
+    xfvk: *const c_uchar,
+    xfvk_len: size_t,
+    diversifier: *const c_uchar,
+    diversifier_len: size_t,
+    index_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let diversifier: [u8; 11] = unsafe { c_deserialize_slice(diversifier, diversifier_len) }.try_into()
+            .map_err(|_| SaplingError::caused_by("diversifierIndexFromXfvk: diversifier must be an array of 11 bytes"))?;
+
+        let xfvk: ExtendedFullViewingKey = unsafe { c_deserialize(xfvk, xfvk_len) }?;
+        let index = get_xfvk_diversifier_index(&xfvk, &Diversifier(diversifier))?;
+
+        unsafe { c_get_result_res::<SaplingError>(index.to_vec(), index_len) }
+    })
+}
+
 #[no_mangle]
 pub extern "C" fn c_payment_address_from_ivk(
     ivk: *const c_uchar,
@@ -119,4 +168,46 @@ pub extern "C" fn c_pkd_from_payment_address(
 
         unsafe { c_serialize(*address.pk_d(), pkd_len) }
     })
-}
\ No newline at end of file
+}
+#[no_mangle]
+pub extern "C" fn c_sign_address_ownership(
+    xsk: *const c_uchar,
+    xsk_len: size_t,
+    address: *const c_uchar,
+    address_len: size_t,
+    message: *const c_uchar,
+    message_len: size_t,
+    proof_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let xsk: ExtendedSpendingKey = unsafe { c_deserialize(xsk, xsk_len) }?;
+        let address: PaymentAddress = unsafe { c_deserialize(address, address_len) }?;
+        let message = unsafe { c_deserialize_slice(message, message_len) };
+
+        let proof = sign_address_ownership(&xsk, &address, message)?;
+
+        unsafe { c_serialize(proof, proof_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_verify_address_ownership(
+    address: *const c_uchar,
+    address_len: size_t,
+    message: *const c_uchar,
+    message_len: size_t,
+    proof: *const c_uchar,
+    proof_len: size_t,
+) -> bool {
+    c_init_lib();
+
+    c_bool_catch_result(|| {
+        let address: PaymentAddress = unsafe { c_deserialize(address, address_len) }?;
+        let message = unsafe { c_deserialize_slice(message, message_len) };
+        let proof: OwnershipProof = unsafe { c_deserialize(proof, proof_len) }?;
+
+        verify_address_ownership(&address, message, &proof)
+    })
+}
@@ -0,0 +1,62 @@
+use libc::{c_uchar, size_t};
+use zcash_primitives::constants::SPENDING_KEY_GENERATOR;
+use zcash_primitives::redjubjub::PublicKey;
+use zcash_primitives::zip32::ExtendedSpendingKey;
+
+use crate::c_init_lib;
+use crate::common::utils::c_utils::{c_deserialize, c_deserialize_slice, c_ptr_catch_result, c_serialize_res};
+use crate::transaction::{create_payment_disclosure, PaymentDisclosure, SizedOutputDescription, spend_rks_from_bytes, verify_payment_disclosure};
+
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub extern "C" fn c_payment_disclosure_from_xsk(
+    xsk: *const c_uchar,
+    xsk_len: size_t,
+    ar: *const c_uchar,
+    ar_len: size_t,
+    output_description: *const c_uchar,
+    output_description_len: size_t,
+    rcv: *const c_uchar,
+    rcv_len: size_t,
+    message: *const c_uchar,
+    message_len: size_t,
+    disclosure_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let xsk: ExtendedSpendingKey = unsafe { c_deserialize(xsk, xsk_len) }?;
+        let ar: jubjub::Scalar = unsafe { c_deserialize(ar, ar_len) }?;
+        let output_description: SizedOutputDescription = unsafe { c_deserialize(output_description, output_description_len) }?;
+        let rcv: Option<jubjub::Scalar> = if rcv.is_null() { None } else { Some(unsafe { c_deserialize(rcv, rcv_len) }?) };
+        let message = unsafe { c_deserialize_slice(message, message_len) };
+
+        let rk = PublicKey(xsk.expsk.proof_generation_key().ak.into()).randomize(ar, SPENDING_KEY_GENERATOR);
+        let disclosure = create_payment_disclosure(&xsk, ar, rk, &xsk.expsk.ovk, &output_description, rcv, message);
+
+        unsafe { c_serialize_res(disclosure, disclosure_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_verify_payment_disclosure(
+    disclosure: *const c_uchar,
+    disclosure_len: size_t,
+    output_description: *const c_uchar,
+    output_description_len: size_t,
+    spend_rks: *const c_uchar,
+    spend_rks_len: size_t,
+    note_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let disclosure: PaymentDisclosure = unsafe { c_deserialize(disclosure, disclosure_len) }?;
+        let output_description: SizedOutputDescription = unsafe { c_deserialize(output_description, output_description_len) }?;
+        let spend_rks = spend_rks_from_bytes(unsafe { c_deserialize_slice(spend_rks, spend_rks_len) })?;
+
+        let decrypted = verify_payment_disclosure(&disclosure, &output_description, &spend_rks);
+
+        unsafe { c_serialize_res(decrypted, note_len) }
+    })
+}
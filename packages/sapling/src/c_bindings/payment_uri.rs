@@ -0,0 +1,106 @@
+use libc::{c_char, c_uchar, size_t};
+use zcash_primitives::primitives::PaymentAddress;
+
+use crate::address::{decode_payment_address, encode_payment_address, Payment, PaymentRequest};
+use crate::c_init_lib;
+use crate::common::errors::SaplingError;
+use crate::common::utils::c_utils::{c_bool_catch_result, c_dereference, c_deserialize, c_deserialize_slice, c_deserialize_str, c_drop_reference, c_get_result_res, c_ptr_catch_result, c_reference, c_serialize};
+use crate::json::to_json;
+
+#[no_mangle]
+pub extern "C" fn c_init_payment_request() -> *mut PaymentRequest {
+    c_init_lib();
+    c_reference(PaymentRequest::new())
+}
+
+#[no_mangle]
+pub extern "C" fn c_drop_payment_request(request: *mut PaymentRequest) {
+    c_init_lib();
+    unsafe { c_drop_reference::<PaymentRequest>(request) }
+}
+
+// `amount` (in mutez), `memo`, `label` and `message` are optional and may be null.
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub extern "C" fn c_payment_request_add_payment(
+    request: *mut PaymentRequest,
+    address: *const c_uchar,
+    address_len: size_t,
+    amount: *const u64,
+    memo: *const c_uchar,
+    memo_len: size_t,
+    label: *const c_char,
+    message: *const c_char,
+) -> bool {
+    c_init_lib();
+
+    c_bool_catch_result(|| {
+        let address: PaymentAddress = unsafe { c_deserialize(address, address_len) }?;
+        let amount: Option<u64> = if amount.is_null() { None } else { Some(unsafe { *amount }) };
+        let memo: Option<Vec<u8>> = if memo.is_null() { None } else { Some(unsafe { c_deserialize_slice(memo, memo_len) }.to_vec()) };
+        let label: Option<String> = if label.is_null() { None } else { Some(String::from(unsafe { c_deserialize_str(label) })) };
+        let message: Option<String> = if message.is_null() { None } else { Some(String::from(unsafe { c_deserialize_str(message) })) };
+
+        let request: &mut PaymentRequest = unsafe { c_dereference(request) };
+        request.add_payment(Payment { address, amount, memo, label, message })
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_payment_request_to_uri(
+    request: *mut PaymentRequest,
+    uri_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let request: &mut PaymentRequest = unsafe { c_dereference(request) };
+
+        let uri = request.to_uri()?;
+
+        unsafe { c_get_result_res::<SaplingError>(uri.into_bytes(), uri_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_parse_payment_uri(
+    uri: *const c_char,
+    json_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let request: PaymentRequest = unsafe { c_deserialize_str(uri) }.parse()?;
+
+        unsafe { c_get_result_res::<SaplingError>(to_json(&request)?.into_bytes(), json_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_encode_payment_address(
+    address: *const c_uchar,
+    address_len: size_t,
+    encoded_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let address: PaymentAddress = unsafe { c_deserialize(address, address_len) }?;
+
+        unsafe { c_get_result_res::<SaplingError>(encode_payment_address(&address).into_bytes(), encoded_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_decode_payment_address(
+    encoded: *const c_char,
+    address_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let address = decode_payment_address(unsafe { c_deserialize_str(encoded) })?;
+
+        unsafe { c_serialize(address, address_len) }
+    })
+}
@@ -0,0 +1,248 @@
+use std::convert::TryInto;
+
+use libc::{c_uchar, c_void, size_t};
+use zcash_primitives::keys::OutgoingViewingKey;
+use zcash_primitives::merkle_tree::MerklePath;
+use zcash_primitives::primitives::{PaymentAddress, ProofGenerationKey};
+use zcash_primitives::sapling::Node;
+use zcash_proofs::ZcashParameters;
+
+use crate::{c_init_lib, State};
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::traits::Serializable;
+use crate::common::utils::c_utils::{c_bool_catch_result, c_dereference, c_deserialize, c_get_result_res, c_deserialize_slice, c_drop_reference, c_ptr_catch_result, c_ref_catch_result, c_reference, c_serialize_res};
+use crate::transaction::{CancellationToken, create_binding_sig, OutputInput, ProvedDescriptions, ProvingBuilder, SpendInput};
+
+#[no_mangle]
+pub extern "C" fn c_init_proving_builder() -> *mut ProvingBuilder {
+    c_init_lib();
+    c_reference(ProvingBuilder::new())
+}
+
+#[no_mangle]
+pub extern "C" fn c_drop_proving_builder(builder: *mut ProvingBuilder) {
+    c_init_lib();
+    unsafe { c_drop_reference::<ProvingBuilder>(builder) }
+}
+
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub extern "C" fn c_proving_builder_add_spend(
+    builder: *mut ProvingBuilder,
+    pak: *const c_uchar,
+    pak_len: size_t,
+    address: *const c_uchar,
+    address_len: size_t,
+    rcm: *const c_uchar,
+    rcm_len: size_t,
+    ar: *const c_uchar,
+    ar_len: size_t,
+    value: u64,
+    anchor: *const c_uchar,
+    anchor_len: size_t,
+    merkle_path: *const c_uchar,
+    merkle_path_len: size_t,
+) -> bool {
+    c_init_lib();
+
+    c_bool_catch_result(|| {
+        let pak: ProofGenerationKey = unsafe { c_deserialize(pak, pak_len) }?;
+        let address: PaymentAddress = unsafe { c_deserialize(address, address_len) }?;
+        let rcm: jubjub::Scalar = unsafe { c_deserialize(rcm, rcm_len) }?;
+        let ar: jubjub::Scalar = unsafe { c_deserialize(ar, ar_len) }?;
+        let anchor: bls12_381::Scalar = unsafe { c_deserialize(anchor, anchor_len) }?;
+        let merkle_path: MerklePath<Node> = unsafe { c_deserialize(merkle_path, merkle_path_len) }?;
+
+        let builder: &mut ProvingBuilder = unsafe { c_dereference(builder) };
+        builder.add_spend(SpendInput { pak, address, value, rcm, ar, anchor, merkle_path });
+
+        Ok::<(), SaplingError>(())
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub extern "C" fn c_proving_builder_add_output(
+    builder: *mut ProvingBuilder,
+    ovk: *const c_uchar,
+    ovk_len: size_t,
+    address: *const c_uchar,
+    address_len: size_t,
+    rcm: *const c_uchar,
+    rcm_len: size_t,
+    value: u64,
+    esk: *const c_uchar,
+    esk_len: size_t,
+    memo: *const c_uchar,
+    memo_len: size_t,
+) -> bool {
+    c_init_lib();
+
+    c_bool_catch_result(|| {
+        let ovk: [u8; 32] = unsafe { c_deserialize_slice(ovk, ovk_len) }.try_into()
+            .map_err(|_| SaplingError::caused_by("provingBuilderAddOutput: ovk must be an array of 32 bytes"))?;
+        let address: PaymentAddress = unsafe { c_deserialize(address, address_len) }?;
+        let rcm: jubjub::Scalar = unsafe { c_deserialize(rcm, rcm_len) }?;
+        let esk: Option<jubjub::Scalar> = if esk.is_null() { None } else { Some(unsafe { c_deserialize(esk, esk_len) }?) };
+        let memo: Option<Vec<u8>> = if memo.is_null() { None } else { Some(unsafe { c_deserialize_slice(memo, memo_len) }.to_vec()) };
+
+        let builder: &mut ProvingBuilder = unsafe { c_dereference(builder) };
+        builder.add_output(OutputInput { ovk: OutgoingViewingKey(ovk), address, value, rcm, esk, memo });
+
+        Ok::<(), SaplingError>(())
+    })
+}
+
+pub type ProvingProgressCallback = extern "C" fn(completed: size_t, total: size_t, user_data: *mut c_void);
+
+// The callback may be called from another thread when proving in parallel,
+// it's the caller's responsibility to make `user_data` safe to use in such case.
+struct ProgressReporter {
+    on_progress: Option<ProvingProgressCallback>,
+    user_data: *mut c_void,
+}
+
+unsafe impl Send for ProgressReporter {}
+unsafe impl Sync for ProgressReporter {}
+
+impl ProgressReporter {
+    fn report(&self, completed: usize, total: usize) {
+        if let Some(on_progress) = self.on_progress {
+            on_progress(completed, total, self.user_data);
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn c_proving_builder_prove(
+    builder: *mut ProvingBuilder,
+    on_progress: Option<ProvingProgressCallback>,
+    user_data: *mut c_void,
+    cancellation_token: *mut CancellationToken,
+) -> *mut ProvedDescriptions {
+    c_init_lib();
+
+    c_ref_catch_result(|| {
+        let builder: &mut ProvingBuilder = unsafe { c_dereference(builder) };
+        let cancellation_token: CancellationToken = get_cancellation_token(cancellation_token);
+        let params: &ZcashParameters = State::proof_params()?;
+        let reporter = ProgressReporter { on_progress, user_data };
+
+        builder.prove(params, |completed, total| reporter.report(completed, total), &cancellation_token).map(c_reference)
+    })
+}
+
+// Proves on the rayon thread pool, the inputs are kept like with `c_proving_builder_prove`,
+// the builder can be proved again or has to be dropped with `c_drop_proving_builder`.
+#[cfg(feature = "parallel_proving")]
+#[no_mangle]
+pub extern "C" fn c_proving_builder_prove_parallel(
+    builder: *mut ProvingBuilder,
+    on_progress: Option<ProvingProgressCallback>,
+    user_data: *mut c_void,
+    cancellation_token: *mut CancellationToken,
+) -> *mut ProvedDescriptions {
+    c_init_lib();
+
+    c_ref_catch_result(|| {
+        let builder: &mut ProvingBuilder = unsafe { c_dereference(builder) };
+        let cancellation_token: CancellationToken = get_cancellation_token(cancellation_token);
+        let params: &ZcashParameters = State::proof_params()?;
+        let reporter = ProgressReporter { on_progress, user_data };
+
+        builder.prove_parallel(params, |completed, total| reporter.report(completed, total), &cancellation_token).map(c_reference)
+    })
+}
+
+fn get_cancellation_token(cancellation_token: *mut CancellationToken) -> CancellationToken {
+    if cancellation_token.is_null() {
+        CancellationToken::new()
+    } else {
+        unsafe { c_dereference(cancellation_token) }.clone()
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn c_init_cancellation_token() -> *mut CancellationToken {
+    c_init_lib();
+    c_reference(CancellationToken::new())
+}
+
+#[no_mangle]
+pub extern "C" fn c_cancel_cancellation_token(cancellation_token: *mut CancellationToken) {
+    c_init_lib();
+
+    let cancellation_token: &mut CancellationToken = unsafe { c_dereference(cancellation_token) };
+    cancellation_token.cancel();
+}
+
+#[no_mangle]
+pub extern "C" fn c_drop_cancellation_token(cancellation_token: *mut CancellationToken) {
+    c_init_lib();
+    unsafe { c_drop_reference::<CancellationToken>(cancellation_token) }
+}
+
+#[no_mangle]
+pub extern "C" fn c_drop_proved_descriptions(proved: *mut ProvedDescriptions) {
+    c_init_lib();
+    unsafe { c_drop_reference::<ProvedDescriptions>(proved) }
+}
+
+#[no_mangle]
+pub extern "C" fn c_proved_descriptions_spend_description(
+    proved: *mut ProvedDescriptions,
+    index: size_t,
+    description_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let proved: &mut ProvedDescriptions = unsafe { c_dereference(proved) };
+        let spend_description = proved.spend_descriptions.get(index)
+            .ok_or_else(|| SaplingError::caused_by("provedDescriptionsSpendDescription: index out of range"))?;
+
+        let spend_description: Vec<u8> = spend_description.serialize()?;
+
+        unsafe { c_get_result_res::<SaplingError>(spend_description, description_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_proved_descriptions_output_description(
+    proved: *mut ProvedDescriptions,
+    index: size_t,
+    description_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let proved: &mut ProvedDescriptions = unsafe { c_dereference(proved) };
+        let output_description = proved.output_descriptions.get(index)
+            .ok_or_else(|| SaplingError::caused_by("provedDescriptionsOutputDescription: index out of range"))?;
+
+        let output_description: Vec<u8> = output_description.serialize()?;
+
+        unsafe { c_get_result_res::<SaplingError>(output_description, description_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_proved_descriptions_binding_signature(
+    proved: *mut ProvedDescriptions,
+    value_balance: i64,
+    sighash: *const c_uchar,
+    sighash_len: size_t,
+    signature_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let proved: &mut ProvedDescriptions = unsafe { c_dereference(proved) };
+        let sighash: [u8; 32] = unsafe { c_deserialize_slice(sighash, sighash_len) }.try_into()
+            .map_err(|_| SaplingError::caused_by("provedDescriptionsBindingSignature: sighash must be an array of 32 bytes"))?;
+
+        let binding_sig = create_binding_sig(&mut proved.ctx, value_balance, sighash);
+
+        unsafe { c_serialize_res(binding_sig, signature_len) }
+    })
+}
@@ -1,8 +1,10 @@
 use libc::{c_uchar, size_t};
 use crate::c_init_lib;
 
-use crate::common::utils::c_utils::{c_serialize, c_ptr_catch_result};
-use crate::transaction::rand_scalar;
+use zcash_primitives::zip32::ExtendedSpendingKey;
+
+use crate::common::utils::c_utils::{c_deserialize, c_deserialize_slice, c_serialize, c_ptr_catch_result};
+use crate::transaction::{derive_rand_scalar, DerivedRandKind, rand_scalar};
 
 #[no_mangle]
 pub extern "C" fn c_rand_r(r_len: *mut size_t) -> *mut c_uchar {
@@ -12,4 +14,60 @@ pub extern "C" fn c_rand_r(r_len: *mut size_t) -> *mut c_uchar {
         let scalar = rand_scalar();
         unsafe { c_serialize(scalar, r_len) }
     })
-}
\ No newline at end of file
+}
+
+#[no_mangle]
+pub extern "C" fn c_derive_rcm(
+    xsk: *const c_uchar,
+    xsk_len: size_t,
+    context: *const c_uchar,
+    context_len: size_t,
+    index: u32,
+    rcm_len: *mut size_t,
+) -> *mut c_uchar {
+    c_derive_rand_scalar(DerivedRandKind::Rcm, xsk, xsk_len, context, context_len, index, rcm_len)
+}
+
+#[no_mangle]
+pub extern "C" fn c_derive_esk(
+    xsk: *const c_uchar,
+    xsk_len: size_t,
+    context: *const c_uchar,
+    context_len: size_t,
+    index: u32,
+    esk_len: *mut size_t,
+) -> *mut c_uchar {
+    c_derive_rand_scalar(DerivedRandKind::Esk, xsk, xsk_len, context, context_len, index, esk_len)
+}
+
+#[no_mangle]
+pub extern "C" fn c_derive_ar(
+    xsk: *const c_uchar,
+    xsk_len: size_t,
+    context: *const c_uchar,
+    context_len: size_t,
+    index: u32,
+    ar_len: *mut size_t,
+) -> *mut c_uchar {
+    c_derive_rand_scalar(DerivedRandKind::Ar, xsk, xsk_len, context, context_len, index, ar_len)
+}
+
+fn c_derive_rand_scalar(
+    kind: DerivedRandKind,
+    xsk: *const c_uchar,
+    xsk_len: size_t,
+    context: *const c_uchar,
+    context_len: size_t,
+    index: u32,
+    scalar_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let xsk: ExtendedSpendingKey = unsafe { c_deserialize(xsk, xsk_len) }?;
+        let context: &[u8] = unsafe { c_deserialize_slice(context, context_len) };
+
+        let scalar = derive_rand_scalar(&xsk, kind, context, index);
+        unsafe { c_serialize(scalar, scalar_len) }
+    })
+}
@@ -0,0 +1,49 @@
+use libc::{c_uchar, size_t};
+use zcash_proofs::ZcashParameters;
+
+use crate::{c_init_lib, State};
+use crate::common::errors::SaplingError;
+use crate::common::utils::c_utils::{c_deserialize, c_deserialize_slice, c_get_result_res, c_ptr_catch_result, c_serialize};
+use crate::transaction::{node_bytes, SaplingState, SaplingTransaction, verify_update};
+
+#[no_mangle]
+pub extern "C" fn c_sapling_state_empty(memo_size: u16, state_len: *mut size_t) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| unsafe { c_serialize(SaplingState::empty(memo_size), state_len) })
+}
+
+#[no_mangle]
+pub extern "C" fn c_sapling_state_root(state: *const c_uchar, state_len: size_t, root_len: *mut size_t) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let state: SaplingState = unsafe { c_deserialize(state, state_len) }?;
+
+        unsafe { c_get_result_res::<SaplingError>(node_bytes(&state.root()).to_vec(), root_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_sapling_verify_update(
+    state: *const c_uchar,
+    state_len: size_t,
+    transaction: *const c_uchar,
+    transaction_len: size_t,
+    anti_replay: *const c_uchar,
+    anti_replay_len: size_t,
+    new_state_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let state: SaplingState = unsafe { c_deserialize(state, state_len) }?;
+        let transaction: SaplingTransaction = unsafe { c_deserialize(transaction, transaction_len) }?;
+        let anti_replay: &[u8] = unsafe { c_deserialize_slice(anti_replay, anti_replay_len) };
+
+        let params: &ZcashParameters = State::proof_params()?;
+        let update = verify_update(&state, &transaction, anti_replay, params)?;
+
+        unsafe { c_serialize(update.state, new_state_len) }
+    })
+}
@@ -0,0 +1,62 @@
+use libc::{c_char, c_uchar, size_t};
+
+use crate::c_init_lib;
+use crate::common::errors::SaplingError;
+use crate::common::utils::c_utils::{c_deserialize, c_deserialize_str, c_get_result_res, c_ptr_catch_result, c_serialize};
+use crate::json::to_json;
+use crate::transaction::{KeyHash, SaplingTransaction, ShieldedPoolParameters, ShieldedTransfer};
+
+#[no_mangle]
+pub extern "C" fn c_inspect_sapling_transaction(
+    transaction: *const c_uchar,
+    transaction_len: size_t,
+    json_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let transaction: SaplingTransaction = unsafe { c_deserialize(transaction, transaction_len) }?;
+        let json: Vec<u8> = to_json(&transaction)?.into_bytes();
+
+        unsafe { c_get_result_res::<SaplingError>(json, json_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_shielded_pool_parameters(
+    transaction: *const c_uchar,
+    transaction_len: size_t,
+    unshield_to: *const c_char,
+    parameters_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let transaction: SaplingTransaction = unsafe { c_deserialize(transaction, transaction_len) }?;
+        let unshield_to: Option<KeyHash> = if unshield_to.is_null() {
+            None
+        } else {
+            Some(unsafe { c_deserialize_str(unshield_to) }.parse()?)
+        };
+
+        let parameters = ShieldedPoolParameters { transfers: vec![ShieldedTransfer { transaction, unshield_to }] };
+
+        unsafe { c_serialize(parameters, parameters_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_inspect_shielded_pool_parameters(
+    parameters: *const c_uchar,
+    parameters_len: size_t,
+    json_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let parameters: ShieldedPoolParameters = unsafe { c_deserialize(parameters, parameters_len) }?;
+        let json: Vec<u8> = to_json(&parameters)?.into_bytes();
+
+        unsafe { c_get_result_res::<SaplingError>(json, json_len) }
+    })
+}
@@ -5,8 +5,8 @@ use zcash_proofs::sapling::SaplingProvingContext;
 use crate::c_init_lib;
 
 use crate::common::errors::{CausedBy, SaplingError};
-use crate::common::utils::c_utils::{c_dereference, c_deserialize_slice, c_serialize_res, c_ptr_catch_result};
-use crate::transaction::create_binding_sig;
+use crate::common::utils::c_utils::{c_dereference, c_deserialize, c_deserialize_slice, c_serialize, c_serialize_res, c_ptr_catch_result};
+use crate::transaction::{create_binding_sig, create_binding_sig_with_bsk};
 
 #[no_mangle]
 pub extern "C" fn c_binding_signature(
@@ -27,4 +27,25 @@ pub extern "C" fn c_binding_signature(
 
         unsafe { c_serialize_res(binding_sig, signature_len) }
     })
-}
\ No newline at end of file
+}
+
+#[no_mangle]
+pub extern "C" fn c_binding_signature_with_bsk(
+    bsk: *const c_uchar,
+    bsk_len: size_t,
+    sighash: *const c_uchar,
+    sighash_len: size_t,
+    signature_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let bsk: jubjub::Scalar = unsafe { c_deserialize(bsk, bsk_len) }?;
+        let sighash: [u8; 32] = unsafe { c_deserialize_slice(sighash, sighash_len) }.try_into()
+            .map_err(|_| SaplingError::caused_by("bindingSignatureWithBsk: sighash must be an array of 32 bytes"))?;
+
+        let binding_sig = create_binding_sig_with_bsk(&bsk, sighash);
+
+        unsafe { c_serialize(binding_sig, signature_len) }
+    })
+}
@@ -2,9 +2,10 @@ use std::convert::TryInto;
 
 use bellman::groth16::{Parameters, PreparedVerifyingKey};
 use bls12_381::Bls12;
-use libc::{c_uchar, size_t};
+use libc::{c_uchar, c_void, size_t};
 use zcash_primitives::merkle_tree::MerklePath;
 use zcash_primitives::primitives::{PaymentAddress, ProofGenerationKey};
+use zcash_primitives::redjubjub::Signature;
 use zcash_primitives::sapling::Node;
 use zcash_primitives::zip32::ExtendedSpendingKey;
 use zcash_proofs::sapling::SaplingProvingContext;
@@ -13,7 +14,15 @@ use zcash_proofs::ZcashParameters;
 use crate::common::errors::{CausedBy, SaplingError};
 use crate::common::utils::c_utils::{c_dereference, c_deserialize, c_deserialize_slice, c_serialize_res, c_ptr_catch_result};
 use crate::{c_init_lib, State};
-use crate::transaction::{prepare_spend_description, sign_spend_description, SpendDetails, SpendParameters, UnsignedSpendDescription};
+use crate::transaction::{
+    LocalSpendAuthSigner,
+    prepare_spend_description,
+    sign_spend_description,
+    SpendAuthSigner,
+    SpendDetails,
+    SpendParameters,
+    UnsignedSpendDescription,
+};
 
 #[allow(clippy::too_many_arguments)]
 #[no_mangle]
@@ -135,7 +144,86 @@ pub extern "C" fn c_sign_spend_description_with_xsk(
         let sighash: [u8; 32] = unsafe { c_deserialize_slice(sighash, sighash_len) }.try_into()
             .map_err(|_| SaplingError::caused_by("signSpendDescriptionWithXsk: sighash must be an array of 32 bytes"))?;
 
-        let spend_description = sign_spend_description(spend_description, xks, ar, sighash);
+        let spend_description = sign_spend_description(spend_description, &xks, ar, sighash);
+
+        unsafe { c_serialize_res(spend_description, description_len) }
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub extern "C" fn c_sign_spend_description_with_ask(
+    spend_description: *const c_uchar,
+    spend_description_len: size_t,
+    ask: *const c_uchar,
+    ask_len: size_t,
+    ar: *const c_uchar,
+    ar_len: size_t,
+    sighash: *const c_uchar,
+    sighash_len: size_t,
+    description_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let spend_description: UnsignedSpendDescription = unsafe { c_deserialize(spend_description, spend_description_len) }?;
+        let ask: jubjub::Scalar = unsafe { c_deserialize(ask, ask_len) }?;
+        let ar: jubjub::Scalar = unsafe { c_deserialize(ar, ar_len) }?;
+
+        let sighash: [u8; 32] = unsafe { c_deserialize_slice(sighash, sighash_len) }.try_into()
+            .map_err(|_| SaplingError::caused_by("signSpendDescriptionWithAsk: sighash must be an array of 32 bytes"))?;
+
+        let spend_description = sign_spend_description(spend_description, &LocalSpendAuthSigner::new(ask), ar, sighash);
+
+        unsafe { c_serialize_res(spend_description, description_len) }
+    })
+}
+
+// Called with `ar` (32 bytes) and the sighash (32 bytes), writes the 64 byte signature and returns `true` on success.
+pub type SpendAuthSignCallback = extern "C" fn(ar: *const c_uchar, sighash: *const c_uchar, signature: *mut c_uchar, user_data: *mut c_void) -> bool;
+
+struct CallbackSpendAuthSigner {
+    sign: SpendAuthSignCallback,
+    user_data: *mut c_void,
+}
+
+impl SpendAuthSigner for CallbackSpendAuthSigner {
+    fn sign_spend_auth(&self, ar: jubjub::Scalar, sighash: [u8; 32]) -> Result<Signature, SaplingError> {
+        let ar = ar.to_bytes();
+        let mut signature = [0u8; 64];
+        if !(self.sign)(ar.as_ptr(), sighash.as_ptr(), signature.as_mut_ptr(), self.user_data) {
+            return Err(SaplingError::caused_by("signSpendDescriptionWithSigner: the signer failed"));
+        }
+
+        Signature::read(&signature[..]).map_err(|err| SaplingError::caused_by(err.to_string()))
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub extern "C" fn c_sign_spend_description_with_signer(
+    spend_description: *const c_uchar,
+    spend_description_len: size_t,
+    sign: Option<SpendAuthSignCallback>,
+    user_data: *mut c_void,
+    ar: *const c_uchar,
+    ar_len: size_t,
+    sighash: *const c_uchar,
+    sighash_len: size_t,
+    description_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let sign = sign.ok_or_else(|| SaplingError::caused_by("signSpendDescriptionWithSigner: signer must not be null"))?;
+        let spend_description: UnsignedSpendDescription = unsafe { c_deserialize(spend_description, spend_description_len) }?;
+        let ar: jubjub::Scalar = unsafe { c_deserialize(ar, ar_len) }?;
+
+        let sighash: [u8; 32] = unsafe { c_deserialize_slice(sighash, sighash_len) }.try_into()
+            .map_err(|_| SaplingError::caused_by("signSpendDescriptionWithSigner: sighash must be an array of 32 bytes"))?;
+
+        let signer = CallbackSpendAuthSigner { sign, user_data };
+        let spend_description = sign_spend_description(spend_description, &signer, ar, sighash);
 
         unsafe { c_serialize_res(spend_description, description_len) }
     })
@@ -0,0 +1,83 @@
+use libc::{c_uchar, size_t};
+
+use crate::c_init_lib;
+use crate::common::utils::c_utils::{c_deserialize, c_deserialize_slice, c_ptr_catch_result, c_serialize, c_serialize_res};
+use crate::transaction::{
+    compute_bsk,
+    compute_bvk,
+    compute_value_commitment,
+    sum_trapdoors,
+    sum_value_commitments,
+    trapdoors_from_bytes,
+    value_commitments_from_bytes,
+};
+
+#[no_mangle]
+pub extern "C" fn c_compute_value_commitment(value: u64, rcv: *const c_uchar, rcv_len: size_t, cv_len: *mut size_t) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let rcv: jubjub::Scalar = unsafe { c_deserialize(rcv, rcv_len) }?;
+
+        unsafe { c_serialize(compute_value_commitment(value, rcv), cv_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_sum_value_commitments(cvs: *const c_uchar, cvs_len: size_t, cv_len: *mut size_t) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let cvs = value_commitments_from_bytes(unsafe { c_deserialize_slice(cvs, cvs_len) })?;
+
+        unsafe { c_serialize(sum_value_commitments(&cvs), cv_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_sum_trapdoors(rcvs: *const c_uchar, rcvs_len: size_t, rcv_len: *mut size_t) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let rcvs = trapdoors_from_bytes(unsafe { c_deserialize_slice(rcvs, rcvs_len) })?;
+
+        unsafe { c_serialize(sum_trapdoors(&rcvs), rcv_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_compute_bsk(
+    spend_rcvs: *const c_uchar,
+    spend_rcvs_len: size_t,
+    output_rcvs: *const c_uchar,
+    output_rcvs_len: size_t,
+    bsk_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let spend_rcvs = trapdoors_from_bytes(unsafe { c_deserialize_slice(spend_rcvs, spend_rcvs_len) })?;
+        let output_rcvs = trapdoors_from_bytes(unsafe { c_deserialize_slice(output_rcvs, output_rcvs_len) })?;
+
+        unsafe { c_serialize(compute_bsk(&spend_rcvs, &output_rcvs), bsk_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_compute_bvk(
+    spend_cvs: *const c_uchar,
+    spend_cvs_len: size_t,
+    output_cvs: *const c_uchar,
+    output_cvs_len: size_t,
+    value_balance: i64,
+    bvk_len: *mut size_t,
+) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let spend_cvs = value_commitments_from_bytes(unsafe { c_deserialize_slice(spend_cvs, spend_cvs_len) })?;
+        let output_cvs = value_commitments_from_bytes(unsafe { c_deserialize_slice(output_cvs, output_cvs_len) })?;
+
+        unsafe { c_serialize_res(compute_bvk(&spend_cvs, &output_cvs, value_balance), bvk_len) }
+    })
+}
@@ -4,7 +4,7 @@ use crate::c_init_lib;
 
 use crate::common::errors::SaplingError;
 use crate::common::utils::c_utils::{c_get_result_res, c_deserialize, c_deserialize_slice, c_deserialize_str, c_serialize, c_serialize_res, c_ptr_catch_result};
-use crate::key::{crh_ivk, SaplingKey};
+use crate::key::{crh_ivk, IncomingViewingKey, SaplingKey};
 
 #[no_mangle]
 pub extern "C" fn c_xfvk(
@@ -59,4 +59,26 @@ pub extern "C" fn c_xfvk_to_ivk(xfvk: *const c_uchar, xfvk_len: size_t, ivk_len:
 
         unsafe { c_get_result_res::<SaplingError>(ivk, ivk_len) }
     })
+}
+
+#[no_mangle]
+pub extern "C" fn c_ivk_to_bech32(ivk: *const c_uchar, ivk_len: size_t, encoded_len: *mut size_t) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let ivk: IncomingViewingKey = unsafe { c_deserialize(ivk, ivk_len) }?;
+
+        unsafe { c_get_result_res::<SaplingError>(ivk.encode().into_bytes(), encoded_len) }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn c_ivk_from_bech32(encoded: *const c_char, ivk_len: *mut size_t) -> *mut c_uchar {
+    c_init_lib();
+
+    c_ptr_catch_result(|| {
+        let encoded: &str = unsafe { c_deserialize_str(encoded) };
+
+        unsafe { c_serialize_res(IncomingViewingKey::decode(encoded), ivk_len) }
+    })
 }
\ No newline at end of file
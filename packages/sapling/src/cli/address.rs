@@ -0,0 +1,71 @@
+use std::convert::TryInto;
+
+use crate::address::get_xfvk_addresses;
+use crate::cli::args::Args;
+use crate::cli::errors::CliError;
+use crate::cli::key::read_xfvk;
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::traits::Serializable;
+
+// Prints `<diversifier index> <payment address>` for `--count` addresses starting at `--index`,
+// indices without a valid diversifier are skipped.
+pub(super) fn address(args: &Args) -> Result<String, SaplingError> {
+    let xfvk = read_xfvk(args)?;
+    let index = args.optional_parsed::<u128>("index")?.map(index_to_bytes).transpose()?;
+    let count = args.optional_parsed::<usize>("count")?.unwrap_or(1);
+
+    let lines = get_xfvk_addresses(&xfvk, index, count)?.iter()
+        .map(|address| Ok(format!("{} {}", index_from_bytes(address.0), hex::encode(address.1.serialize()?))))
+        .collect::<Result<Vec<String>, SaplingError>>()?;
+
+    Ok(lines.join("\n"))
+}
+
+fn index_to_bytes(index: u128) -> Result<[u8; 11], SaplingError> {
+    let bytes = index.to_le_bytes();
+    if bytes[11..].iter().any(|byte| *byte != 0) {
+        return Err(SaplingError::caused_by(CliError::InvalidArgument("index", String::from("index exceeds 88 bits"))));
+    }
+
+    Ok(bytes[..11].try_into().unwrap())
+}
+
+fn index_from_bytes(index: [u8; 11]) -> u128 {
+    let mut bytes = [0u8; 16];
+    bytes[..11].copy_from_slice(&index);
+
+    u128::from_le_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use zcash_primitives::zip32::{ExtendedFullViewingKey, ExtendedSpendingKey};
+
+    use crate::address::{get_next_xfvk_address, get_xfvk_address};
+    use crate::cli::run;
+    use crate::key::SaplingKey;
+
+    use super::*;
+
+    #[test]
+    fn prints_consecutive_addresses() {
+        let xsk = ExtendedSpendingKey::from_seed(&[0u8; 32], "m/").unwrap();
+        let xfvk = ExtendedFullViewingKey::from(&xsk);
+        let xfvk_hex = hex::encode(xfvk.serialize().unwrap());
+
+        let first = get_xfvk_address(&xfvk, Some(index_to_bytes(5).unwrap())).unwrap();
+        let second = get_next_xfvk_address(&xfvk, first.0).unwrap();
+        let expected = format!(
+            "{} {}\n{} {}",
+            index_from_bytes(first.0),
+            hex::encode(first.1.serialize().unwrap()),
+            index_from_bytes(second.0),
+            hex::encode(second.1.serialize().unwrap()),
+        );
+
+        let actual = run(vec!["address", "--xfvk", &xfvk_hex, "--index", "5", "--count", "2"].into_iter().map(String::from)).unwrap();
+
+        assert_eq!(actual, expected);
+        assert!(index_to_bytes(1 << 88).is_err());
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::str::FromStr;
+
+use crate::cli::errors::CliError;
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::utils::hex_utils::from_hex;
+
+// `<command> [--option value]... [--flag]...`, an option without a value is treated as a flag.
+#[derive(Debug, Default)]
+pub(super) struct Args {
+    pub command: Option<String>,
+    options: HashMap<String, String>,
+    flags: HashSet<String>,
+}
+
+impl Args {
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Args, SaplingError> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter().peekable();
+
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                Some(name) => {
+                    let has_value = matches!(args.peek(), Some(next) if !next.starts_with("--"));
+                    if has_value {
+                        parsed.options.insert(name.to_string(), args.next().unwrap());
+                    } else {
+                        parsed.flags.insert(name.to_string());
+                    }
+                }
+                None if parsed.command.is_none() => parsed.command = Some(arg),
+                None => return Err(SaplingError::caused_by(CliError::UnexpectedValue(arg))),
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    pub fn optional(&self, name: &'static str) -> Option<&str> {
+        self.options.get(name).map(String::as_str)
+    }
+
+    pub fn required(&self, name: &'static str) -> Result<&str, SaplingError> {
+        self.optional(name).ok_or_else(|| SaplingError::caused_by(CliError::MissingArgument(name)))
+    }
+
+    pub fn flag(&self, name: &'static str) -> bool {
+        self.flags.contains(name)
+    }
+
+    pub fn optional_hex(&self, name: &'static str) -> Result<Option<Vec<u8>>, SaplingError> {
+        self.optional(name).map(|value| decode_hex(name, value)).transpose()
+    }
+
+    pub fn required_hex(&self, name: &'static str) -> Result<Vec<u8>, SaplingError> {
+        decode_hex(name, self.required(name)?)
+    }
+
+    pub fn optional_parsed<T: FromStr>(&self, name: &'static str) -> Result<Option<T>, SaplingError> {
+        self.optional(name)
+            .map(|value| value.parse::<T>().map_err(|_| SaplingError::caused_by(CliError::InvalidArgument(name, format!("cannot parse `{}`", value)))))
+            .transpose()
+    }
+
+    pub fn required_file(&self, name: &'static str) -> Result<Vec<u8>, SaplingError> {
+        read_file(self.required(name)?)
+    }
+}
+
+pub(super) fn decode_hex(name: &'static str, value: &str) -> Result<Vec<u8>, SaplingError> {
+    let value = value.trim();
+    let value = value.strip_prefix("0x").unwrap_or(value);
+
+    from_hex(value).ok_or_else(|| SaplingError::caused_by(CliError::InvalidArgument(name, String::from("invalid hex"))))
+}
+
+pub(super) fn read_file(path: &str) -> Result<Vec<u8>, SaplingError> {
+    fs::read(path).map_err(|err| SaplingError::caused_by(CliError::ReadFailed(path.to_string(), err)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_command_options_and_flags() {
+        let args = Args::parse(to_args(&["inspect", "--transaction", "0xab", "--json", "--count", "3"])).unwrap();
+
+        assert_eq!(args.command, Some(String::from("inspect")));
+        assert_eq!(args.required_hex("transaction").unwrap(), vec![0xab]);
+        assert_eq!(args.optional_parsed::<u32>("count").unwrap(), Some(3));
+        assert!(args.flag("json"));
+        assert!(!args.flag("transaction"));
+        assert_eq!(args.optional("index"), None);
+    }
+
+    #[test]
+    fn fails_on_missing_and_invalid_arguments() {
+        let args = Args::parse(to_args(&["address", "--index", "first"])).unwrap();
+
+        assert_eq!(args.required("xfvk"), Err(SaplingError::caused_by(CliError::MissingArgument("xfvk"))));
+        assert_eq!(
+            args.optional_parsed::<u128>("index"),
+            Err(SaplingError::caused_by(CliError::InvalidArgument("index", String::from("cannot parse `first`"))))
+        );
+        assert!(Args::parse(to_args(&["address", "unexpected"])).is_err());
+    }
+}
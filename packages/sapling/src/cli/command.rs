@@ -0,0 +1,42 @@
+use crate::cli::address::address;
+use crate::cli::args::Args;
+use crate::cli::errors::CliError;
+use crate::cli::history::history;
+use crate::cli::key::{derive_key, ivk, ovk};
+use crate::cli::scan::scan;
+use crate::cli::transaction::{build, inspect};
+use crate::common::errors::{CausedBy, SaplingError};
+
+pub const USAGE: &str = "\
+usage: sapling-cli <command> [options]
+
+commands:
+  derive-key  (--seed <hex> | --mnemonic <words> [--passphrase <text>])
+              [--path <bip32 path> | --account <n> [--coin tezos|zcash|<coin type>]]
+  address     (--xfvk <hex> | --xsk <hex>) [--index <diversifier index>] [--count <n>]
+  ivk         (--xfvk <hex> | --xsk <hex>) [--bech32]
+  ovk         (--xfvk <hex> | --xsk <hex>)
+  inspect     (--transaction <hex> | --file <path>) [--json] [--parameters]
+  scan        (--xfvk <hex> | --xsk <hex> | --ivk <hex or bech32>) --state-diff <json file>
+  history     (--xfvk <hex> | --xsk <hex>) --transactions <json file> [--csv]
+  build       --input <json file> --spend-params <file> --output-params <file> [--quiet]
+  help";
+
+// Runs a command and returns its output, the arguments don't include the program name.
+pub fn run<I: IntoIterator<Item = String>>(args: I) -> Result<String, SaplingError> {
+    let args = Args::parse(args)?;
+
+    match args.command.as_deref() {
+        Some("derive-key") => derive_key(&args),
+        Some("address") => address(&args),
+        Some("ivk") => ivk(&args),
+        Some("ovk") => ovk(&args),
+        Some("inspect") => inspect(&args),
+        Some("scan") => scan(&args),
+        Some("history") => history(&args),
+        Some("build") => build(&args),
+        Some("help") => Ok(String::from(USAGE)),
+        Some(command) => Err(SaplingError::caused_by(CliError::UnknownCommand(command.to_string()))),
+        None => Err(SaplingError::caused_by(CliError::MissingCommand)),
+    }
+}
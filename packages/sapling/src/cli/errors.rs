@@ -0,0 +1,38 @@
+use std::io;
+
+use crate::common::errors::DetailedError;
+
+#[derive(Debug)]
+pub enum CliError {
+    MissingCommand,
+    UnknownCommand(String),
+    MissingArgument(&'static str),
+    UnexpectedValue(String),
+    InvalidArgument(&'static str, String),
+    InvalidMnemonic(String),
+    InvalidInput(String),
+    ReadFailed(String, io::Error),
+}
+
+impl DetailedError for CliError {
+    fn details(&self) -> String {
+        use CliError::*;
+
+        match self {
+            MissingCommand => String::from("No command specified"),
+            UnknownCommand(command) => format!("Unknown command `{}`", command),
+            MissingArgument(name) => format!("Missing argument `--{}`", name),
+            UnexpectedValue(value) => format!("Unexpected value `{}`", value),
+            InvalidArgument(name, cause) => format!("Invalid argument `--{}`: {}", name, cause),
+            InvalidMnemonic(cause) => format!("Invalid mnemonic: {}", cause),
+            InvalidInput(cause) => format!("Invalid input file: {}", cause),
+            ReadFailed(path, err) => format!("Could not read `{}`: {}", path, err),
+        }
+    }
+}
+
+impl PartialEq for CliError {
+    fn eq(&self, other: &Self) -> bool {
+        self.details() == other.details()
+    }
+}
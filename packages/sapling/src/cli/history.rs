@@ -0,0 +1,125 @@
+use std::convert::TryInto;
+
+use serde::Deserialize;
+use zcash_primitives::primitives::Nullifier;
+
+use crate::cli::args::{Args, decode_hex};
+use crate::cli::errors::CliError;
+use crate::cli::key::read_xfvk;
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::traits::Serializable;
+use crate::json::to_json;
+use crate::transaction::{build_history, HistoryOutput, HistoryTransaction, SizedOutputDescription};
+
+// Like the `scan` state diff, but grouped by transaction, the outputs are serialized [`SizedOutputDescription`]s.
+#[derive(Deserialize)]
+struct TransactionInput {
+    hash: String,
+    level: u64,
+    #[serde(default)]
+    nullifiers: Vec<String>,
+    #[serde(default)]
+    outputs: Vec<OutputInput>,
+}
+
+#[derive(Deserialize)]
+struct OutputInput {
+    position: u64,
+    description: String,
+}
+
+// Builds the history of the key from the `--transactions` JSON file, ordered by level,
+// and exports it as JSON or, with `--csv`, as CSV.
+pub(super) fn history(args: &Args) -> Result<String, SaplingError> {
+    let xfvk = read_xfvk(args)?;
+
+    let input: Vec<TransactionInput> = serde_json::from_slice(&args.required_file("transactions")?)
+        .map_err(|err| SaplingError::caused_by(CliError::InvalidInput(err.to_string())))?;
+    let transactions = input.into_iter()
+        .map(read_transaction)
+        .collect::<Result<Vec<HistoryTransaction>, SaplingError>>()?;
+
+    let history = build_history(&xfvk, &transactions)?;
+    if args.flag("csv") {
+        Ok(history.to_csv())
+    } else {
+        to_json(&history)
+    }
+}
+
+fn read_transaction(input: TransactionInput) -> Result<HistoryTransaction, SaplingError> {
+    let nullifiers = input.nullifiers.iter()
+        .map(|nullifier| {
+            let nullifier = decode_hex("nullifiers", nullifier)?;
+            let nullifier = nullifier.as_slice().try_into()
+                .map_err(|_| SaplingError::caused_by(CliError::InvalidArgument("nullifiers", String::from("a nullifier must be 32 bytes"))))?;
+
+            Ok(Nullifier(nullifier))
+        })
+        .collect::<Result<Vec<Nullifier>, SaplingError>>()?;
+    let outputs = input.outputs.iter()
+        .map(|output| {
+            let description = SizedOutputDescription::deserialize(decode_hex("description", &output.description)?)?;
+
+            Ok(HistoryOutput { position: output.position, description })
+        })
+        .collect::<Result<Vec<HistoryOutput>, SaplingError>>()?;
+
+    Ok(HistoryTransaction { hash: input.hash, level: input.level, nullifiers, outputs })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use zcash_primitives::note_encryption::OUT_CIPHERTEXT_SIZE;
+    use zcash_primitives::transaction::components::GROTH_PROOF_SIZE;
+    use zcash_primitives::zip32::{ExtendedFullViewingKey, ExtendedSpendingKey};
+
+    use crate::cli::run;
+    use crate::key::SaplingKey;
+    use crate::transaction::{create_note, encrypt_note_plaintext, rand_scalar};
+
+    use super::*;
+
+    #[test]
+    fn exports_history_as_json_and_csv() {
+        let xfvk = ExtendedFullViewingKey::from(&ExtendedSpendingKey::from_seed(&[0u8; 32], "m/").unwrap());
+        let (_, address) = xfvk.default_address().unwrap();
+        let note = create_note(&address, 100, rand_scalar()).unwrap();
+        let esk = rand_scalar();
+        let description = SizedOutputDescription {
+            cv: jubjub::ExtendedPoint::identity(),
+            cmu: note.cmu(),
+            ephemeral_key: (note.g_d * esk).into(),
+            enc_ciphertext: encrypt_note_plaintext(&note, &address, &esk, Some(b"hi"), 8).unwrap(),
+            out_ciphertext: [0u8; OUT_CIPHERTEXT_SIZE],
+            zkproof: [0u8; GROTH_PROOF_SIZE],
+        };
+
+        let transactions = format!(
+            r#"[{{"hash":"op1","level":7,"outputs":[{{"position":0,"description":"{}"}}]}}]"#,
+            hex::encode(description.serialize().unwrap()),
+        );
+        let path = env::temp_dir().join("airgap_sapling_cli_history.json");
+        fs::write(&path, transactions).unwrap();
+        let xfvk_hex = hex::encode(xfvk.serialize().unwrap());
+        let json = run(["history", "--xfvk", &xfvk_hex, "--transactions", path.to_str().unwrap()].iter().map(|arg| arg.to_string()));
+        let csv = run(["history", "--xfvk", &xfvk_hex, "--transactions", path.to_str().unwrap(), "--csv"].iter().map(|arg| arg.to_string()));
+        fs::remove_file(&path).unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&json.unwrap()).unwrap();
+        assert_eq!(json["entries"][0]["kind"], "received");
+        assert_eq!(json["entries"][0]["value"], 100);
+        assert_eq!(json["entries"][0]["memo_text"], "hi");
+        assert_eq!(json["balances"][0]["level"], 7);
+        assert_eq!(json["balances"][0]["balance"], 100);
+
+        let csv = csv.unwrap();
+        let rows: Vec<&str> = csv.lines().collect();
+        assert_eq!(rows.len(), 2);
+        assert!(rows[1].contains(",7,op1,0,received,zet1"));
+        assert!(rows[1].ends_with(",100,100,6869000000000000,hi"));
+    }
+}
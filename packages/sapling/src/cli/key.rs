@@ -0,0 +1,158 @@
+use std::convert::TryFrom;
+
+use bip39::Mnemonic;
+use zcash_primitives::zip32::{ExtendedFullViewingKey, ExtendedSpendingKey};
+
+use crate::cli::args::Args;
+use crate::cli::errors::CliError;
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::traits::Serializable;
+use crate::key::{Account, CoinType, crh_ivk, IncomingViewingKey, IVK_HRP, SaplingKey};
+
+// `--account` derives the key on the standard ZIP-32 path of the `--coin`, Tezos by default.
+pub(super) fn derive_key(args: &Args) -> Result<String, SaplingError> {
+    let seed = read_seed(args)?;
+
+    let xsk = match args.optional_parsed::<u32>("account")? {
+        Some(account) => Account::from_seed(&seed, read_coin_type(args)?, account)?.xsk,
+        None => ExtendedSpendingKey::from_seed(&seed, args.optional("path").unwrap_or("m/"))?,
+    };
+    let xfvk = ExtendedFullViewingKey::from(&xsk);
+
+    Ok(format!("xsk: {}\nxfvk: {}", hex::encode(xsk.serialize()?), hex::encode(xfvk.serialize()?)))
+}
+
+pub(super) fn ivk(args: &Args) -> Result<String, SaplingError> {
+    let xfvk = read_xfvk(args)?;
+
+    if args.flag("bech32") {
+        Ok(IncomingViewingKey::from(&xfvk).encode())
+    } else {
+        Ok(hex::encode(crh_ivk(&xfvk)))
+    }
+}
+
+pub(super) fn ovk(args: &Args) -> Result<String, SaplingError> {
+    let xfvk = read_xfvk(args)?;
+
+    Ok(hex::encode(xfvk.fvk.ovk.serialize()?))
+}
+
+// `--xsk` takes precedence, so the commands that only need a viewing key can be given either.
+pub(super) fn read_xfvk(args: &Args) -> Result<ExtendedFullViewingKey, SaplingError> {
+    match args.optional_hex("xsk")? {
+        Some(xsk) => Ok(ExtendedFullViewingKey::from(&ExtendedSpendingKey::deserialize(xsk)?)),
+        None => ExtendedFullViewingKey::deserialize(args.required_hex("xfvk")?),
+    }
+}
+
+// `--ivk` accepts both the hex and the Bech32 encoding.
+pub(super) fn read_ivk(args: &Args) -> Result<Option<IncomingViewingKey>, SaplingError> {
+    match args.optional("ivk") {
+        Some(ivk) if ivk.starts_with(IVK_HRP) => IncomingViewingKey::decode(ivk).map(Some),
+        Some(_) => IncomingViewingKey::deserialize(args.required_hex("ivk")?).map(Some),
+        None => Ok(None),
+    }
+}
+
+fn read_coin_type(args: &Args) -> Result<CoinType, SaplingError> {
+    match args.optional("coin") {
+        None | Some("tezos") => Ok(CoinType::Tezos),
+        Some("zcash") => Ok(CoinType::Zcash),
+        Some(coin) => {
+            let coin_type = coin.parse::<u32>()
+                .map_err(|_| SaplingError::caused_by(CliError::InvalidArgument("coin", format!("unknown coin `{}`", coin))))?;
+
+            CoinType::try_from(coin_type).map_err(SaplingError::caused_by)
+        }
+    }
+}
+
+fn read_seed(args: &Args) -> Result<Vec<u8>, SaplingError> {
+    if let Some(seed) = args.optional_hex("seed")? {
+        return Ok(seed);
+    }
+
+    let mnemonic = args.optional("mnemonic").ok_or_else(|| SaplingError::caused_by(CliError::MissingArgument("seed")))?;
+    let mnemonic = Mnemonic::parse_normalized(mnemonic).map_err(|err| SaplingError::caused_by(CliError::InvalidMnemonic(err.to_string())))?;
+    let passphrase = args.optional("passphrase").unwrap_or("");
+
+    Ok(mnemonic.to_seed_normalized(passphrase).to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::run;
+
+    use super::*;
+
+    const SEED: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn run_with(args: &[&str]) -> Result<String, SaplingError> {
+        run(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn derives_keys_from_seed_and_mnemonic() {
+        let seed = hex::decode(SEED).unwrap();
+        let xsk = ExtendedSpendingKey::from_seed(&seed, "m/0'").unwrap();
+        let xfvk = ExtendedFullViewingKey::from(&xsk);
+
+        let expected = format!("xsk: {}\nxfvk: {}", hex::encode(xsk.serialize().unwrap()), hex::encode(xfvk.serialize().unwrap()));
+        assert_eq!(run_with(&["derive-key", "--seed", SEED, "--path", "m/0'"]).unwrap(), expected);
+
+        let seed = Mnemonic::parse(MNEMONIC).unwrap().to_seed("");
+        let xsk = ExtendedSpendingKey::from_seed(&seed, "m/").unwrap();
+
+        let actual = run_with(&["derive-key", "--mnemonic", MNEMONIC]).unwrap();
+        assert!(actual.starts_with(&format!("xsk: {}\n", hex::encode(xsk.serialize().unwrap()))));
+
+        assert!(run_with(&["derive-key", "--mnemonic", "abandon abandon"]).is_err());
+    }
+
+    #[test]
+    fn derives_account_keys() {
+        let seed = hex::decode(SEED).unwrap();
+        let test_data = vec![
+            (vec!["--account", "1"], "m/32'/1729'/1'"),
+            (vec!["--account", "0", "--coin", "zcash"], "m/32'/133'/0'"),
+            (vec!["--account", "2", "--coin", "1"], "m/32'/1'/2'"),
+        ];
+
+        for (options, path) in test_data {
+            let xsk = ExtendedSpendingKey::from_seed(&seed, path).unwrap();
+            let actual = run_with(&[&["derive-key", "--seed", SEED], &options[..]].concat()).unwrap();
+
+            assert!(actual.starts_with(&format!("xsk: {}\n", hex::encode(xsk.serialize().unwrap()))));
+        }
+
+        assert_eq!(
+            run_with(&["derive-key", "--seed", SEED, "--account", "0", "--coin", "btc"]),
+            Err(SaplingError::caused_by(CliError::InvalidArgument("coin", String::from("unknown coin `btc`"))))
+        );
+        assert_eq!(
+            run_with(&["derive-key", "--seed", SEED, "--account", "0", "--coin", "2147483648"]),
+            Err(SaplingError::caused_by(CoinType::try_from(0x8000_0000).unwrap_err()))
+        );
+    }
+
+    #[test]
+    fn computes_viewing_keys_from_spending_or_viewing_key() {
+        let seed = hex::decode(SEED).unwrap();
+        let xsk = ExtendedSpendingKey::from_seed(&seed, "m/").unwrap();
+        let xfvk = ExtendedFullViewingKey::from(&xsk);
+        let xsk_hex = hex::encode(xsk.serialize().unwrap());
+        let xfvk_hex = hex::encode(xfvk.serialize().unwrap());
+
+        let expected_ivk = hex::encode(crh_ivk(&xfvk));
+        assert_eq!(run_with(&["ivk", "--xsk", &xsk_hex]).unwrap(), expected_ivk);
+        assert_eq!(run_with(&["ivk", "--xfvk", &xfvk_hex]).unwrap(), expected_ivk);
+        assert_eq!(run_with(&["ivk", "--xfvk", &xfvk_hex, "--bech32"]).unwrap(), IncomingViewingKey::from(&xfvk).encode());
+
+        let expected_ovk = hex::encode(xfvk.fvk.ovk.0);
+        assert_eq!(run_with(&["ovk", "--xfvk", &xfvk_hex]).unwrap(), expected_ovk);
+
+        assert_eq!(run_with(&["ovk"]), Err(SaplingError::caused_by(CliError::MissingArgument("xfvk"))));
+    }
+}
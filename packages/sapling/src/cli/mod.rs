@@ -0,0 +1,13 @@
+pub use command::{run, USAGE};
+pub use errors::CliError;
+
+mod address;
+mod history;
+mod key;
+mod scan;
+mod transaction;
+
+mod args;
+mod command;
+
+mod errors;
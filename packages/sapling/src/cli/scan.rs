@@ -0,0 +1,180 @@
+use serde::{Deserialize, Serialize};
+
+use crate::cli::args::{Args, decode_hex};
+use crate::cli::errors::CliError;
+use crate::cli::key::{read_ivk, read_xfvk};
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::traits::Serializable;
+use crate::key::IncomingViewingKey;
+use crate::transaction::{compute_nullifier, decrypt_note_ciphertext, Memo, SizedOutputDescription};
+
+// The outputs are serialized [`SizedOutputDescription`]s at their position in the commitment tree,
+// their memo size is derived from the length.
+#[derive(Deserialize)]
+struct StateDiff {
+    #[serde(default)]
+    outputs: Vec<StateDiffOutput>,
+    #[serde(default)]
+    nullifiers: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct StateDiffOutput {
+    position: u64,
+    description: String,
+}
+
+#[derive(Serialize)]
+struct ScannedNote {
+    position: u64,
+    address: String,
+    value: u64,
+    rcm: String,
+    memo: String,
+    memo_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nullifier: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    spent: Option<bool>,
+}
+
+// Trial-decrypts the outputs of the `--state-diff` JSON file with the incoming viewing key
+// and marks the notes whose nullifiers are listed in the file as spent.
+// An `--ivk` can't compute nullifiers, so the notes found with it have no spent status.
+pub(super) fn scan(args: &Args) -> Result<String, SaplingError> {
+    let (ivk, xfvk) = match read_ivk(args)? {
+        Some(ivk) => (ivk, None),
+        None => {
+            let xfvk = read_xfvk(args)?;
+            (IncomingViewingKey::from(&xfvk), Some(xfvk))
+        }
+    };
+    let ivk = ivk.to_sapling_ivk();
+
+    let state_diff: StateDiff = serde_json::from_slice(&args.required_file("state-diff")?)
+        .map_err(|err| SaplingError::caused_by(CliError::InvalidInput(err.to_string())))?;
+    let nullifiers = state_diff.nullifiers.iter()
+        .map(|nullifier| decode_hex("nullifiers", nullifier))
+        .collect::<Result<Vec<Vec<u8>>, SaplingError>>()?;
+
+    let mut notes = vec![];
+    for output in state_diff.outputs.iter() {
+        let description = SizedOutputDescription::deserialize(decode_hex("description", &output.description)?)?;
+        let decrypted = decrypt_note_ciphertext(
+            &ivk,
+            &description.ephemeral_key,
+            &description.cmu,
+            &description.enc_ciphertext,
+            description.memo_size(),
+        );
+
+        let decrypted = match decrypted {
+            Ok(decrypted) => decrypted,
+            Err(_) => continue,
+        };
+
+        let rcm = decrypted.note.rcm();
+        let nullifier = match &xfvk {
+            Some(xfvk) => Some(compute_nullifier(&xfvk.fvk.vk, &decrypted.address, decrypted.note.value, rcm, output.position)?),
+            None => None,
+        };
+
+        notes.push(ScannedNote {
+            position: output.position,
+            address: hex::encode(decrypted.address.serialize()?),
+            value: decrypted.note.value,
+            rcm: hex::encode(rcm.serialize()?),
+            memo: hex::encode(&decrypted.memo),
+            memo_text: match Memo::decode(&decrypted.memo) {
+                Ok(Memo::Text(text)) => Some(text),
+                _ => None,
+            },
+            nullifier: nullifier.map(|nullifier| hex::encode(nullifier.0)),
+            spent: nullifier.map(|nullifier| nullifiers.iter().any(|spent| spent[..] == nullifier.0[..])),
+        });
+    }
+
+    serde_json::to_string(&notes).map_err(|err| SaplingError::caused_by(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use zcash_primitives::note_encryption::OUT_CIPHERTEXT_SIZE;
+    use zcash_primitives::transaction::components::GROTH_PROOF_SIZE;
+    use zcash_primitives::zip32::{ExtendedFullViewingKey, ExtendedSpendingKey};
+
+    use crate::cli::run;
+    use crate::key::SaplingKey;
+    use crate::transaction::{create_note, encrypt_note_plaintext, rand_scalar};
+
+    use super::*;
+
+    fn output_description(xfvk: &ExtendedFullViewingKey, value: u64, memo: &[u8]) -> SizedOutputDescription {
+        let (_, address) = xfvk.default_address().unwrap();
+        let note = create_note(&address, value, rand_scalar()).unwrap();
+        let esk = rand_scalar();
+
+        SizedOutputDescription {
+            cv: jubjub::ExtendedPoint::identity(),
+            cmu: note.cmu(),
+            ephemeral_key: (note.g_d * esk).into(),
+            enc_ciphertext: encrypt_note_plaintext(&note, &address, &esk, Some(memo), 8).unwrap(),
+            out_ciphertext: [0u8; OUT_CIPHERTEXT_SIZE],
+            zkproof: [0u8; GROTH_PROOF_SIZE],
+        }
+    }
+
+    #[test]
+    fn scans_state_diff_for_owned_notes() {
+        let xsk = ExtendedSpendingKey::from_seed(&[0u8; 32], "m/").unwrap();
+        let xfvk = ExtendedFullViewingKey::from(&xsk);
+        let other_xfvk = ExtendedFullViewingKey::from(&ExtendedSpendingKey::from_seed(&[1u8; 32], "m/").unwrap());
+
+        let owned = output_description(&xfvk, 100, b"hi");
+        let spent = output_description(&xfvk, 200, &[]);
+        let foreign = output_description(&other_xfvk, 300, &[]);
+
+        let spent_note = decrypt_note_ciphertext(&xfvk.fvk.vk.ivk(), &spent.ephemeral_key, &spent.cmu, &spent.enc_ciphertext, 8).unwrap();
+        let spent_nullifier = compute_nullifier(&xfvk.fvk.vk, &spent_note.address, 200, spent_note.note.rcm(), 2).unwrap();
+
+        let state_diff = format!(
+            r#"{{"outputs":[{{"position":0,"description":"{}"}},{{"position":1,"description":"{}"}},{{"position":2,"description":"{}"}}],"nullifiers":["{}"]}}"#,
+            hex::encode(owned.serialize().unwrap()),
+            hex::encode(foreign.serialize().unwrap()),
+            hex::encode(spent.serialize().unwrap()),
+            hex::encode(spent_nullifier.0),
+        );
+
+        let path = env::temp_dir().join("airgap_sapling_cli_scan.json");
+        fs::write(&path, state_diff).unwrap();
+        let xfvk_hex = hex::encode(xfvk.serialize().unwrap());
+        let ivk_bech32 = IncomingViewingKey::from(&xfvk).encode();
+        let actual = run(["scan", "--xfvk", &xfvk_hex, "--state-diff", path.to_str().unwrap()].iter().map(|arg| arg.to_string()));
+        let actual_ivk = run(["scan", "--ivk", &ivk_bech32, "--state-diff", path.to_str().unwrap()].iter().map(|arg| arg.to_string()));
+        fs::remove_file(&path).unwrap();
+
+        let notes: serde_json::Value = serde_json::from_str(&actual.unwrap()).unwrap();
+        let notes = notes.as_array().unwrap();
+
+        assert_eq!(notes.len(), 2);
+        assert_eq!(notes[0]["position"], 0);
+        assert_eq!(notes[0]["value"], 100);
+        assert_eq!(notes[0]["memo"], "6869000000000000");
+        assert_eq!(notes[0]["memo_text"], "hi");
+        assert_eq!(notes[0]["spent"], false);
+        assert_eq!(notes[1]["position"], 2);
+        assert_eq!(notes[1]["value"], 200);
+        assert_eq!(notes[1]["spent"], true);
+
+        let notes_ivk: serde_json::Value = serde_json::from_str(&actual_ivk.unwrap()).unwrap();
+        let notes_ivk = notes_ivk.as_array().unwrap();
+
+        assert_eq!(notes_ivk.len(), 2);
+        assert_eq!(notes_ivk[0]["value"], 100);
+        assert_eq!(notes_ivk[1]["value"], 200);
+        assert!(notes_ivk.iter().all(|note| note.get("nullifier").is_none() && note.get("spent").is_none()));
+    }
+}
\ No newline at end of file
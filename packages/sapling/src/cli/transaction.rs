@@ -0,0 +1,250 @@
+use std::convert::{TryFrom, TryInto};
+use std::str;
+
+use serde::{Deserialize, Serialize};
+use zcash_primitives::keys::OutgoingViewingKey;
+use zcash_primitives::merkle_tree::MerklePath;
+use zcash_primitives::primitives::PaymentAddress;
+use zcash_primitives::sapling::Node;
+use zcash_primitives::zip32::ExtendedSpendingKey;
+
+use crate::cli::args::{Args, decode_hex, read_file};
+use crate::cli::errors::CliError;
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::traits::Serializable;
+use crate::json::to_json;
+use crate::transaction::{
+    CancellationToken,
+    create_binding_sig,
+    OutputInput,
+    prepare_proof_parameters,
+    ProvingBuilder,
+    rand_scalar,
+    SaplingTransaction,
+    ShieldedPoolParameters,
+    sign_spend_description,
+    SpendInput,
+};
+
+// Decodes a Tezos `sapling_transaction` or, with `--parameters`, shielded pool contract parameters (packed or not).
+pub(super) fn inspect(args: &Args) -> Result<String, SaplingError> {
+    let bytes = match args.optional("file") {
+        Some(path) => decode_file(&read_file(path)?),
+        None => args.required_hex("transaction")?,
+    };
+
+    if args.flag("parameters") {
+        let parameters = match bytes.first() {
+            Some(0x05) => ShieldedPoolParameters::unpack(&bytes)?,
+            _ => ShieldedPoolParameters::deserialize(bytes)?,
+        };
+
+        return to_json(&parameters);
+    }
+
+    let transaction = SaplingTransaction::read(&bytes).map_err(SaplingError::caused_by)?;
+    if args.flag("json") {
+        to_json(&transaction)
+    } else {
+        Ok(transaction.to_string())
+    }
+}
+
+#[derive(Deserialize)]
+struct BuildInput {
+    sighash: String,
+    #[serde(default)]
+    spends: Vec<SpendJson>,
+    #[serde(default)]
+    outputs: Vec<OutputJson>,
+}
+
+#[derive(Deserialize)]
+struct SpendJson {
+    xsk: String,
+    address: String,
+    value: u64,
+    rcm: String,
+    ar: Option<String>,
+    anchor: String,
+    merkle_path: String,
+}
+
+#[derive(Deserialize)]
+struct OutputJson {
+    ovk: String,
+    address: String,
+    value: u64,
+    rcm: Option<String>,
+    esk: Option<String>,
+    memo: Option<String>,
+}
+
+#[derive(Serialize)]
+struct BuildOutput {
+    spend_descriptions: Vec<String>,
+    output_descriptions: Vec<String>,
+    binding_sig: String,
+    balance: i64,
+}
+
+// Proves and signs the descriptions listed in the `--input` JSON file with the `--spend-params` and `--output-params` files.
+// The sighash is provided by the caller, since it commits to data of the target chain (e.g. Tezos anti-replay).
+pub(super) fn build(args: &Args) -> Result<String, SaplingError> {
+    let input: BuildInput = serde_json::from_slice(&args.required_file("input")?)
+        .map_err(|err| SaplingError::caused_by(CliError::InvalidInput(err.to_string())))?;
+    let sighash = read_sighash(&input.sighash)?;
+
+    let mut builder = ProvingBuilder::new();
+    let mut spending_keys = Vec::with_capacity(input.spends.len());
+    let mut balance: i128 = 0;
+
+    for spend in input.spends.iter() {
+        let xsk = ExtendedSpendingKey::deserialize(decode_hex("xsk", &spend.xsk)?)?;
+        let ar = match &spend.ar {
+            Some(ar) => jubjub::Scalar::deserialize(decode_hex("ar", ar)?)?,
+            None => rand_scalar(),
+        };
+
+        builder.add_spend(SpendInput {
+            pak: xsk.expsk.proof_generation_key(),
+            address: PaymentAddress::deserialize(decode_hex("address", &spend.address)?)?,
+            value: spend.value,
+            rcm: jubjub::Scalar::deserialize(decode_hex("rcm", &spend.rcm)?)?,
+            ar,
+            anchor: bls12_381::Scalar::deserialize(decode_hex("anchor", &spend.anchor)?)?,
+            merkle_path: MerklePath::<Node>::deserialize(decode_hex("merkle_path", &spend.merkle_path)?)?,
+        });
+        spending_keys.push((xsk, ar));
+        balance += i128::from(spend.value);
+    }
+
+    for output in input.outputs.iter() {
+        let rcm = match &output.rcm {
+            Some(rcm) => jubjub::Scalar::deserialize(decode_hex("rcm", rcm)?)?,
+            None => rand_scalar(),
+        };
+
+        builder.add_output(OutputInput {
+            ovk: OutgoingViewingKey::deserialize(decode_hex("ovk", &output.ovk)?)?,
+            address: PaymentAddress::deserialize(decode_hex("address", &output.address)?)?,
+            value: output.value,
+            rcm,
+            esk: output.esk.as_deref().map(|esk| decode_hex("esk", esk).and_then(jubjub::Scalar::deserialize)).transpose()?,
+            memo: output.memo.as_deref().map(|memo| decode_hex("memo", memo)).transpose()?,
+        });
+        balance -= i128::from(output.value);
+    }
+
+    let balance = i64::try_from(balance).map_err(|_| SaplingError::caused_by(CliError::InvalidInput(String::from("balance is outside of the i64 range"))))?;
+
+    let spend_params = args.required_file("spend-params")?;
+    let output_params = args.required_file("output-params")?;
+    let params = prepare_proof_parameters(&spend_params, &output_params);
+
+    let quiet = args.flag("quiet");
+    let mut proved = builder.prove(
+        &params,
+        |completed, total| if !quiet { eprintln!("proved {}/{}", completed, total) },
+        &CancellationToken::new(),
+    )?;
+
+    let spend_descriptions = proved.spend_descriptions.into_iter()
+        .zip(spending_keys)
+        .map(|(description, (xsk, ar))| sign_spend_description(description, &xsk, ar, sighash)?.serialize().map(hex::encode))
+        .collect::<Result<Vec<String>, SaplingError>>()?;
+    let output_descriptions = proved.output_descriptions.iter()
+        .map(|description| description.serialize().map(hex::encode))
+        .collect::<Result<Vec<String>, SaplingError>>()?;
+    let binding_sig = create_binding_sig(&mut proved.ctx, balance, sighash)?;
+
+    let output = BuildOutput {
+        spend_descriptions,
+        output_descriptions,
+        binding_sig: hex::encode(binding_sig.serialize()?),
+        balance,
+    };
+
+    serde_json::to_string(&output).map_err(|err| SaplingError::caused_by(err.to_string()))
+}
+
+fn read_sighash(sighash: &str) -> Result<[u8; 32], SaplingError> {
+    let bytes = decode_hex("sighash", sighash)?;
+
+    bytes.try_into().map_err(|bytes: Vec<u8>| {
+        SaplingError::caused_by(CliError::InvalidArgument("sighash", format!("expected 32 bytes, got {}", bytes.len())))
+    })
+}
+
+// Files can hold either the raw bytes or their hex encoding.
+fn decode_file(bytes: &[u8]) -> Vec<u8> {
+    str::from_utf8(bytes).ok()
+        .and_then(|text| decode_hex("file", text).ok())
+        .unwrap_or_else(|| bytes.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use zcash_primitives::redjubjub::Signature;
+
+    use crate::cli::run;
+
+    use super::*;
+
+    fn run_with(args: &[&str]) -> Result<String, SaplingError> {
+        run(args.iter().map(|arg| arg.to_string()))
+    }
+
+    fn empty_transaction() -> SaplingTransaction {
+        SaplingTransaction {
+            spend_descriptions: vec![],
+            output_descriptions: vec![],
+            binding_sig: Signature::read(&[9u8; 64][..]).unwrap(),
+            balance: 5,
+            root: bls12_381::Scalar::zero(),
+            bound_data: vec![],
+        }
+    }
+
+    #[test]
+    fn inspects_transaction_from_hex_and_file() {
+        let transaction = empty_transaction();
+        let serialized = transaction.serialize().unwrap();
+        let serialized_hex = hex::encode(&serialized);
+
+        assert_eq!(run_with(&["inspect", "--transaction", &serialized_hex]).unwrap(), transaction.to_string());
+        assert_eq!(run_with(&["inspect", "--transaction", &serialized_hex, "--json"]).unwrap(), to_json(&transaction).unwrap());
+
+        let path = env::temp_dir().join("airgap_sapling_cli_inspect.bin");
+        fs::write(&path, &serialized).unwrap();
+        let actual = run_with(&["inspect", "--file", path.to_str().unwrap(), "--json"]);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(actual.unwrap(), to_json(&transaction).unwrap());
+    }
+
+    #[test]
+    fn inspects_shielded_pool_parameters() {
+        let parameters = ShieldedPoolParameters { transfers: vec![] };
+        let packed = hex::encode(parameters.pack().unwrap());
+
+        assert_eq!(run_with(&["inspect", "--transaction", &packed, "--parameters"]).unwrap(), "[]");
+    }
+
+    #[test]
+    fn fails_to_build_from_invalid_input() {
+        let path = env::temp_dir().join("airgap_sapling_cli_build.json");
+        fs::write(&path, r#"{"sighash":"00","spends":[],"outputs":[]}"#).unwrap();
+        let actual = run_with(&["build", "--input", path.to_str().unwrap()]);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            actual,
+            Err(SaplingError::caused_by(CliError::InvalidArgument("sighash", String::from("expected 32 bytes, got 1"))))
+        );
+        assert!(run_with(&["build", "--input", "/nonexistent/input.json"]).is_err());
+    }
+}
@@ -0,0 +1,20 @@
+use crate::common::errors::DetailedError;
+
+#[derive(Debug, PartialEq)]
+pub enum DeserializationError {
+    InvalidLength(&'static str, usize, usize),
+    InvalidEncoding(&'static str),
+    TrailingBytes(&'static str, usize),
+}
+
+impl DetailedError for DeserializationError {
+    fn details(&self) -> String {
+        use DeserializationError::*;
+
+        match self {
+            InvalidLength(name, expected, actual) => format!("invalid {} length, expected {}, got {}", name, expected, actual),
+            InvalidEncoding(name) => format!("invalid {} encoding", name),
+            TrailingBytes(name, count) => format!("{} is followed by {} unexpected bytes", name, count),
+        }
+    }
+}
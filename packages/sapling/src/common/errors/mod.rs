@@ -1,7 +1,9 @@
 pub use caused_by::CausedBy;
+pub use deserialization_error::DeserializationError;
 pub use detailed_error::DetailedError;
 pub use sapling_error::SaplingError;
 
 mod caused_by;
+mod deserialization_error;
 mod detailed_error;
 mod sapling_error;
\ No newline at end of file
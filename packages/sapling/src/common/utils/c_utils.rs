@@ -17,6 +17,13 @@ pub fn c_ptr_catch_result<F, E>(f: F) -> *mut c_uchar
     catch_result(f).unwrap_or(ptr::null_mut())
 }
 
+pub fn c_ref_catch_result<F, T, E>(f: F) -> *mut T
+    where F: FnOnce() -> Result<*mut T, E> + UnwindSafe,
+          E: Display {
+
+    catch_result(f).unwrap_or(ptr::null_mut())
+}
+
 pub fn c_bool_catch_result<F, R, E>(f: F) -> bool
     where F: FnOnce() -> Result<R, E> + UnwindSafe,
           E: Display {
@@ -67,8 +74,13 @@ pub unsafe fn c_deserialize<S, E>(bytes: *const c_uchar, len: size_t) -> Result<
     S::deserialize(bytes.to_vec())
 }
 
+// `from_raw_parts` requires a non-null pointer, even for an empty slice.
 pub unsafe fn c_deserialize_slice<'a>(bytes: *const c_uchar, len: size_t) -> &'a[u8] {
-    slice::from_raw_parts(bytes, len)
+    if bytes.is_null() {
+        &[]
+    } else {
+        slice::from_raw_parts(bytes, len)
+    }
 }
 
 pub unsafe fn c_deserialize_str<'a>(chars: *const c_char) -> &'a str {
@@ -89,6 +101,10 @@ pub unsafe fn c_drop_reference<T>(pointer: *mut T) {
     drop(Box::from_raw(pointer));
 }
 
+pub unsafe fn c_take_reference<T>(pointer: *mut T) -> T {
+    *Box::from_raw(pointer)
+}
+
 fn catch_result<F, R, E>(f: F) -> Result<R, String>
     where F: FnOnce() -> Result<R, E> + UnwindSafe,
           E: Display {
@@ -0,0 +1,58 @@
+use std::convert::TryInto;
+use std::io;
+
+use crate::common::errors::{CausedBy, DeserializationError, DetailedError, SaplingError};
+use crate::common::utils::assert_utils::assert_value_or_error;
+
+pub fn to_array<const N: usize>(name: &'static str, bytes: &[u8]) -> Result<[u8; N], DeserializationError> {
+    bytes.try_into().map_err(|_| DeserializationError::InvalidLength(name, N, bytes.len()))
+}
+
+// `read` on its own stops after the value and would silently accept any bytes that follow it.
+pub fn read_all<T, F>(name: &'static str, bytes: &[u8], read: F) -> io::Result<T>
+    where F: FnOnce(&mut &[u8]) -> io::Result<T> {
+
+    let mut reader = bytes;
+    let value = read(&mut reader)?;
+    if !reader.is_empty() {
+        let error = DeserializationError::TrailingBytes(name, reader.len());
+        return Err(io::Error::new(io::ErrorKind::InvalidData, error.details()));
+    }
+
+    Ok(value)
+}
+
+// Concatenated 32 byte values, as passed through the bindings.
+pub fn read_chunks<T, F>(name: &'static str, bytes: &[u8], read: F) -> Result<Vec<T>, SaplingError>
+    where F: FnMut(&[u8; 32]) -> Result<T, SaplingError> {
+
+    let chunks = bytes.chunks_exact(32);
+    let remainder = chunks.remainder().len();
+    assert_value_or_error(remainder == 0, DeserializationError::InvalidLength(name, bytes.len() - remainder, bytes.len()))
+        .map_err(SaplingError::caused_by)?;
+
+    chunks.map(|chunk| chunk.try_into().unwrap()).map(read).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_whole_slice_only() {
+        let read_u8 = |reader: &mut &[u8]| {
+            let mut byte = [0u8; 1];
+            io::Read::read_exact(reader, &mut byte)?;
+
+            Ok(byte[0])
+        };
+
+        assert_eq!(read_all("byte", &[1], read_u8).unwrap(), 1);
+        assert_eq!(read_all("byte", &[], read_u8).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(
+            read_all("byte", &[1, 2, 3], read_u8).unwrap_err().to_string(),
+            DeserializationError::TrailingBytes("byte", 2).details()
+        );
+        assert_eq!(to_array::<2>("pair", &[1]), Err(DeserializationError::InvalidLength("pair", 2, 1)));
+    }
+}
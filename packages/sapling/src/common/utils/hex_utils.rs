@@ -0,0 +1,18 @@
+pub fn from_hex(value: &str) -> Option<Vec<u8>> {
+    hex::decode(value).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_hex() {
+        assert_eq!(from_hex(""), Some(vec![]));
+        assert_eq!(from_hex("000aFf"), Some(vec![0x00, 0x0a, 0xff]));
+        assert_eq!(from_hex("0"), None);
+        assert_eq!(from_hex("0g"), None);
+        assert_eq!(from_hex("+1"), None);
+        assert_eq!(from_hex("é0"), None);
+    }
+}
@@ -0,0 +1,23 @@
+use std::ptr;
+use std::sync::atomic::{compiler_fence, Ordering};
+
+// Volatile writes, so that clearing a buffer that is about to be dropped isn't optimized away.
+pub fn zeroize(bytes: &mut [u8]) {
+    for byte in bytes.iter_mut() {
+        unsafe { ptr::write_volatile(byte, 0) };
+    }
+    compiler_fence(Ordering::SeqCst);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zeroizes_bytes() {
+        let mut bytes = vec![1u8, 2, 3, 4];
+        zeroize(&mut bytes);
+
+        assert_eq!(bytes, vec![0u8; 4]);
+    }
+}
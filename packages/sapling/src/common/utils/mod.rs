@@ -5,6 +5,9 @@ pub mod c_utils;
 pub mod wasm_utils;
 
 pub mod assert_utils;
+pub mod deserialize_utils;
+pub mod hex_utils;
+pub mod memory_utils;
 pub mod option_utils;
 pub mod regex_utils;
-pub mod serializable_impl;
+pub mod serializable_impl;
\ No newline at end of file
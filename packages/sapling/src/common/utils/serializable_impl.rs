@@ -1,5 +1,6 @@
-use crate::common::errors::SaplingError;
+use crate::common::errors::{CausedBy, DeserializationError, SaplingError};
 use crate::common::traits::Serializable;
+use crate::common::utils::deserialize_utils::to_array;
 use crate::common::utils::option_utils::ct_unwrap;
 use group::GroupEncoding;
 
@@ -7,16 +8,14 @@ impl Serializable<Vec<u8>, SaplingError> for jubjub::Scalar {
     fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
         match serialized.len() {
             64 => {
-                let mut bytes = [0u8; 64];
-                bytes.copy_from_slice(&serialized[..]);
+                let bytes = to_array::<64>("jubjub scalar", &serialized).map_err(SaplingError::caused_by)?;
                 Ok(jubjub::Scalar::from_bytes_wide(&bytes))
             },
-            32 => {
-                let mut bytes = [0u8; 32];
-                bytes.copy_from_slice(&serialized[..]);
-                ct_unwrap(jubjub::Scalar::from_bytes(&bytes)).ok_or_else(SaplingError::new)
-            },
-            _ => Err(SaplingError::new())
+            _ => {
+                let bytes = to_array::<32>("jubjub scalar", &serialized).map_err(SaplingError::caused_by)?;
+                ct_unwrap(jubjub::Scalar::from_bytes(&bytes))
+                    .ok_or_else(|| SaplingError::caused_by(DeserializationError::InvalidEncoding("jubjub scalar")))
+            }
         }
     }
 
@@ -29,16 +28,14 @@ impl Serializable<Vec<u8>, SaplingError> for bls12_381::Scalar {
     fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> where Self: Sized {
         match serialized.len() {
             64 => {
-                let mut bytes = [0u8; 64];
-                bytes.copy_from_slice(&serialized[..]);
+                let bytes = to_array::<64>("bls12-381 scalar", &serialized).map_err(SaplingError::caused_by)?;
                 Ok(bls12_381::Scalar::from_bytes_wide(&bytes))
             },
-            32 => {
-                let mut bytes = [0u8; 32];
-                bytes.copy_from_slice(&serialized[..]);
-                ct_unwrap(bls12_381::Scalar::from_bytes(&bytes)).ok_or_else(SaplingError::new)
-            },
-            _ => Err(SaplingError::new())
+            _ => {
+                let bytes = to_array::<32>("bls12-381 scalar", &serialized).map_err(SaplingError::caused_by)?;
+                ct_unwrap(bls12_381::Scalar::from_bytes(&bytes))
+                    .ok_or_else(|| SaplingError::caused_by(DeserializationError::InvalidEncoding("bls12-381 scalar")))
+            }
         }
     }
 
@@ -49,9 +46,9 @@ impl Serializable<Vec<u8>, SaplingError> for bls12_381::Scalar {
 
 impl Serializable<Vec<u8>, SaplingError> for jubjub::ExtendedPoint {
     fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
-        let mut bytes = [0u8; 32];
-        bytes.copy_from_slice(&serialized[..]);
-        ct_unwrap(jubjub::ExtendedPoint::from_bytes(&bytes)).ok_or_else(SaplingError::new)
+        let bytes = to_array::<32>("jubjub point", &serialized).map_err(SaplingError::caused_by)?;
+        ct_unwrap(jubjub::ExtendedPoint::from_bytes(&bytes))
+            .ok_or_else(|| SaplingError::caused_by(DeserializationError::InvalidEncoding("jubjub point")))
     }
 
     fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
@@ -61,12 +58,37 @@ impl Serializable<Vec<u8>, SaplingError> for jubjub::ExtendedPoint {
 
 impl Serializable<Vec<u8>, SaplingError> for jubjub::SubgroupPoint {
     fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> where Self: Sized {
-        let mut bytes = [0u8; 32];
-        bytes.copy_from_slice(&serialized[..]);
-        ct_unwrap(jubjub::SubgroupPoint::from_bytes(&bytes)).ok_or_else(SaplingError::new)
+        let bytes = to_array::<32>("jubjub subgroup point", &serialized).map_err(SaplingError::caused_by)?;
+        ct_unwrap(jubjub::SubgroupPoint::from_bytes(&bytes))
+            .ok_or_else(|| SaplingError::caused_by(DeserializationError::InvalidEncoding("jubjub subgroup point")))
     }
 
     fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
         Ok(self.to_bytes().to_vec())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fails_to_deserialize_points_and_scalars_of_invalid_length() {
+        assert_eq!(
+            jubjub::ExtendedPoint::deserialize(vec![0u8; 31]).unwrap_err(),
+            SaplingError::caused_by(DeserializationError::InvalidLength("jubjub point", 32, 31))
+        );
+        assert_eq!(
+            jubjub::SubgroupPoint::deserialize(vec![]).unwrap_err(),
+            SaplingError::caused_by(DeserializationError::InvalidLength("jubjub subgroup point", 32, 0))
+        );
+        assert_eq!(
+            jubjub::Scalar::deserialize(vec![0u8; 33]).unwrap_err(),
+            SaplingError::caused_by(DeserializationError::InvalidLength("jubjub scalar", 32, 33))
+        );
+        assert_eq!(
+            bls12_381::Scalar::deserialize(vec![0xffu8; 32]).unwrap_err(),
+            SaplingError::caused_by(DeserializationError::InvalidEncoding("bls12-381 scalar"))
+        );
+    }
+}
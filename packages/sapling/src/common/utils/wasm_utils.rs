@@ -2,6 +2,7 @@ use wasm_bindgen::__rt::WasmRefCell;
 use wasm_bindgen::JsValue;
 
 use crate::common::traits::Serializable;
+use crate::json::{from_json, JsonRepresentable, to_json};
 
 pub fn js_serialize<S, E>(value: S) -> Result<Vec<u8>, JsValue> 
     where S: Serializable<Vec<u8>, E>,
@@ -26,6 +27,18 @@ pub fn js_deserialize<S, E>(bytes: &[u8]) -> Result<S, JsValue>
     S::deserialize(bytes.to_vec()).map_err(|err| JsValue::from(err.to_string()))
 }
 
+pub fn js_to_object<T: JsonRepresentable>(value: &T) -> Result<JsValue, JsValue> {
+    let json = to_json(value).map_err(js_error_from)?;
+
+    js_sys::JSON::parse(&json)
+}
+
+pub fn js_from_object<T: JsonRepresentable>(object: &JsValue) -> Result<T, JsValue> {
+    let json: String = js_sys::JSON::stringify(object)?.into();
+
+    from_json(&json).map_err(js_error_from)
+}
+
 pub fn js_result_from<O, E: ToString>(error: E) -> Result<O, JsValue> {
     Err(js_error_from(error))
 }
@@ -53,6 +66,12 @@ pub unsafe fn js_drop_reference<T>(pointer: u32) {
     drop(Box::from_raw(pointer));
 }
 
+pub unsafe fn js_take_reference<T>(pointer: u32) -> T {
+    let pointer = pointer as *mut WasmRefCell<T>;
+    (*pointer).borrow_mut(); // ensure no active borrows
+    Box::from_raw(pointer).into_inner()
+}
+
 #[cfg(test)]
 mod tests {
     use std::fmt;
@@ -0,0 +1,217 @@
+use std::str;
+
+use zcash_primitives::keys::OutgoingViewingKey;
+use zcash_primitives::merkle_tree::MerklePath;
+use zcash_primitives::primitives::{PaymentAddress, ProofGenerationKey};
+use zcash_primitives::redjubjub::Signature;
+use zcash_primitives::sapling::Node;
+use zcash_primitives::transaction::components::{OutputDescription, SpendDescription};
+use zcash_primitives::zip32::{ExtendedFullViewingKey, ExtendedSpendingKey};
+
+use crate::address::{decode_payment_address, IndexedAddress, OwnershipProof, PaymentRequest};
+use crate::common::errors::SaplingError;
+use crate::common::traits::Serializable;
+use crate::key::{
+    DkgRound1Package,
+    DkgRound1Secret,
+    DkgRound2Package,
+    EncryptedKeystore,
+    IncomingViewingKey,
+    KeyPackage,
+    PublicKeyPackage,
+    SignatureShare,
+    SigningCommitments,
+    SigningPackage,
+};
+use crate::transaction::{
+    CommitmentTreeFrontier,
+    DecryptedNote,
+    EncryptedMessage,
+    KeyHash,
+    Memo,
+    PartialOutputDescription,
+    PaymentDisclosure,
+    SaplingState,
+    SaplingTransaction,
+    ShieldedPoolParameters,
+    SizedOutputDescription,
+    UnsignedSpendDescription,
+};
+
+pub type EntryPoint = fn(&[u8]);
+
+// Every entry point that parses untrusted bytes, the results are discarded since only panics are of interest.
+pub const ENTRY_POINTS: &[(&str, EntryPoint)] = &[
+    ("jubjub_scalar", deserialize::<jubjub::Scalar>),
+    ("bls12_381_scalar", deserialize::<bls12_381::Scalar>),
+    ("jubjub_point", deserialize::<jubjub::ExtendedPoint>),
+    ("jubjub_subgroup_point", deserialize::<jubjub::SubgroupPoint>),
+    ("proof_generation_key", deserialize::<ProofGenerationKey>),
+    ("outgoing_viewing_key", deserialize::<OutgoingViewingKey>),
+    ("extended_spending_key", deserialize::<ExtendedSpendingKey>),
+    ("extended_full_viewing_key", deserialize::<ExtendedFullViewingKey>),
+    ("encrypted_keystore", deserialize::<EncryptedKeystore>),
+    ("incoming_viewing_key", deserialize::<IncomingViewingKey>),
+    ("incoming_viewing_key_bech32", |data| { let _ = str::from_utf8(data).map(IncomingViewingKey::decode); }),
+    ("frost_dkg_round1_secret", deserialize::<DkgRound1Secret>),
+    ("frost_dkg_round1_package", deserialize::<DkgRound1Package>),
+    ("frost_dkg_round2_package", deserialize::<DkgRound2Package>),
+    ("frost_key_package", deserialize::<KeyPackage>),
+    ("frost_public_key_package", deserialize::<PublicKeyPackage>),
+    ("frost_signing_commitments", deserialize::<SigningCommitments>),
+    ("frost_signing_package", deserialize::<SigningPackage>),
+    ("frost_signature_share", deserialize::<SignatureShare>),
+    ("payment_address", deserialize::<PaymentAddress>),
+    ("indexed_address", deserialize::<IndexedAddress>),
+    ("ownership_proof", deserialize::<OwnershipProof>),
+    ("unsigned_spend_description", deserialize::<UnsignedSpendDescription>),
+    ("unsigned_spend_description_read", |data| { let _ = UnsignedSpendDescription::read(&mut &data[..]); }),
+    ("spend_description", deserialize::<SpendDescription>),
+    ("output_description", deserialize::<OutputDescription>),
+    ("partial_output_description", deserialize::<PartialOutputDescription>),
+    ("partial_output_description_read", |data| { let _ = PartialOutputDescription::read(&mut &data[..]); }),
+    ("sized_output_description", deserialize::<SizedOutputDescription>),
+    ("sized_output_description_read", read_sized_output_description),
+    ("signature", deserialize::<Signature>),
+    ("merkle_path", deserialize::<MerklePath<Node>>),
+    ("commitment_tree_frontier", deserialize::<CommitmentTreeFrontier>),
+    ("sapling_state", deserialize::<SaplingState>),
+    ("decrypted_note", deserialize::<DecryptedNote>),
+    ("payment_disclosure", deserialize::<PaymentDisclosure>),
+    ("encrypted_message", deserialize::<EncryptedMessage>),
+    ("key_hash", deserialize::<KeyHash>),
+    ("key_hash_str", |data| { let _ = str::from_utf8(data).map(str::parse::<KeyHash>); }),
+    ("sapling_transaction", deserialize::<SaplingTransaction>),
+    ("shielded_pool_parameters", deserialize::<ShieldedPoolParameters>),
+    ("shielded_pool_parameters_unpack", |data| { let _ = ShieldedPoolParameters::unpack(data); }),
+    ("memo", |data| { let _ = Memo::decode(data); }),
+    ("payment_request", |data| { let _ = str::from_utf8(data).map(str::parse::<PaymentRequest>); }),
+    ("tezos_payment_address", |data| { let _ = str::from_utf8(data).map(decode_payment_address); }),
+];
+
+// Returns `false` if there's no entry point with the given name.
+pub fn fuzz(name: &str, data: &[u8]) -> bool {
+    match ENTRY_POINTS.iter().find(|(entry_point, _)| *entry_point == name) {
+        Some((_, entry_point)) => {
+            entry_point(data);
+            true
+        },
+        None => false,
+    }
+}
+
+fn deserialize<S: Serializable<Vec<u8>, SaplingError>>(data: &[u8]) {
+    let _ = S::deserialize(data.to_vec());
+}
+
+// The first 2 bytes (LE) are the memo size, so the fuzzer can't make it allocate arbitrarily large buffers.
+fn read_sized_output_description(data: &[u8]) {
+    if data.len() < 2 {
+        return;
+    }
+
+    let memo_size = u16::from_le_bytes([data[0], data[1]]) as usize;
+    let _ = SizedOutputDescription::read(&mut &data[2..], memo_size);
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use std::convert::TryInto;
+
+    use group::Group;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use zcash_primitives::primitives::Nullifier;
+    use zcash_primitives::redjubjub::PublicKey;
+    use zcash_primitives::transaction::components::GROTH_PROOF_SIZE;
+
+    use crate::address::sign_address_ownership;
+    use crate::key::{dkg_round1, SaplingKey};
+
+    use super::*;
+
+    type Validator = fn(Vec<u8>) -> bool;
+
+    fn is_valid<S: Serializable<Vec<u8>, SaplingError>>(bytes: Vec<u8>) -> bool {
+        S::deserialize(bytes).is_ok()
+    }
+
+    fn valid_encodings(seed: [u8; 32]) -> Vec<(Vec<u8>, Validator)> {
+        let xsk = ExtendedSpendingKey::from_seed(&seed, "m/").unwrap();
+        let xfvk = ExtendedFullViewingKey::from(&xsk);
+        let (index, address) = xfvk.default_address().unwrap();
+        let unsigned_spend_description = UnsignedSpendDescription {
+            cv: jubjub::ExtendedPoint::generator(),
+            anchor: bls12_381::Scalar::from(1u64),
+            nullifier: Nullifier(seed),
+            rk: PublicKey(jubjub::ExtendedPoint::generator()),
+            zkproof: [0u8; GROTH_PROOF_SIZE],
+        };
+        let dkg_secret = dkg_round1(1, 2, 3).unwrap();
+        let commitment = Node::new(bls12_381::Scalar::from(u64::from_le_bytes(seed[0..8].try_into().unwrap())).to_bytes());
+
+        vec![
+            (xsk.serialize().unwrap(), is_valid::<ExtendedSpendingKey>),
+            (xfvk.serialize().unwrap(), is_valid::<ExtendedFullViewingKey>),
+            (IncomingViewingKey::from(&xfvk).serialize().unwrap(), is_valid::<IncomingViewingKey>),
+            (xsk.expsk.proof_generation_key().serialize().unwrap(), is_valid::<ProofGenerationKey>),
+            (xsk.expsk.ovk.serialize().unwrap(), is_valid::<OutgoingViewingKey>),
+            (address.serialize().unwrap(), is_valid::<PaymentAddress>),
+            (sign_address_ownership(&xsk, &address, &seed).unwrap().serialize().unwrap(), is_valid::<OwnershipProof>),
+            (IndexedAddress::new(index, address).serialize().unwrap(), is_valid::<IndexedAddress>),
+            (unsigned_spend_description.serialize().unwrap(), is_valid::<UnsignedSpendDescription>),
+            (xfvk.fvk.vk.ak.serialize().unwrap(), is_valid::<jubjub::SubgroupPoint>),
+            (xsk.expsk.nsk.serialize().unwrap(), is_valid::<jubjub::Scalar>),
+            (CommitmentTreeFrontier::from_commitments(&[commitment; 7]).unwrap().serialize().unwrap(), is_valid::<CommitmentTreeFrontier>),
+            (SaplingState::empty(8).serialize().unwrap(), is_valid::<SaplingState>),
+            (dkg_secret.serialize().unwrap(), is_valid::<DkgRound1Secret>),
+            (dkg_secret.package().serialize().unwrap(), is_valid::<DkgRound1Package>),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn entry_points_do_not_panic_on_arbitrary_bytes(data in vec(any::<u8>(), 0..1500)) {
+            for (_, entry_point) in ENTRY_POINTS {
+                entry_point(&data);
+            }
+        }
+    }
+
+    // Deriving the keys is comparatively slow, so fewer cases are run for the tests based on valid encodings.
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn entry_points_do_not_panic_on_modified_encodings(seed in any::<[u8; 32]>(), position in any::<usize>(), byte in any::<u8>()) {
+            for (mut bytes, _) in valid_encodings(seed) {
+                let position = position % bytes.len();
+                bytes[position] = byte;
+
+                for (_, entry_point) in ENTRY_POINTS {
+                    entry_point(&bytes);
+                    entry_point(&bytes[..position]);
+                }
+            }
+        }
+
+        #[test]
+        fn deserializers_accept_exact_encodings_only(seed in any::<[u8; 32]>(), length in any::<usize>(), extra in vec(any::<u8>(), 1..32)) {
+            for (bytes, is_valid) in valid_encodings(seed) {
+                let truncated = bytes[..length % bytes.len()].to_vec();
+                let extended = [bytes.clone(), extra.clone()].concat();
+
+                prop_assert!(is_valid(bytes));
+                prop_assert!(!is_valid(truncated));
+                prop_assert!(!is_valid(extended));
+            }
+        }
+    }
+
+    #[test]
+    fn fuzzes_entry_point_by_name() {
+        assert!(fuzz("merkle_path", &[]));
+        assert!(fuzz("sized_output_description_read", &[0xff, 0xff, 0x00]));
+        assert!(!fuzz("unknown", &[]));
+    }
+}
@@ -0,0 +1,3 @@
+pub use entry_points::{ENTRY_POINTS, EntryPoint, fuzz};
+
+mod entry_points;
\ No newline at end of file
@@ -0,0 +1,88 @@
+use std::convert::TryInto;
+
+use serde::{Deserialize, Serialize};
+use zcash_primitives::primitives::PaymentAddress;
+
+use crate::address::IndexedAddress;
+use crate::common::errors::SaplingError;
+use crate::common::traits::Serializable;
+use crate::json::hex_fields::{decode_hex, HexFields};
+use crate::json::traits::JsonRepresentable;
+
+#[derive(Serialize, Deserialize)]
+pub struct PaymentAddressJson {
+    pub diversifier: String,
+    pub pk_d: String,
+}
+
+impl JsonRepresentable for PaymentAddress {
+    type Repr = PaymentAddressJson;
+
+    fn to_repr(&self) -> Result<Self::Repr, SaplingError> {
+        let bytes = self.serialize()?;
+        let mut fields = HexFields::new(&bytes);
+
+        Ok(PaymentAddressJson {
+            diversifier: fields.next(11),
+            pk_d: fields.next(32),
+        })
+    }
+
+    fn from_repr(repr: Self::Repr) -> Result<Self, SaplingError> {
+        let bytes = [
+            decode_hex("diversifier", &repr.diversifier, Some(11))?,
+            decode_hex("pk_d", &repr.pk_d, Some(32))?,
+        ].concat();
+
+        PaymentAddress::deserialize(bytes)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct IndexedAddressJson {
+    pub diversifier_index: String,
+    pub address: PaymentAddressJson,
+}
+
+impl JsonRepresentable for IndexedAddress {
+    type Repr = IndexedAddressJson;
+
+    fn to_repr(&self) -> Result<Self::Repr, SaplingError> {
+        Ok(IndexedAddressJson {
+            diversifier_index: hex::encode(self.0),
+            address: self.1.to_repr()?,
+        })
+    }
+
+    fn from_repr(repr: Self::Repr) -> Result<Self, SaplingError> {
+        let diversifier_index = decode_hex("diversifier_index", &repr.diversifier_index, Some(11))?;
+        let address = PaymentAddress::from_repr(repr.address)?;
+
+        Ok(IndexedAddress(diversifier_index.try_into().unwrap(), address))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zcash_primitives::zip32::ExtendedSpendingKey;
+
+    use crate::json::traits::{from_json, to_json};
+    use crate::key::SaplingKey;
+
+    use super::*;
+
+    #[test]
+    fn converts_addresses_to_and_from_json() {
+        let xsk = ExtendedSpendingKey::from_seed(&[1u8; 32], "m/").unwrap();
+        let (index, address) = xsk.default_address().unwrap();
+        let indexed_address = IndexedAddress::new(index, address.clone());
+
+        let address_json = to_json(&address).unwrap();
+        assert!(address_json.contains(&format!(r#""diversifier":"{}""#, hex::encode(address.diversifier().0))));
+        assert_eq!(from_json::<PaymentAddress>(&address_json).unwrap(), address);
+
+        let indexed_address_json = to_json(&indexed_address).unwrap();
+        assert!(indexed_address_json.contains(&format!(r#""diversifier_index":"{}""#, hex::encode(index.0))));
+        assert_eq!(from_json::<IndexedAddress>(&indexed_address_json).unwrap(), indexed_address);
+    }
+}
@@ -0,0 +1,24 @@
+use crate::common::errors::DetailedError;
+
+#[derive(Debug, PartialEq)]
+pub enum JsonError {
+    InvalidHex(&'static str),
+    InvalidLength { field: &'static str, expected: usize, actual: usize },
+    InvalidValue { field: &'static str, value: String },
+    SerializationFailed(String),
+    DeserializationFailed(String),
+}
+
+impl DetailedError for JsonError {
+    fn details(&self) -> String {
+        use JsonError::*;
+
+        match self {
+            InvalidHex(field) => format!("Field `{}` is not a valid hex string", field),
+            InvalidLength { field, expected, actual } => format!("Invalid length of `{}`, expected {} bytes, got {}", field, expected, actual),
+            InvalidValue { field, value } => format!("Invalid value `{}` of `{}`", value, field),
+            SerializationFailed(cause) => format!("Could not serialize to JSON: {}", cause),
+            DeserializationFailed(cause) => format!("Could not deserialize from JSON: {}", cause),
+        }
+    }
+}
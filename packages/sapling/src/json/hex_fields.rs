@@ -0,0 +1,45 @@
+use std::convert::TryInto;
+
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::utils::hex_utils::from_hex;
+use crate::json::errors::JsonError;
+
+// Splits serialized bytes into hex-encoded fields of a JSON representation.
+pub(super) struct HexFields<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> HexFields<'a> {
+    pub fn new(bytes: &'a [u8]) -> HexFields<'a> {
+        HexFields { bytes, position: 0 }
+    }
+
+    pub fn next_bytes(&mut self, len: usize) -> &'a [u8] {
+        let bytes = &self.bytes[self.position..self.position + len];
+        self.position += len;
+
+        bytes
+    }
+
+    pub fn next(&mut self, len: usize) -> String {
+        hex::encode(self.next_bytes(len))
+    }
+
+    pub fn rest(&mut self) -> String {
+        let len = self.bytes.len() - self.position;
+        self.next(len)
+    }
+}
+
+pub(super) fn decode_hex(field: &'static str, value: &str, len: Option<usize>) -> Result<Vec<u8>, SaplingError> {
+    let bytes = from_hex(value).ok_or_else(|| SaplingError::caused_by(JsonError::InvalidHex(field)))?;
+    match len {
+        Some(len) if len != bytes.len() => Err(SaplingError::caused_by(JsonError::InvalidLength { field, expected: len, actual: bytes.len() })),
+        _ => Ok(bytes),
+    }
+}
+
+pub(super) fn decode_hex_array<const N: usize>(field: &'static str, value: &str) -> Result<[u8; N], SaplingError> {
+    decode_hex(field, value, Some(N)).map(|bytes| bytes.try_into().unwrap())
+}
@@ -0,0 +1,143 @@
+use std::convert::TryInto;
+
+use serde::{Deserialize, Serialize};
+
+use crate::address::{decode_payment_address, encode_payment_address};
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::json::errors::JsonError;
+use crate::json::hex_fields::decode_hex;
+use crate::json::traits::JsonRepresentable;
+use crate::transaction::{History, HistoryEntry, HistoryEntryKind, LevelBalance, memo_text};
+
+// Addresses are `zet1` encoded, so the export can be read without decoding them.
+#[derive(Serialize, Deserialize)]
+pub struct HistoryJson {
+    pub fingerprint: String,
+    pub entries: Vec<HistoryEntryJson>,
+    pub balances: Vec<LevelBalanceJson>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct HistoryEntryJson {
+    pub kind: String,
+    pub level: u64,
+    pub transaction_hash: String,
+    pub position: u64,
+    pub address: String,
+    pub value: u64,
+    pub memo: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memo_text: Option<String>,
+    pub balance: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct LevelBalanceJson {
+    pub level: u64,
+    pub balance: u64,
+}
+
+impl JsonRepresentable for History {
+    type Repr = HistoryJson;
+
+    fn to_repr(&self) -> Result<Self::Repr, SaplingError> {
+        let entries = self.entries.iter()
+            .map(|entry| HistoryEntryJson {
+                kind: String::from(entry.kind.name()),
+                level: entry.level,
+                transaction_hash: entry.transaction_hash.clone(),
+                position: entry.position,
+                address: encode_payment_address(&entry.address),
+                value: entry.value,
+                memo: hex::encode(&entry.memo),
+                memo_text: memo_text(&entry.memo),
+                balance: entry.balance,
+            })
+            .collect();
+        let balances = self.balances.iter()
+            .map(|balance| LevelBalanceJson { level: balance.level, balance: balance.balance })
+            .collect();
+
+        Ok(HistoryJson { fingerprint: hex::encode(self.fingerprint), entries, balances })
+    }
+
+    fn from_repr(repr: Self::Repr) -> Result<Self, SaplingError> {
+        let fingerprint = decode_hex("fingerprint", &repr.fingerprint, Some(32))?;
+        let entries = repr.entries.into_iter()
+            .map(|entry| {
+                let kind = HistoryEntryKind::from_name(&entry.kind)
+                    .ok_or_else(|| SaplingError::caused_by(JsonError::InvalidValue { field: "kind", value: entry.kind.clone() }))?;
+
+                Ok(HistoryEntry {
+                    kind,
+                    level: entry.level,
+                    transaction_hash: entry.transaction_hash,
+                    position: entry.position,
+                    address: decode_payment_address(&entry.address)?,
+                    value: entry.value,
+                    memo: decode_hex("memo", &entry.memo, None)?,
+                    balance: entry.balance,
+                })
+            })
+            .collect::<Result<Vec<HistoryEntry>, SaplingError>>()?;
+        let balances = repr.balances.into_iter()
+            .map(|balance| LevelBalance { level: balance.level, balance: balance.balance })
+            .collect();
+
+        Ok(History { fingerprint: fingerprint.try_into().unwrap(), entries, balances })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zcash_primitives::zip32::{ExtendedFullViewingKey, ExtendedSpendingKey};
+
+    use crate::json::traits::{from_json, to_json};
+    use crate::key::SaplingKey;
+
+    use super::*;
+
+    #[test]
+    fn converts_history_to_and_from_json() {
+        let xfvk = ExtendedFullViewingKey::from(&ExtendedSpendingKey::from_seed(&[1u8; 32], "m/").unwrap());
+        let (_, address) = xfvk.default_address().unwrap();
+        let history = History {
+            fingerprint: [3u8; 32],
+            entries: vec![
+                HistoryEntry {
+                    kind: HistoryEntryKind::Received,
+                    level: 10,
+                    transaction_hash: String::from("op1"),
+                    position: 4,
+                    address: address.clone(),
+                    value: 1000,
+                    memo: vec![0x68, 0x69, 0, 0],
+                    balance: 1000,
+                },
+                HistoryEntry {
+                    kind: HistoryEntryKind::Spent,
+                    level: 12,
+                    transaction_hash: String::from("op2"),
+                    position: 4,
+                    address: address.clone(),
+                    value: 1000,
+                    memo: vec![0xF6, 0, 0, 0],
+                    balance: 0,
+                },
+            ],
+            balances: vec![LevelBalance { level: 10, balance: 1000 }, LevelBalance { level: 12, balance: 0 }],
+        };
+
+        let json = to_json(&history).unwrap();
+        assert!(json.contains(&format!(r#""address":"{}""#, encode_payment_address(&address))));
+        assert!(json.contains(r#""kind":"received""#));
+        assert!(json.contains(r#""memo_text":"hi""#));
+        assert_eq!(from_json::<History>(&json).unwrap(), history);
+
+        let invalid = json.replace(r#""kind":"spent""#, r#""kind":"burnt""#);
+        assert_eq!(
+            from_json::<History>(&invalid).err(),
+            Some(SaplingError::caused_by(JsonError::InvalidValue { field: "kind", value: String::from("burnt") })),
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use std::convert::TryInto;
+
+use serde::{Deserialize, Serialize};
+use zcash_primitives::keys::OutgoingViewingKey;
+use zcash_primitives::primitives::ProofGenerationKey;
+use zcash_primitives::zip32::{ExtendedFullViewingKey, ExtendedSpendingKey};
+
+use crate::common::errors::SaplingError;
+use crate::common::traits::Serializable;
+use crate::json::hex_fields::{decode_hex, HexFields};
+use crate::json::traits::JsonRepresentable;
+use crate::key::Bip32Path;
+
+#[derive(Serialize, Deserialize)]
+pub struct ExtendedSpendingKeyJson {
+    pub depth: u8,
+    pub parent_fvk_tag: String,
+    pub child_index: u32,
+    pub chain_code: String,
+    pub ask: String,
+    pub nsk: String,
+    pub ovk: String,
+    pub dk: String,
+}
+
+impl JsonRepresentable for ExtendedSpendingKey {
+    type Repr = ExtendedSpendingKeyJson;
+
+    fn to_repr(&self) -> Result<Self::Repr, SaplingError> {
+        let bytes = self.serialize()?;
+        let mut fields = HexFields::new(&bytes);
+
+        Ok(ExtendedSpendingKeyJson {
+            depth: fields.next_bytes(1)[0],
+            parent_fvk_tag: fields.next(4),
+            child_index: u32::from_le_bytes(fields.next_bytes(4).try_into().unwrap()),
+            chain_code: fields.next(32),
+            ask: fields.next(32),
+            nsk: fields.next(32),
+            ovk: fields.next(32),
+            dk: fields.next(32),
+        })
+    }
+
+    fn from_repr(repr: Self::Repr) -> Result<Self, SaplingError> {
+        let bytes = [
+            vec![repr.depth],
+            decode_hex("parent_fvk_tag", &repr.parent_fvk_tag, Some(4))?,
+            repr.child_index.to_le_bytes().to_vec(),
+            decode_hex("chain_code", &repr.chain_code, Some(32))?,
+            decode_hex("ask", &repr.ask, Some(32))?,
+            decode_hex("nsk", &repr.nsk, Some(32))?,
+            decode_hex("ovk", &repr.ovk, Some(32))?,
+            decode_hex("dk", &repr.dk, Some(32))?,
+        ].concat();
+
+        ExtendedSpendingKey::deserialize(bytes)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExtendedFullViewingKeyJson {
+    pub depth: u8,
+    pub parent_fvk_tag: String,
+    pub child_index: u32,
+    pub chain_code: String,
+    pub ak: String,
+    pub nk: String,
+    pub ovk: String,
+    pub dk: String,
+}
+
+impl JsonRepresentable for ExtendedFullViewingKey {
+    type Repr = ExtendedFullViewingKeyJson;
+
+    fn to_repr(&self) -> Result<Self::Repr, SaplingError> {
+        let bytes = self.serialize()?;
+        let mut fields = HexFields::new(&bytes);
+
+        Ok(ExtendedFullViewingKeyJson {
+            depth: fields.next_bytes(1)[0],
+            parent_fvk_tag: fields.next(4),
+            child_index: u32::from_le_bytes(fields.next_bytes(4).try_into().unwrap()),
+            chain_code: fields.next(32),
+            ak: fields.next(32),
+            nk: fields.next(32),
+            ovk: fields.next(32),
+            dk: fields.next(32),
+        })
+    }
+
+    fn from_repr(repr: Self::Repr) -> Result<Self, SaplingError> {
+        let bytes = [
+            vec![repr.depth],
+            decode_hex("parent_fvk_tag", &repr.parent_fvk_tag, Some(4))?,
+            repr.child_index.to_le_bytes().to_vec(),
+            decode_hex("chain_code", &repr.chain_code, Some(32))?,
+            decode_hex("ak", &repr.ak, Some(32))?,
+            decode_hex("nk", &repr.nk, Some(32))?,
+            decode_hex("ovk", &repr.ovk, Some(32))?,
+            decode_hex("dk", &repr.dk, Some(32))?,
+        ].concat();
+
+        ExtendedFullViewingKey::deserialize(bytes)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ProofGenerationKeyJson {
+    pub ak: String,
+    pub nsk: String,
+}
+
+impl JsonRepresentable for ProofGenerationKey {
+    type Repr = ProofGenerationKeyJson;
+
+    fn to_repr(&self) -> Result<Self::Repr, SaplingError> {
+        let bytes = self.serialize()?;
+        let mut fields = HexFields::new(&bytes);
+
+        Ok(ProofGenerationKeyJson {
+            ak: fields.next(32),
+            nsk: fields.next(32),
+        })
+    }
+
+    fn from_repr(repr: Self::Repr) -> Result<Self, SaplingError> {
+        let bytes = [
+            decode_hex("ak", &repr.ak, Some(32))?,
+            decode_hex("nsk", &repr.nsk, Some(32))?,
+        ].concat();
+
+        ProofGenerationKey::deserialize(bytes)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct OutgoingViewingKeyJson {
+    pub ovk: String,
+}
+
+impl JsonRepresentable for OutgoingViewingKey {
+    type Repr = OutgoingViewingKeyJson;
+
+    fn to_repr(&self) -> Result<Self::Repr, SaplingError> {
+        Ok(OutgoingViewingKeyJson { ovk: hex::encode(self.0) })
+    }
+
+    fn from_repr(repr: Self::Repr) -> Result<Self, SaplingError> {
+        OutgoingViewingKey::deserialize(decode_hex("ovk", &repr.ovk, Some(32))?)
+    }
+}
+
+// Paths are represented in their textual form, e.g. `m/32'/1729'/0'`.
+impl JsonRepresentable for Bip32Path {
+    type Repr = String;
+
+    fn to_repr(&self) -> Result<Self::Repr, SaplingError> {
+        Ok(self.to_string())
+    }
+
+    fn from_repr(repr: Self::Repr) -> Result<Self, SaplingError> {
+        repr.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::json::traits::{from_json, to_json};
+    use crate::key::{SaplingKey, split_bip32_path};
+
+    use super::*;
+
+    #[test]
+    fn converts_keys_to_and_from_json() {
+        let xsk = ExtendedSpendingKey::from_seed(&[1u8; 32], "m/32'/1729'/0'").unwrap();
+        let xfvk = ExtendedFullViewingKey::from(&xsk);
+        let pak = xsk.expsk.proof_generation_key();
+
+        let xsk_json = to_json(&xsk).unwrap();
+        assert!(xsk_json.contains(r#""depth":3"#));
+        assert!(xsk_json.contains(&format!(r#""ovk":"{}""#, hex::encode(xsk.expsk.ovk.0))));
+        assert_eq!(from_json::<ExtendedSpendingKey>(&xsk_json).unwrap(), xsk);
+
+        let xfvk_json = to_json(&xfvk).unwrap();
+        assert!(xfvk_json.contains(&format!(r#""child_index":{}"#, (1u32 << 31))));
+        assert_eq!(from_json::<ExtendedFullViewingKey>(&xfvk_json).unwrap().serialize().unwrap(), xfvk.serialize().unwrap());
+
+        let pak_json = to_json(&pak).unwrap();
+        assert_eq!(from_json::<ProofGenerationKey>(&pak_json).unwrap().serialize().unwrap(), pak.serialize().unwrap());
+
+        let ovk_json = to_json(&xsk.expsk.ovk).unwrap();
+        assert_eq!(ovk_json, format!(r#"{{"ovk":"{}"}}"#, hex::encode(xsk.expsk.ovk.0)));
+        assert_eq!(from_json::<OutgoingViewingKey>(&ovk_json).unwrap(), xsk.expsk.ovk);
+    }
+
+    #[test]
+    fn converts_bip32_path_to_and_from_json() {
+        let path = split_bip32_path("m/32'/1729'/0'/1").unwrap();
+
+        let json = to_json(&path).unwrap();
+        assert_eq!(json, r#""m/32'/1729'/0'/1""#);
+        assert_eq!(from_json::<Bip32Path>(&json).unwrap(), path);
+    }
+
+    #[test]
+    fn fails_to_convert_invalid_json() {
+        let test_data = [
+            r#"{"ovk":"zz"}"#,
+            r#"{"ovk":"0102"}"#,
+            r#"{"key":"0102"}"#,
+        ];
+
+        for json in test_data.iter() {
+            assert!(from_json::<OutgoingViewingKey>(json).is_err());
+        }
+    }
+}
@@ -0,0 +1,13 @@
+pub use traits::{from_json, JsonRepresentable, to_json};
+
+mod address;
+mod history;
+mod key;
+mod payment_request;
+mod sapling_transaction;
+mod transaction;
+
+mod hex_fields;
+mod traits;
+
+mod errors;
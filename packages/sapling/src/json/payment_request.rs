@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+use crate::address::{decode_payment_address, encode_payment_address, Payment, PaymentRequest};
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::json::errors::JsonError;
+use crate::json::hex_fields::decode_hex;
+use crate::json::traits::JsonRepresentable;
+
+// The amount is in mutez, as a string so it's not truncated by JSON parsers that read numbers as doubles.
+#[derive(Serialize, Deserialize)]
+pub struct PaymentJson {
+    pub address: String,
+    pub amount: Option<String>,
+    pub memo: Option<String>,
+    pub label: Option<String>,
+    pub message: Option<String>,
+}
+
+impl JsonRepresentable for PaymentRequest {
+    type Repr = Vec<PaymentJson>;
+
+    fn to_repr(&self) -> Result<Self::Repr, SaplingError> {
+        let payments = self.payments.iter()
+            .map(|payment| PaymentJson {
+                address: encode_payment_address(&payment.address),
+                amount: payment.amount.map(|amount| amount.to_string()),
+                memo: payment.memo.as_ref().map(hex::encode),
+                label: payment.label.clone(),
+                message: payment.message.clone(),
+            })
+            .collect();
+
+        Ok(payments)
+    }
+
+    fn from_repr(repr: Self::Repr) -> Result<Self, SaplingError> {
+        let mut request = PaymentRequest::new();
+        for payment in repr.into_iter() {
+            let amount = payment.amount
+                .map(|amount| amount.parse().map_err(|_| SaplingError::caused_by(JsonError::InvalidValue { field: "amount", value: amount.clone() })))
+                .transpose()?;
+            let memo = payment.memo.map(|memo| decode_hex("memo", &memo, None)).transpose()?;
+
+            request.add_payment(Payment {
+                address: decode_payment_address(&payment.address)?,
+                amount,
+                memo,
+                label: payment.label,
+                message: payment.message,
+            })?;
+        }
+
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zcash_primitives::zip32::{ExtendedFullViewingKey, ExtendedSpendingKey};
+
+    use crate::json::traits::{from_json, to_json};
+    use crate::key::SaplingKey;
+
+    use super::*;
+
+    #[test]
+    fn converts_payment_request_to_and_from_json() {
+        let xfvk = ExtendedFullViewingKey::from(&ExtendedSpendingKey::from_seed(&[1u8; 32], "m/").unwrap());
+        let (_, address) = xfvk.default_address().unwrap();
+        let request = PaymentRequest {
+            payments: vec![
+                Payment { amount: Some(5), label: Some(String::from("say \"hi\"")), ..Payment::new(address.clone()) },
+                Payment { memo: Some(b"hi".to_vec()), ..Payment::new(address.clone()) },
+            ],
+        };
+
+        let json = to_json(&request).unwrap();
+        assert_eq!(
+            json,
+            format!(
+                r#"[{{"address":"{0}","amount":"5","memo":null,"label":"say \"hi\"","message":null}},{{"address":"{0}","amount":null,"memo":"6869","label":null,"message":null}}]"#,
+                encode_payment_address(&address),
+            ),
+        );
+        assert_eq!(from_json::<PaymentRequest>(&json).unwrap(), request);
+
+        let invalid = json.replace(r#""amount":"5""#, r#""amount":"0.5""#);
+        assert_eq!(
+            from_json::<PaymentRequest>(&invalid).err(),
+            Some(SaplingError::caused_by(JsonError::InvalidValue { field: "amount", value: String::from("0.5") })),
+        );
+    }
+}
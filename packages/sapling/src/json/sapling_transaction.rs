@@ -0,0 +1,288 @@
+use serde::{Deserialize, Serialize};
+use zcash_primitives::primitives::Nullifier;
+use zcash_primitives::redjubjub::{PublicKey, Signature};
+use zcash_primitives::transaction::components::SpendDescription;
+
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::traits::Serializable;
+use crate::json::errors::JsonError;
+use crate::json::hex_fields::{decode_hex, decode_hex_array};
+use crate::json::traits::JsonRepresentable;
+use crate::transaction::{
+    SaplingTransaction,
+    SaplingTransactionCiphertext,
+    SaplingTransactionOutput,
+    ShieldedPoolParameters,
+    ShieldedTransfer,
+};
+
+// The balance is a string, so it's not truncated by JSON parsers that read numbers as doubles.
+#[derive(Serialize, Deserialize)]
+pub struct SaplingTransactionJson {
+    pub spend_descriptions: Vec<SaplingTransactionSpendJson>,
+    pub output_descriptions: Vec<SaplingTransactionOutputJson>,
+    pub binding_sig: String,
+    pub balance: String,
+    pub root: String,
+    pub bound_data: String,
+}
+
+// The spend descriptions don't carry an anchor, it's the `root` of the transaction.
+#[derive(Serialize, Deserialize)]
+pub struct SaplingTransactionSpendJson {
+    pub cv: String,
+    pub nullifier: String,
+    pub rk: String,
+    pub zkproof: String,
+    pub spend_auth_sig: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SaplingTransactionOutputJson {
+    pub cmu: String,
+    pub zkproof: String,
+    pub ciphertext: SaplingTransactionCiphertextJson,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SaplingTransactionCiphertextJson {
+    pub cv: String,
+    pub epk: String,
+    pub payload_enc: String,
+    pub nonce_enc: String,
+    pub payload_out: String,
+    pub nonce_out: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ShieldedTransferJson {
+    pub transaction: SaplingTransactionJson,
+    pub unshield_to: Option<String>,
+}
+
+impl JsonRepresentable for SaplingTransaction {
+    type Repr = SaplingTransactionJson;
+
+    fn to_repr(&self) -> Result<Self::Repr, SaplingError> {
+        let spend_descriptions = self.spend_descriptions.iter()
+            .map(spend_description_to_repr)
+            .collect::<Result<Vec<SaplingTransactionSpendJson>, SaplingError>>()?;
+        let output_descriptions = self.output_descriptions.iter()
+            .map(output_description_to_repr)
+            .collect::<Result<Vec<SaplingTransactionOutputJson>, SaplingError>>()?;
+
+        Ok(SaplingTransactionJson {
+            spend_descriptions,
+            output_descriptions,
+            binding_sig: hex::encode(self.binding_sig.serialize()?),
+            balance: self.balance.to_string(),
+            root: hex::encode(self.root.serialize()?),
+            bound_data: hex::encode(&self.bound_data),
+        })
+    }
+
+    fn from_repr(repr: Self::Repr) -> Result<Self, SaplingError> {
+        let root = bls12_381::Scalar::deserialize(decode_hex("root", &repr.root, Some(32))?)?;
+        let spend_descriptions = repr.spend_descriptions.into_iter()
+            .map(|spend_description| spend_description_from_repr(spend_description, &root))
+            .collect::<Result<Vec<SpendDescription>, SaplingError>>()?;
+        let output_descriptions = repr.output_descriptions.into_iter()
+            .map(output_description_from_repr)
+            .collect::<Result<Vec<SaplingTransactionOutput>, SaplingError>>()?;
+        let balance = &repr.balance;
+        let balance = balance.parse()
+            .map_err(|_| SaplingError::caused_by(JsonError::InvalidValue { field: "balance", value: balance.clone() }))?;
+
+        Ok(SaplingTransaction {
+            spend_descriptions,
+            output_descriptions,
+            binding_sig: Signature::deserialize(decode_hex("binding_sig", &repr.binding_sig, Some(64))?)?,
+            balance,
+            root,
+            bound_data: decode_hex("bound_data", &repr.bound_data, None)?,
+        })
+    }
+}
+
+impl JsonRepresentable for ShieldedTransfer {
+    type Repr = ShieldedTransferJson;
+
+    fn to_repr(&self) -> Result<Self::Repr, SaplingError> {
+        Ok(ShieldedTransferJson {
+            transaction: self.transaction.to_repr()?,
+            unshield_to: self.unshield_to.map(|key_hash| key_hash.to_string()),
+        })
+    }
+
+    fn from_repr(repr: Self::Repr) -> Result<Self, SaplingError> {
+        Ok(ShieldedTransfer {
+            transaction: SaplingTransaction::from_repr(repr.transaction)?,
+            unshield_to: repr.unshield_to.map(|key_hash| key_hash.parse()).transpose()?,
+        })
+    }
+}
+
+impl JsonRepresentable for ShieldedPoolParameters {
+    type Repr = Vec<ShieldedTransferJson>;
+
+    fn to_repr(&self) -> Result<Self::Repr, SaplingError> {
+        self.transfers.iter().map(ShieldedTransfer::to_repr).collect()
+    }
+
+    fn from_repr(repr: Self::Repr) -> Result<Self, SaplingError> {
+        let transfers = repr.into_iter()
+            .map(ShieldedTransfer::from_repr)
+            .collect::<Result<Vec<ShieldedTransfer>, SaplingError>>()?;
+
+        Ok(ShieldedPoolParameters { transfers })
+    }
+}
+
+// An unsigned spend description has an empty `spend_auth_sig`.
+fn spend_description_to_repr(spend_description: &SpendDescription) -> Result<SaplingTransactionSpendJson, SaplingError> {
+    let spend_auth_sig = match &spend_description.spend_auth_sig {
+        Some(spend_auth_sig) => hex::encode(spend_auth_sig.serialize()?),
+        None => String::new(),
+    };
+
+    Ok(SaplingTransactionSpendJson {
+        cv: hex::encode(spend_description.cv.serialize()?),
+        nullifier: hex::encode(spend_description.nullifier.0),
+        rk: hex::encode(spend_description.rk.0.serialize()?),
+        zkproof: hex::encode(spend_description.zkproof),
+        spend_auth_sig,
+    })
+}
+
+fn spend_description_from_repr(repr: SaplingTransactionSpendJson, root: &bls12_381::Scalar) -> Result<SpendDescription, SaplingError> {
+    let spend_auth_sig = match repr.spend_auth_sig.as_str() {
+        "" => None,
+        spend_auth_sig => Some(Signature::deserialize(decode_hex("spend_auth_sig", spend_auth_sig, Some(64))?)?),
+    };
+
+    Ok(SpendDescription {
+        cv: jubjub::ExtendedPoint::deserialize(decode_hex("cv", &repr.cv, Some(32))?)?,
+        anchor: *root,
+        nullifier: Nullifier(decode_hex_array("nullifier", &repr.nullifier)?),
+        rk: PublicKey(jubjub::ExtendedPoint::deserialize(decode_hex("rk", &repr.rk, Some(32))?)?),
+        zkproof: decode_hex_array("zkproof", &repr.zkproof)?,
+        spend_auth_sig,
+    })
+}
+
+fn output_description_to_repr(output_description: &SaplingTransactionOutput) -> Result<SaplingTransactionOutputJson, SaplingError> {
+    let ciphertext = &output_description.ciphertext;
+
+    Ok(SaplingTransactionOutputJson {
+        cmu: hex::encode(output_description.cmu.serialize()?),
+        zkproof: hex::encode(output_description.zkproof),
+        ciphertext: SaplingTransactionCiphertextJson {
+            cv: hex::encode(ciphertext.cv.serialize()?),
+            epk: hex::encode(ciphertext.epk.serialize()?),
+            payload_enc: hex::encode(&ciphertext.payload_enc),
+            nonce_enc: hex::encode(ciphertext.nonce_enc),
+            payload_out: hex::encode(ciphertext.payload_out),
+            nonce_out: hex::encode(ciphertext.nonce_out),
+        },
+    })
+}
+
+fn output_description_from_repr(repr: SaplingTransactionOutputJson) -> Result<SaplingTransactionOutput, SaplingError> {
+    let ciphertext = repr.ciphertext;
+
+    Ok(SaplingTransactionOutput {
+        cmu: bls12_381::Scalar::deserialize(decode_hex("cmu", &repr.cmu, Some(32))?)?,
+        zkproof: decode_hex_array("zkproof", &repr.zkproof)?,
+        ciphertext: SaplingTransactionCiphertext {
+            cv: jubjub::ExtendedPoint::deserialize(decode_hex("cv", &ciphertext.cv, Some(32))?)?,
+            epk: jubjub::ExtendedPoint::deserialize(decode_hex("epk", &ciphertext.epk, Some(32))?)?,
+            payload_enc: decode_hex("payload_enc", &ciphertext.payload_enc, None)?,
+            nonce_enc: decode_hex_array("nonce_enc", &ciphertext.nonce_enc)?,
+            payload_out: decode_hex_array("payload_out", &ciphertext.payload_out)?,
+            nonce_out: decode_hex_array("nonce_out", &ciphertext.nonce_out)?,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use zcash_primitives::constants::SPENDING_KEY_GENERATOR;
+    use zcash_primitives::transaction::components::GROTH_PROOF_SIZE;
+
+    use crate::json::traits::{from_json, to_json};
+    use crate::transaction::KeyHash;
+
+    use super::*;
+
+    fn sapling_transaction() -> SaplingTransaction {
+        let point = jubjub::ExtendedPoint::from(SPENDING_KEY_GENERATOR);
+        let root = bls12_381::Scalar::from(7u64);
+
+        SaplingTransaction {
+            spend_descriptions: vec![SpendDescription {
+                cv: point,
+                anchor: root,
+                nullifier: Nullifier([1u8; 32]),
+                rk: PublicKey(point),
+                zkproof: [2u8; GROTH_PROOF_SIZE],
+                spend_auth_sig: Some(Signature::read(&[3u8; 64][..]).unwrap()),
+            }],
+            output_descriptions: vec![SaplingTransactionOutput {
+                cmu: bls12_381::Scalar::from(5u64),
+                zkproof: [4u8; GROTH_PROOF_SIZE],
+                ciphertext: SaplingTransactionCiphertext {
+                    cv: point,
+                    epk: point,
+                    payload_enc: vec![5u8; 10],
+                    nonce_enc: [6u8; 24],
+                    payload_out: [7u8; 80],
+                    nonce_out: [8u8; 24],
+                },
+            }],
+            binding_sig: Signature::read(&[9u8; 64][..]).unwrap(),
+            balance: -100,
+            root,
+            bound_data: vec![10u8; 3],
+        }
+    }
+
+    #[test]
+    fn converts_sapling_transaction_to_and_from_json() {
+        let transaction = sapling_transaction();
+
+        let json = to_json(&transaction).unwrap();
+        assert!(json.starts_with(r#"{"spend_descriptions":[{"cv":""#));
+        assert!(json.contains(r#""payload_enc":"05050505050505050505""#));
+        assert!(json.contains(r#""balance":"-100""#));
+        assert!(json.ends_with(r#""bound_data":"0a0a0a"}"#));
+
+        let decoded = from_json::<SaplingTransaction>(&json).unwrap();
+        assert_eq!(decoded.spend_descriptions[0].anchor, transaction.root);
+        assert_eq!(decoded.serialize().unwrap(), transaction.serialize().unwrap());
+
+        let invalid = json.replace(r#""balance":"-100""#, r#""balance":"-1.5""#);
+        assert_eq!(
+            from_json::<SaplingTransaction>(&invalid).err(),
+            Some(SaplingError::caused_by(JsonError::InvalidValue { field: "balance", value: String::from("-1.5") })),
+        );
+    }
+
+    #[test]
+    fn converts_shielded_pool_parameters_to_and_from_json() {
+        let key_hash = KeyHash::Ed25519([1u8; 20]);
+        let parameters = ShieldedPoolParameters {
+            transfers: vec![
+                ShieldedTransfer { transaction: sapling_transaction(), unshield_to: None },
+                ShieldedTransfer { transaction: sapling_transaction(), unshield_to: Some(key_hash) },
+            ],
+        };
+
+        let json = to_json(&parameters).unwrap();
+        assert!(json.starts_with(r#"[{"transaction":{"spend_descriptions""#));
+        assert!(json.contains(r#""unshield_to":null"#));
+        assert!(json.contains(&format!(r#""unshield_to":"{}""#, key_hash)));
+
+        let decoded = from_json::<ShieldedPoolParameters>(&json).unwrap();
+        assert_eq!(decoded.serialize().unwrap(), parameters.serialize().unwrap());
+    }
+}
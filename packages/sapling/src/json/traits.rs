@@ -0,0 +1,24 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::json::errors::JsonError;
+
+pub trait JsonRepresentable: Sized {
+    type Repr: Serialize + DeserializeOwned;
+
+    fn to_repr(&self) -> Result<Self::Repr, SaplingError>;
+    fn from_repr(repr: Self::Repr) -> Result<Self, SaplingError>;
+}
+
+pub fn to_json<T: JsonRepresentable>(value: &T) -> Result<String, SaplingError> {
+    let repr = value.to_repr()?;
+
+    serde_json::to_string(&repr).map_err(|err| SaplingError::caused_by(JsonError::SerializationFailed(err.to_string())))
+}
+
+pub fn from_json<T: JsonRepresentable>(json: &str) -> Result<T, SaplingError> {
+    let repr: T::Repr = serde_json::from_str(json).map_err(|err| SaplingError::caused_by(JsonError::DeserializationFailed(err.to_string())))?;
+
+    T::from_repr(repr)
+}
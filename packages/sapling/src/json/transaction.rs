@@ -0,0 +1,227 @@
+use serde::{Deserialize, Serialize};
+use zcash_primitives::transaction::components::{GROTH_PROOF_SIZE, OutputDescription, SpendDescription};
+
+use crate::common::errors::SaplingError;
+use crate::common::traits::Serializable;
+use crate::json::hex_fields::{decode_hex, HexFields};
+use crate::json::traits::JsonRepresentable;
+use crate::transaction::{PartialOutputDescription, UnsignedSpendDescription};
+
+#[derive(Serialize, Deserialize)]
+pub struct UnsignedSpendDescriptionJson {
+    pub cv: String,
+    pub anchor: String,
+    pub nullifier: String,
+    pub rk: String,
+    pub zkproof: String,
+}
+
+impl JsonRepresentable for UnsignedSpendDescription {
+    type Repr = UnsignedSpendDescriptionJson;
+
+    fn to_repr(&self) -> Result<Self::Repr, SaplingError> {
+        let bytes = self.serialize()?;
+        let mut fields = HexFields::new(&bytes);
+
+        Ok(UnsignedSpendDescriptionJson {
+            cv: fields.next(32),
+            anchor: fields.next(32),
+            nullifier: fields.next(32),
+            rk: fields.next(32),
+            zkproof: fields.next(GROTH_PROOF_SIZE),
+        })
+    }
+
+    fn from_repr(repr: Self::Repr) -> Result<Self, SaplingError> {
+        let bytes = [
+            decode_hex("cv", &repr.cv, Some(32))?,
+            decode_hex("anchor", &repr.anchor, Some(32))?,
+            decode_hex("nullifier", &repr.nullifier, Some(32))?,
+            decode_hex("rk", &repr.rk, Some(32))?,
+            decode_hex("zkproof", &repr.zkproof, Some(GROTH_PROOF_SIZE))?,
+        ].concat();
+
+        UnsignedSpendDescription::deserialize(bytes)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SpendDescriptionJson {
+    pub cv: String,
+    pub anchor: String,
+    pub nullifier: String,
+    pub rk: String,
+    pub zkproof: String,
+    pub spend_auth_sig: String,
+}
+
+impl JsonRepresentable for SpendDescription {
+    type Repr = SpendDescriptionJson;
+
+    fn to_repr(&self) -> Result<Self::Repr, SaplingError> {
+        let bytes = self.serialize()?;
+        let mut fields = HexFields::new(&bytes);
+
+        Ok(SpendDescriptionJson {
+            cv: fields.next(32),
+            anchor: fields.next(32),
+            nullifier: fields.next(32),
+            rk: fields.next(32),
+            zkproof: fields.next(GROTH_PROOF_SIZE),
+            spend_auth_sig: fields.next(64),
+        })
+    }
+
+    fn from_repr(repr: Self::Repr) -> Result<Self, SaplingError> {
+        let bytes = [
+            decode_hex("cv", &repr.cv, Some(32))?,
+            decode_hex("anchor", &repr.anchor, Some(32))?,
+            decode_hex("nullifier", &repr.nullifier, Some(32))?,
+            decode_hex("rk", &repr.rk, Some(32))?,
+            decode_hex("zkproof", &repr.zkproof, Some(GROTH_PROOF_SIZE))?,
+            decode_hex("spend_auth_sig", &repr.spend_auth_sig, Some(64))?,
+        ].concat();
+
+        SpendDescription::deserialize(bytes)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct OutputDescriptionJson {
+    pub cv: String,
+    pub cmu: String,
+    pub ephemeral_key: String,
+    pub enc_ciphertext: String,
+    pub out_ciphertext: String,
+    pub zkproof: String,
+}
+
+impl JsonRepresentable for OutputDescription {
+    type Repr = OutputDescriptionJson;
+
+    fn to_repr(&self) -> Result<Self::Repr, SaplingError> {
+        let bytes = self.serialize()?;
+        let mut fields = HexFields::new(&bytes);
+
+        Ok(OutputDescriptionJson {
+            cv: fields.next(32),
+            cmu: fields.next(32),
+            ephemeral_key: fields.next(32),
+            enc_ciphertext: fields.next(self.enc_ciphertext.len()),
+            out_ciphertext: fields.next(self.out_ciphertext.len()),
+            zkproof: fields.next(GROTH_PROOF_SIZE),
+        })
+    }
+
+    fn from_repr(repr: Self::Repr) -> Result<Self, SaplingError> {
+        let bytes = [
+            decode_hex("cv", &repr.cv, Some(32))?,
+            decode_hex("cmu", &repr.cmu, Some(32))?,
+            decode_hex("ephemeral_key", &repr.ephemeral_key, Some(32))?,
+            decode_hex("enc_ciphertext", &repr.enc_ciphertext, None)?,
+            decode_hex("out_ciphertext", &repr.out_ciphertext, None)?,
+            decode_hex("zkproof", &repr.zkproof, Some(GROTH_PROOF_SIZE))?,
+        ].concat();
+
+        OutputDescription::deserialize(bytes)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PartialOutputDescriptionJson {
+    pub cv: String,
+    pub cmu: String,
+    pub zkproof: String,
+}
+
+impl JsonRepresentable for PartialOutputDescription {
+    type Repr = PartialOutputDescriptionJson;
+
+    fn to_repr(&self) -> Result<Self::Repr, SaplingError> {
+        let bytes = self.serialize()?;
+        let mut fields = HexFields::new(&bytes);
+
+        Ok(PartialOutputDescriptionJson {
+            cv: fields.next(32),
+            cmu: fields.next(32),
+            zkproof: fields.next(GROTH_PROOF_SIZE),
+        })
+    }
+
+    fn from_repr(repr: Self::Repr) -> Result<Self, SaplingError> {
+        let bytes = [
+            decode_hex("cv", &repr.cv, Some(32))?,
+            decode_hex("cmu", &repr.cmu, Some(32))?,
+            decode_hex("zkproof", &repr.zkproof, Some(GROTH_PROOF_SIZE))?,
+        ].concat();
+
+        PartialOutputDescription::deserialize(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::Group;
+    use zcash_primitives::primitives::Nullifier;
+    use zcash_primitives::redjubjub::{PublicKey, Signature};
+
+    use crate::json::traits::{from_json, to_json};
+
+    use super::*;
+
+    fn point() -> jubjub::ExtendedPoint {
+        jubjub::ExtendedPoint::generator()
+    }
+
+    #[test]
+    fn converts_descriptions_to_and_from_json() {
+        let unsigned_spend_description = UnsignedSpendDescription {
+            cv: point(),
+            anchor: bls12_381::Scalar::from(1u64),
+            nullifier: Nullifier([2u8; 32]),
+            rk: PublicKey(point()),
+            zkproof: [3u8; GROTH_PROOF_SIZE],
+        };
+        let json = to_json(&unsigned_spend_description).unwrap();
+        assert!(json.contains(&format!(r#""nullifier":"{}""#, hex::encode([2u8; 32]))));
+        assert_eq!(
+            from_json::<UnsignedSpendDescription>(&json).unwrap().serialize().unwrap(),
+            unsigned_spend_description.serialize().unwrap()
+        );
+
+        let spend_description = SpendDescription {
+            cv: point(),
+            anchor: bls12_381::Scalar::from(1u64),
+            nullifier: Nullifier([2u8; 32]),
+            rk: PublicKey(point()),
+            zkproof: [3u8; GROTH_PROOF_SIZE],
+            spend_auth_sig: Some(Signature::read(&[4u8; 64][..]).unwrap()),
+        };
+        let json = to_json(&spend_description).unwrap();
+        assert!(json.contains(&format!(r#""spend_auth_sig":"{}""#, hex::encode([4u8; 64]))));
+        assert_eq!(from_json::<SpendDescription>(&json).unwrap().serialize().unwrap(), spend_description.serialize().unwrap());
+
+        let output_description = OutputDescription {
+            cv: point(),
+            cmu: bls12_381::Scalar::from(5u64),
+            ephemeral_key: point(),
+            enc_ciphertext: [6u8; 580],
+            out_ciphertext: [7u8; 80],
+            zkproof: [8u8; GROTH_PROOF_SIZE],
+        };
+        let json = to_json(&output_description).unwrap();
+        assert!(json.contains(&format!(r#""out_ciphertext":"{}""#, hex::encode([7u8; 80]))));
+        assert_eq!(from_json::<OutputDescription>(&json).unwrap().serialize().unwrap(), output_description.serialize().unwrap());
+
+        let partial_output_description = PartialOutputDescription {
+            cv: point(),
+            cmu: bls12_381::Scalar::from(5u64),
+            zkproof: [8u8; GROTH_PROOF_SIZE],
+        };
+        let json = to_json(&partial_output_description).unwrap();
+        assert_eq!(
+            from_json::<PartialOutputDescription>(&json).unwrap().serialize().unwrap(),
+            partial_output_description.serialize().unwrap()
+        );
+    }
+}
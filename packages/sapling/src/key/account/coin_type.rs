@@ -0,0 +1,52 @@
+use std::convert::TryFrom;
+
+use crate::key::account::errors::AccountError;
+
+const COIN_TYPE_ZCASH: u32 = 133;
+const COIN_TYPE_TEZOS: u32 = 1729;
+const MAX_COIN_TYPE: u32 = 0x7fff_ffff;
+
+// [SLIP-44](https://github.com/satoshilabs/slips/blob/master/slip-0044.md) coin types used in ZIP-32 paths.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CoinType {
+    Tezos,
+    Zcash,
+    Other(u32),
+}
+
+impl CoinType {
+    pub fn value(&self) -> u32 {
+        match self {
+            CoinType::Tezos => COIN_TYPE_TEZOS,
+            CoinType::Zcash => COIN_TYPE_ZCASH,
+            CoinType::Other(value) => *value,
+        }
+    }
+}
+
+// The coin type is a hardened index, so it must be less than 2^31.
+impl TryFrom<u32> for CoinType {
+    type Error = AccountError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            COIN_TYPE_TEZOS => Ok(CoinType::Tezos),
+            COIN_TYPE_ZCASH => Ok(CoinType::Zcash),
+            _ if value > MAX_COIN_TYPE => Err(AccountError::InvalidCoinType(value)),
+            _ => Ok(CoinType::Other(value)),
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_coin_types() {
+        assert_eq!(CoinType::try_from(1729), Ok(CoinType::Tezos));
+        assert_eq!(CoinType::try_from(133), Ok(CoinType::Zcash));
+        assert_eq!(CoinType::try_from(0x7fff_ffff), Ok(CoinType::Other(0x7fff_ffff)));
+        assert_eq!(CoinType::try_from(0x8000_0000), Err(AccountError::InvalidCoinType(0x8000_0000)));
+        assert_eq!(CoinType::try_from(u32::MAX), Err(AccountError::InvalidCoinType(u32::MAX)));
+    }
+}
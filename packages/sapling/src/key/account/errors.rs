@@ -0,0 +1,24 @@
+use crate::common::errors::DetailedError;
+
+#[derive(Debug, PartialEq)]
+pub enum AccountError {
+    InvalidAccountIndex(u32),
+    InvalidCoinType(u32),
+    InvalidPurpose(u32),
+    MissingLevel(&'static str),
+    NonHardenedLevel(&'static str),
+}
+
+impl DetailedError for AccountError {
+    fn details(&self) -> String {
+        use AccountError::*;
+
+        match self {
+            InvalidAccountIndex(index) => format!("account index {} is out of range, expected less than 2^31", index),
+            InvalidCoinType(coin_type) => format!("coin type {} is out of range, expected less than 2^31", coin_type),
+            InvalidPurpose(purpose) => format!("invalid purpose {}, expected 32'", purpose),
+            MissingLevel(level) => format!("the path is missing the {} level", level),
+            NonHardenedLevel(level) => format!("the {} level must be hardened", level),
+        }
+    }
+}
\ No newline at end of file
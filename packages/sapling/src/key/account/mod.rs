@@ -0,0 +1,9 @@
+pub use coin_type::CoinType;
+pub use path::{account_path, validate_zip32_path};
+pub use zip32_account::{Account, get_accounts};
+
+mod coin_type;
+mod path;
+mod zip32_account;
+
+mod errors;
\ No newline at end of file
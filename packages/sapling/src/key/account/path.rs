@@ -0,0 +1,69 @@
+use std::convert::TryFrom;
+
+use zcash_primitives::zip32::ChildIndex;
+
+use crate::key::account::coin_type::CoinType;
+use crate::key::account::errors::AccountError;
+use crate::key::bip32::Bip32Path;
+
+pub(super) const PURPOSE: u32 = 32;
+
+pub fn account_path(coin_type: CoinType, account: u32) -> String {
+    format!("m/{}'/{}'/{}'", PURPOSE, coin_type.value(), account)
+}
+
+// ZIP-32 requires the purpose, coin type and account levels to be hardened, any levels that follow are not restricted.
+// Returns the coin type and account index of the path.
+pub fn validate_zip32_path(path: &Bip32Path) -> Result<(CoinType, u32), AccountError> {
+    let purpose = hardened_level(path, 0, "purpose")?;
+    if purpose != PURPOSE {
+        return Err(AccountError::InvalidPurpose(purpose));
+    }
+
+    let coin_type = hardened_level(path, 1, "coin type")?;
+    let account = hardened_level(path, 2, "account")?;
+
+    Ok((CoinType::try_from(coin_type)?, account))
+}
+
+fn hardened_level(path: &Bip32Path, position: usize, level: &'static str) -> Result<u32, AccountError> {
+    match path.indices.get(position) {
+        Some(ChildIndex::Hardened(index)) => Ok(*index),
+        Some(ChildIndex::NonHardened(_)) => Err(AccountError::NonHardenedLevel(level)),
+        None => Err(AccountError::MissingLevel(level)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::key::bip32::split_bip32_path;
+
+    use super::*;
+
+    #[test]
+    fn creates_standard_account_paths() {
+        assert_eq!(account_path(CoinType::Tezos, 0), "m/32'/1729'/0'");
+        assert_eq!(account_path(CoinType::Zcash, 3), "m/32'/133'/3'");
+        assert_eq!(account_path(CoinType::Other(1), 1), "m/32'/1'/1'");
+    }
+
+    #[test]
+    fn validates_zip32_paths() {
+        let test_data = vec![
+            ("m/32'/1729'/0'", Ok((CoinType::Tezos, 0))),
+            ("m/32h/133h/5h", Ok((CoinType::Zcash, 5))),
+            ("m/32'/1'/2'/0", Ok((CoinType::Other(1), 2))),
+            ("m/44'/1729'/0'", Err(AccountError::InvalidPurpose(44))),
+            ("m/32/1729'/0'", Err(AccountError::NonHardenedLevel("purpose"))),
+            ("m/32'/1729/0'", Err(AccountError::NonHardenedLevel("coin type"))),
+            ("m/32'/1729'/0", Err(AccountError::NonHardenedLevel("account"))),
+            ("m/32'/1729'", Err(AccountError::MissingLevel("account"))),
+            ("m/", Err(AccountError::MissingLevel("purpose"))),
+        ];
+
+        for (path, expected) in test_data {
+            let path = split_bip32_path(path).unwrap();
+            assert_eq!(validate_zip32_path(&path), expected);
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use std::convert::TryFrom;
+
+use zcash_primitives::zip32::{ChildIndex, ExtendedFullViewingKey, ExtendedSpendingKey};
+
+use crate::address::IndexedAddress;
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::utils::assert_utils::assert_value_or_error;
+use crate::key::account::coin_type::CoinType;
+use crate::key::account::errors::AccountError;
+use crate::key::account::path::{PURPOSE, validate_zip32_path};
+use crate::key::bip32::split_bip32_path;
+
+const MAX_ACCOUNT_INDEX: u32 = 0x7fff_ffff;
+
+pub struct Account {
+    pub coin_type: CoinType,
+    pub index: u32,
+    pub xsk: ExtendedSpendingKey,
+}
+
+impl Account {
+    pub fn from_seed(seed: &[u8], coin_type: CoinType, index: u32) -> Result<Account, SaplingError> {
+        assert_account_index(index).map_err(SaplingError::caused_by)?;
+        // `CoinType::Other` can be constructed directly, bypassing `TryFrom`.
+        CoinType::try_from(coin_type.value()).map_err(SaplingError::caused_by)?;
+
+        let master_key = ExtendedSpendingKey::master(seed);
+        let xsk = ExtendedSpendingKey::from_path(&master_key, &[
+            ChildIndex::Hardened(PURPOSE),
+            ChildIndex::Hardened(coin_type.value()),
+            ChildIndex::Hardened(index),
+        ]);
+
+        Ok(Account { coin_type, index, xsk })
+    }
+
+    // Derives the account from a custom path, which must follow the ZIP-32 layout but may have further levels.
+    pub fn from_path(seed: &[u8], derivation_path: &str) -> Result<Account, SaplingError> {
+        let path = split_bip32_path(derivation_path)?;
+        let (coin_type, index) = validate_zip32_path(&path).map_err(SaplingError::caused_by)?;
+
+        let master_key = ExtendedSpendingKey::master(seed);
+        let xsk = ExtendedSpendingKey::from_path(&master_key, &path.indices);
+
+        Ok(Account { coin_type, index, xsk })
+    }
+
+    pub fn xfvk(&self) -> ExtendedFullViewingKey {
+        ExtendedFullViewingKey::from(&self.xsk)
+    }
+
+    pub fn default_address(&self) -> Result<IndexedAddress, SaplingError> {
+        let (index, address) = self.xfvk().default_address()
+            .map_err(|_| SaplingError::caused_by("could not find a valid diversifier"))?;
+
+        Ok(IndexedAddress::new(index, address))
+    }
+}
+
+// Accounts `start..start + count`, stops early at the last valid account index.
+pub fn get_accounts(seed: &[u8], coin_type: CoinType, start: u32, count: usize) -> Result<Vec<Account>, SaplingError> {
+    assert_account_index(start).map_err(SaplingError::caused_by)?;
+
+    (start..=MAX_ACCOUNT_INDEX)
+        .take(count)
+        .map(|index| Account::from_seed(seed, coin_type, index))
+        .collect()
+}
+
+fn assert_account_index(index: u32) -> Result<(), AccountError> {
+    assert_value_or_error(index <= MAX_ACCOUNT_INDEX, AccountError::InvalidAccountIndex(index))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::traits::Serializable;
+    use crate::key::SaplingKey;
+
+    use super::*;
+
+    const SEED: [u8; 32] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+                            17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31];
+
+    #[test]
+    fn derives_accounts_on_standard_paths() {
+        let test_data = vec![
+            (CoinType::Tezos, 0, "m/32'/1729'/0'"),
+            (CoinType::Tezos, 7, "m/32'/1729'/7'"),
+            (CoinType::Zcash, 0, "m/32'/133'/0'"),
+        ];
+
+        for (coin_type, index, path) in test_data {
+            let account = Account::from_seed(&SEED, coin_type, index).unwrap();
+            let expected = ExtendedSpendingKey::from_seed(&SEED, path).unwrap();
+
+            assert_eq!(account.xsk, expected);
+            assert_eq!(account.xfvk().serialize().unwrap(), ExtendedFullViewingKey::from(&expected).serialize().unwrap());
+
+            let (expected_index, expected_address) = expected.default_address().unwrap();
+            assert_eq!(account.default_address().unwrap(), IndexedAddress::new(expected_index, expected_address));
+        }
+    }
+
+    #[test]
+    fn derives_account_from_custom_path() {
+        let account = Account::from_path(&SEED, "m/32'/1'/4'/0").unwrap();
+
+        assert_eq!(account.coin_type, CoinType::Other(1));
+        assert_eq!(account.index, 4);
+        assert_eq!(account.xsk, ExtendedSpendingKey::from_seed(&SEED, "m/32'/1'/4'/0").unwrap());
+
+        assert_eq!(
+            Account::from_path(&SEED, "m/44'/1729'/0'").err(),
+            Some(SaplingError::caused_by(AccountError::InvalidPurpose(44)))
+        );
+    }
+
+    #[test]
+    fn enumerates_accounts() {
+        let accounts = get_accounts(&SEED, CoinType::Zcash, 2, 3).unwrap();
+
+        assert_eq!(accounts.iter().map(|account| account.index).collect::<Vec<u32>>(), vec![2, 3, 4]);
+        assert_eq!(accounts[1].xsk, Account::from_seed(&SEED, CoinType::Zcash, 3).unwrap().xsk);
+
+        assert_eq!(get_accounts(&SEED, CoinType::Tezos, MAX_ACCOUNT_INDEX, 5).unwrap().len(), 1);
+        assert_eq!(
+            get_accounts(&SEED, CoinType::Tezos, MAX_ACCOUNT_INDEX + 1, 1).err(),
+            Some(SaplingError::caused_by(AccountError::InvalidAccountIndex(MAX_ACCOUNT_INDEX + 1)))
+        );
+    }
+
+    #[test]
+    fn fails_to_derive_account_with_invalid_coin_type() {
+        assert_eq!(
+            Account::from_seed(&SEED, CoinType::Other(0x8000_0000), 0).err(),
+            Some(SaplingError::caused_by(AccountError::InvalidCoinType(0x8000_0000)))
+        );
+    }
+}
@@ -19,6 +19,8 @@ impl DetailedError for Bip32PathError {
 pub enum Bip32IndexError {
     Empty,
     InvalidCharacter(Vec<String>),
+    MisplacedHardenedMarker,
+    OutOfRange(u32),
     ParseError,
 }
 
@@ -27,6 +29,8 @@ impl DetailedError for Bip32IndexError {
         match self {
             Bip32IndexError::Empty => String::from("the index can't be empty"),
             Bip32IndexError::InvalidCharacter(unknown) => (format!("unknown character `{}`", unknown.join(", "))),
+            Bip32IndexError::MisplacedHardenedMarker => String::from("the hardened marker must be the last character of the index"),
+            Bip32IndexError::OutOfRange(index) => format!("index {} is out of range, expected less than 2^31", index),
             Bip32IndexError::ParseError => String::from("could not parse bip32 index"),
         }
     }
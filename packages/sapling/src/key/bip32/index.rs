@@ -8,7 +8,6 @@ use super::errors::Bip32IndexError;
 use crate::common::utils::assert_utils::{assert_value_or_error, assert_value};
 
 const MASK_HARD_DERIVATION: u32 = 0x8000_0000;
-const MASK_SOFT_DERIVATION: u32 = 0x0000_0000;
 
 const VALID_INDEX_CHARACTERS_RE: &str = "0-9";
 const VALID_IS_HARD_CHARACTERS_RE: &str = "'h";
@@ -19,6 +18,13 @@ pub fn create_index(index: &str) -> Result<Bip32Index, SaplingError> {
     parse_index(index).map_err(SaplingError::caused_by)
 }
 
+pub fn format_index(index: &Bip32Index) -> String {
+    match index {
+        Bip32Index::Hardened(index) => format!("{}'", index),
+        Bip32Index::NonHardened(index) => index.to_string(),
+    }
+}
+
 fn parse_index(index: &str) -> Result<Bip32Index, Bip32IndexError> {
     assert_index_non_empty(index)?;
     assert_index_valid(index)?;
@@ -27,10 +33,16 @@ fn parse_index(index: &str) -> Result<Bip32Index, Bip32IndexError> {
     let index_end = get_index_end(index, is_hard);
 
     let index = &index[..index_end];
-    let index = index.parse::<u32>().or_else(|_| Err(Bip32IndexError::ParseError))?;
-    let index = mask_index(index, is_hard);
+    assert_hard_marker_position(index)?;
+
+    let index = index.parse::<u32>().or(Err(Bip32IndexError::ParseError))?;
+    assert_index_in_range(index)?;
 
-    Ok(Bip32Index::from_index(index))
+    if is_hard {
+        Ok(Bip32Index::Hardened(index))
+    } else {
+        Ok(Bip32Index::NonHardened(index))
+    }
 }
 
 fn assert_index_non_empty(index: &str) -> Result<(), Bip32IndexError> {
@@ -49,13 +61,23 @@ fn assert_index_valid(index: &str) -> Result<(), Bip32IndexError> {
     })
 }
 
-fn is_hard_index(index: &str) -> bool {
+// The hardened marker is only allowed once, as the last character.
+fn assert_hard_marker_position(index: &str) -> Result<(), Bip32IndexError> {
     let is_hard_regex = {
         let is_hard_re = contains_chars_re(&[VALID_IS_HARD_CHARACTERS_RE]);
-        Regex::new(&is_hard_re).expect("could not create bip32 index, invalid `is_hard` regular expression")
+        Regex::new(&is_hard_re).expect("could not check bip32 index, invalid `is_hard` regular expression")
     };
 
-    is_hard_regex.is_match(index)
+    assert_value_or_error(!is_hard_regex.is_match(index), Bip32IndexError::MisplacedHardenedMarker)
+}
+
+// Indices above 2^31 - 1 would collide with hardened ones once masked.
+fn assert_index_in_range(index: u32) -> Result<(), Bip32IndexError> {
+    assert_value_or_error(index < MASK_HARD_DERIVATION, Bip32IndexError::OutOfRange(index))
+}
+
+fn is_hard_index(index: &str) -> bool {
+    index.ends_with('\'') || index.ends_with('h')
 }
 
 fn get_index_end(index: &str, is_hard: bool) -> usize {
@@ -67,16 +89,6 @@ fn get_index_end(index: &str, is_hard: bool) -> usize {
     }
 }
 
-fn mask_index(index: u32, is_hard: bool) -> u32 {
-    let mask = if is_hard {
-        MASK_HARD_DERIVATION
-    } else {
-        MASK_SOFT_DERIVATION
-    };
-
-    index | mask
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -134,4 +146,38 @@ mod tests {
 
         assert_eq!(too_big, SaplingError::caused_by(Bip32IndexError::ParseError));
     }
+
+    #[test]
+    fn fails_with_out_of_range_error_if_index_would_be_masked() {
+        let test_data = vec![
+            ("2147483648", SaplingError::caused_by(Bip32IndexError::OutOfRange(2147483648))),
+            ("4294967295'", SaplingError::caused_by(Bip32IndexError::OutOfRange(4294967295))),
+        ];
+
+        for (index, expected) in test_data {
+            assert_eq!(create_index(index).unwrap_err(), expected);
+        }
+        assert_eq!(create_index("2147483647'").unwrap(), Bip32Index::Hardened(2147483647));
+    }
+
+    #[test]
+    fn fails_with_misplaced_hardened_marker_error() {
+        let test_data = vec![
+            ("1'2", SaplingError::caused_by(Bip32IndexError::MisplacedHardenedMarker)),
+            ("h1", SaplingError::caused_by(Bip32IndexError::MisplacedHardenedMarker)),
+            ("1''", SaplingError::caused_by(Bip32IndexError::MisplacedHardenedMarker)),
+            ("1h'", SaplingError::caused_by(Bip32IndexError::MisplacedHardenedMarker)),
+            ("'", SaplingError::caused_by(Bip32IndexError::ParseError)),
+        ];
+
+        for (index, expected) in test_data {
+            assert_eq!(create_index(index).unwrap_err(), expected);
+        }
+    }
+
+    #[test]
+    fn formats_bip32_index() {
+        assert_eq!(format_index(&Bip32Index::Hardened(44)), "44'");
+        assert_eq!(format_index(&Bip32Index::NonHardened(0)), "0");
+    }
 }
\ No newline at end of file
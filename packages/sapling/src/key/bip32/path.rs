@@ -1,9 +1,14 @@
+use std::fmt;
+use std::str::FromStr;
+
 use crate::common::errors::{CausedBy, SaplingError};
 use crate::key::bip32::errors::Bip32PathError;
-use crate::key::bip32::index::Bip32Index;
-use crate::key::bip32::index::create_index;
+use crate::key::bip32::index::{Bip32Index, create_index, format_index};
 use crate::common::utils::assert_utils::assert_value_or_error;
 
+const PATH_PREFIX: &str = "m";
+const PATH_SEPARATOR: char = '/';
+
 #[derive(Debug, PartialEq)]
 pub struct Bip32Path {
     pub indices: Vec<Bip32Index>
@@ -19,17 +24,37 @@ impl Bip32Path {
     }
 }
 
+// The empty path is formatted as `m`, hardened indices with the `'` marker.
+impl fmt::Display for Bip32Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", PATH_PREFIX)?;
+        for index in self.indices.iter() {
+            write!(f, "{}{}", PATH_SEPARATOR, format_index(index))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Bip32Path {
+    type Err = SaplingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        split_path(s)
+    }
+}
+
+// Accepts `m`, `m/` and `m/<index>/...` with at most one trailing `/`.
 pub fn split_path(path: &str) -> Result<Bip32Path, SaplingError> {
     assert_path_non_empty(path).map_err(SaplingError::caused_by)?;
-    assert_path_prefixed(path).map_err(SaplingError::caused_by)?;
 
-    let indices_iterator = path
-        .trim_end_matches('/') // remove leading and trailing `/`
-        .split('/') // split indices
-        .skip(1) // skip the `m` prefix
-        .map(|s| create_index(s));
+    let path = path.strip_suffix(PATH_SEPARATOR).unwrap_or(path);
+    let mut segments = path.split(PATH_SEPARATOR);
+    assert_path_prefixed(segments.next()).map_err(SaplingError::caused_by)?;
 
-    let indices = unwrap_valid_indices(indices_iterator)?;
+    let indices = segments
+        .map(create_index)
+        .collect::<Result<Vec<Bip32Index>, SaplingError>>()?;
 
     Ok(Bip32Path::new(&indices))
 }
@@ -38,22 +63,8 @@ fn assert_path_non_empty(path: &str) -> Result<(), Bip32PathError> {
     assert_value_or_error(!path.is_empty(), Bip32PathError::Empty)
 }
 
-fn assert_path_prefixed(path: &str) -> Result<(), Bip32PathError> {
-    assert_value_or_error(&path[..1] == "m", Bip32PathError::MissingPrefix)
-}
-
-fn unwrap_valid_indices<I>(indices: I) -> Result<Vec<Bip32Index>, SaplingError>
-    where I: Iterator<Item = Result<Bip32Index, SaplingError>>
-{
-    let mut valid_indices: Vec<Bip32Index> = vec![];
-    for result in indices {
-        match result {
-            Ok(index) => valid_indices.push(index),
-            Err(err) => return Err(err)
-        }
-    }
-
-    Ok(valid_indices)
+fn assert_path_prefixed(prefix: Option<&str>) -> Result<(), Bip32PathError> {
+    assert_value_or_error(prefix == Some(PATH_PREFIX), Bip32PathError::MissingPrefix)
 }
 
 #[cfg(test)]
@@ -66,6 +77,14 @@ mod tests {
     fn splits_valid_bip32_path() {
         let test_data = vec![
             ("m/", Bip32Path::empty()),
+            ("m", Bip32Path::empty()),
+            ("m/32'/1729'/0'/", Bip32Path {
+                indices: vec![
+                    Bip32Index::Hardened(32),
+                    Bip32Index::Hardened(1729),
+                    Bip32Index::Hardened(0),
+                ]
+            }),
             ("m/44'/123'/0'/0/0", Bip32Path {
                 indices: vec![
                     Bip32Index::Hardened(44),
@@ -98,6 +117,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn formats_and_parses_bip32_path() {
+        let test_data = vec![
+            ("m", "m"),
+            ("m/", "m"),
+            ("m/32'/1729'/0'", "m/32'/1729'/0'"),
+            ("m/44h/0/1/", "m/44'/0/1"),
+        ];
+
+        for (path, expected) in test_data {
+            let parsed: Bip32Path = path.parse().unwrap();
+            assert_eq!(parsed.to_string(), expected);
+            assert_eq!(expected.parse::<Bip32Path>().unwrap(), parsed);
+        }
+    }
+
     #[test]
     fn fails_to_split_invalid_path_with_error() {
         let test_data = vec![
@@ -105,6 +140,12 @@ mod tests {
             ("44'/123'/0'/0/0", SaplingError::caused_by(Bip32PathError::MissingPrefix)),
             ("m/44'//0'/0/0", SaplingError::caused_by(Bip32IndexError::Empty)),
             ("m/44'/123a/0'/0/0", SaplingError::caused_by(Bip32IndexError::InvalidCharacter(vec!["a".to_owned()]))),
+            ("mx/44'", SaplingError::caused_by(Bip32PathError::MissingPrefix)),
+            ("é", SaplingError::caused_by(Bip32PathError::MissingPrefix)),
+            ("m//", SaplingError::caused_by(Bip32IndexError::Empty)),
+            ("m/44'//", SaplingError::caused_by(Bip32IndexError::Empty)),
+            ("m/2147483648", SaplingError::caused_by(Bip32IndexError::OutOfRange(2147483648))),
+            ("m/1'h", SaplingError::caused_by(Bip32IndexError::MisplacedHardenedMarker)),
         ];
 
         let actual_expected = test_data.iter()
@@ -0,0 +1,646 @@
+use std::collections::BTreeSet;
+
+use blake2b_simd::Params as Blake2bParams;
+use group::{Group, GroupEncoding};
+use zcash_primitives::constants::SPENDING_KEY_GENERATOR;
+use zcash_primitives::primitives::ProofGenerationKey;
+
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::traits::Serializable;
+use crate::common::utils::assert_utils::assert_value_or_error;
+use crate::common::utils::option_utils::ct_unwrap;
+use crate::key::frost::encoding::{
+    DKG_ROUND1_PACKAGE_TAG,
+    DKG_ROUND1_SECRET_TAG,
+    DKG_ROUND2_PACKAGE_TAG,
+    FrostMessage,
+    FrostReader,
+    KEY_PACKAGE_TAG,
+    PUBLIC_KEY_PACKAGE_TAG,
+    read_message,
+    write_message,
+    write_point,
+    write_scalar,
+};
+use crate::key::frost::errors::FrostError;
+use crate::transaction::rand_scalar;
+
+const DKG_PERSONALIZATION: &[u8; 16] = b"AirGap_FROST_DKG";
+
+// Pedersen DKG as specified for FROST, run by `max_signers` participants identified by 1..=max_signers.
+// The group key is `ak`, any `min_signers` participants can create a spend authorization signature for it.
+// Round 2 packages carry secret shares and must be sent over a confidential channel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DkgRound1Secret {
+    identifier: u16,
+    min_signers: u16,
+    max_signers: u16,
+    coefficients: Vec<jubjub::Scalar>,
+}
+
+// Broadcast to all other participants.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DkgRound1Package {
+    identifier: u16,
+    commitment: Vec<jubjub::SubgroupPoint>,
+    proof_r: jubjub::SubgroupPoint,
+    proof_mu: jubjub::Scalar,
+}
+
+// Sent to `receiver` only.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DkgRound2Package {
+    sender: u16,
+    receiver: u16,
+    share: jubjub::Scalar,
+}
+
+// The long-lived secret of a participant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyPackage {
+    identifier: u16,
+    min_signers: u16,
+    secret_share: jubjub::Scalar,
+    ak: jubjub::SubgroupPoint,
+}
+
+// The group key and the verifying shares of all participants, needed to aggregate signature shares.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PublicKeyPackage {
+    min_signers: u16,
+    ak: jubjub::SubgroupPoint,
+    verifying_shares: Vec<(u16, jubjub::SubgroupPoint)>,
+}
+
+pub fn dkg_round1(identifier: u16, min_signers: u16, max_signers: u16) -> Result<DkgRound1Secret, SaplingError> {
+    assert_value_or_error(min_signers >= 2 && min_signers <= max_signers, FrostError::InvalidSignerCount(min_signers, max_signers))
+        .map_err(SaplingError::caused_by)?;
+    assert_identifier(identifier, max_signers)?;
+
+    let coefficients = (0..min_signers).map(|_| rand_scalar()).collect();
+
+    Ok(DkgRound1Secret { identifier, min_signers, max_signers, coefficients })
+}
+
+pub fn dkg_round2(secret: &DkgRound1Secret, round1_packages: &[DkgRound1Package]) -> Result<Vec<DkgRound2Package>, SaplingError> {
+    secret.validate_round1_packages(round1_packages)?;
+
+    let packages = round1_packages.iter()
+        .map(|package| DkgRound2Package {
+            sender: secret.identifier,
+            receiver: package.identifier,
+            share: evaluate_polynomial(&secret.coefficients, package.identifier),
+        })
+        .collect();
+
+    Ok(packages)
+}
+
+pub fn dkg_round3(
+    secret: &DkgRound1Secret,
+    round1_packages: &[DkgRound1Package],
+    round2_packages: &[DkgRound2Package],
+) -> Result<KeyPackage, SaplingError> {
+    secret.validate_round1_packages(round1_packages)?;
+    assert_value_or_error(round2_packages.len() == round1_packages.len(), FrostError::UnexpectedPackageCount(round1_packages.len(), round2_packages.len()))
+        .map_err(SaplingError::caused_by)?;
+
+    let mut secret_share = evaluate_polynomial(&secret.coefficients, secret.identifier);
+    let mut senders = BTreeSet::new();
+    for package in round2_packages {
+        assert_value_or_error(package.receiver == secret.identifier, FrostError::InvalidIdentifier(package.receiver)).map_err(SaplingError::caused_by)?;
+        assert_value_or_error(senders.insert(package.sender), FrostError::DuplicateIdentifier(package.sender)).map_err(SaplingError::caused_by)?;
+
+        let round1_package = round1_packages.iter()
+            .find(|round1_package| round1_package.identifier == package.sender)
+            .ok_or(FrostError::InvalidIdentifier(package.sender))
+            .map_err(SaplingError::caused_by)?;
+
+        let expected = evaluate_commitment(&round1_package.commitment, secret.identifier);
+        assert_value_or_error(SPENDING_KEY_GENERATOR * package.share == expected, FrostError::InvalidSecretShare(package.sender))
+            .map_err(SaplingError::caused_by)?;
+
+        secret_share += package.share;
+    }
+
+    Ok(KeyPackage {
+        identifier: secret.identifier,
+        min_signers: secret.min_signers,
+        secret_share,
+        ak: group_ak(secret, round1_packages),
+    })
+}
+
+pub fn dkg_public_key_package(secret: &DkgRound1Secret, round1_packages: &[DkgRound1Package]) -> Result<PublicKeyPackage, SaplingError> {
+    secret.validate_round1_packages(round1_packages)?;
+
+    let own_commitment = secret.commitment();
+    let commitments: Vec<&[jubjub::SubgroupPoint]> = std::iter::once(own_commitment.as_slice())
+        .chain(round1_packages.iter().map(|package| package.commitment.as_slice()))
+        .collect();
+
+    let verifying_shares = (1..=secret.max_signers)
+        .map(|identifier| {
+            let share = commitments.iter().map(|commitment| evaluate_commitment(commitment, identifier)).sum();
+            (identifier, share)
+        })
+        .collect();
+
+    Ok(PublicKeyPackage {
+        min_signers: secret.min_signers,
+        ak: group_ak(secret, round1_packages),
+        verifying_shares,
+    })
+}
+
+impl DkgRound1Secret {
+    pub fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    // The proof of knowledge of the constant term uses a nonce derived from it, so the package can be recreated from the secret.
+    pub fn package(&self) -> DkgRound1Package {
+        let commitment = self.commitment();
+        let k = dkg_hash(&[&[0u8], &self.coefficients[0].to_bytes(), &self.identifier.to_le_bytes()]);
+        let proof_r = SPENDING_KEY_GENERATOR * k;
+        let c = proof_challenge(self.identifier, &commitment[0], &proof_r);
+
+        DkgRound1Package {
+            identifier: self.identifier,
+            commitment,
+            proof_r,
+            proof_mu: k + self.coefficients[0] * c,
+        }
+    }
+
+    fn commitment(&self) -> Vec<jubjub::SubgroupPoint> {
+        self.coefficients.iter().map(|coefficient| SPENDING_KEY_GENERATOR * coefficient).collect()
+    }
+
+    fn validate_round1_packages(&self, packages: &[DkgRound1Package]) -> Result<(), SaplingError> {
+        let expected = self.max_signers as usize - 1;
+        assert_value_or_error(packages.len() == expected, FrostError::UnexpectedPackageCount(expected, packages.len())).map_err(SaplingError::caused_by)?;
+
+        let mut identifiers = BTreeSet::new();
+        for package in packages {
+            assert_identifier(package.identifier, self.max_signers)?;
+            assert_value_or_error(package.identifier != self.identifier && identifiers.insert(package.identifier), FrostError::DuplicateIdentifier(package.identifier))
+                .map_err(SaplingError::caused_by)?;
+            assert_value_or_error(package.commitment.len() == self.min_signers as usize, FrostError::InvalidCommitmentLength(package.identifier))
+                .map_err(SaplingError::caused_by)?;
+
+            let c = proof_challenge(package.identifier, &package.commitment[0], &package.proof_r);
+            assert_value_or_error(
+                SPENDING_KEY_GENERATOR * package.proof_mu == package.proof_r + package.commitment[0] * c,
+                FrostError::InvalidProofOfKnowledge(package.identifier),
+            ).map_err(SaplingError::caused_by)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl DkgRound2Package {
+    pub fn sender(&self) -> u16 {
+        self.sender
+    }
+
+    pub fn receiver(&self) -> u16 {
+        self.receiver
+    }
+}
+
+impl KeyPackage {
+    pub fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    pub fn min_signers(&self) -> u16 {
+        self.min_signers
+    }
+
+    pub fn ak(&self) -> jubjub::SubgroupPoint {
+        self.ak
+    }
+
+    pub(super) fn secret_share(&self) -> &jubjub::Scalar {
+        &self.secret_share
+    }
+
+    fn validate(&self) -> Result<(), SaplingError> {
+        assert_value_or_error(self.identifier >= 1, FrostError::InvalidIdentifier(self.identifier)).map_err(SaplingError::caused_by)?;
+        assert_value_or_error(self.min_signers >= 2, FrostError::InvalidSignerCount(self.min_signers, self.min_signers)).map_err(SaplingError::caused_by)?;
+        assert_value_or_error(
+            !bool::from(self.ak.is_identity()) && self.secret_share != jubjub::Scalar::zero(),
+            FrostError::InvalidEncoding("FROST key package"),
+        ).map_err(SaplingError::caused_by)
+    }
+}
+
+impl PublicKeyPackage {
+    pub fn min_signers(&self) -> u16 {
+        self.min_signers
+    }
+
+    pub fn ak(&self) -> jubjub::SubgroupPoint {
+        self.ak
+    }
+
+    pub fn verifying_share(&self, identifier: u16) -> Option<jubjub::SubgroupPoint> {
+        self.verifying_shares.iter().find(|(id, _)| *id == identifier).map(|(_, share)| *share)
+    }
+
+    // The verifying shares must lie on a polynomial of degree `min_signers - 1` that evaluates to `ak` at 0:
+    // `ak` is interpolated from the first `min_signers - 1` shares and each of the remaining ones.
+    fn validate(&self) -> Result<(), SaplingError> {
+        let count = self.verifying_shares.len();
+        assert_value_or_error(
+            self.min_signers >= 2 && self.min_signers as usize <= count,
+            FrostError::InvalidSignerCount(self.min_signers, count as u16),
+        ).map_err(SaplingError::caused_by)?;
+
+        let mut identifiers = BTreeSet::new();
+        for (identifier, _) in self.verifying_shares.iter() {
+            assert_value_or_error(*identifier >= 1, FrostError::InvalidIdentifier(*identifier)).map_err(SaplingError::caused_by)?;
+            assert_value_or_error(identifiers.insert(*identifier), FrostError::DuplicateIdentifier(*identifier)).map_err(SaplingError::caused_by)?;
+        }
+
+        let (base, rest) = self.verifying_shares.split_at(self.min_signers as usize - 1);
+        for share in rest {
+            let shares = || base.iter().chain(std::iter::once(share));
+            let interpolated = shares()
+                .map(|(identifier, verifying_share)| {
+                    lagrange_coefficient(*identifier, shares().map(|(identifier, _)| *identifier)).map(|lambda| verifying_share * lambda)
+                })
+                .sum::<Option<jubjub::SubgroupPoint>>();
+
+            assert_value_or_error(interpolated == Some(self.ak), FrostError::InconsistentVerifyingShares).map_err(SaplingError::caused_by)?;
+        }
+
+        Ok(())
+    }
+
+    // `nsk` isn't shared, it has to be agreed on by the participants to derive the viewing key and to create proofs.
+    pub fn proof_generation_key(&self, nsk: jubjub::Scalar) -> ProofGenerationKey {
+        ProofGenerationKey { ak: self.ak, nsk }
+    }
+}
+
+pub(super) fn identifier_scalar(identifier: u16) -> jubjub::Scalar {
+    jubjub::Scalar::from(u64::from(identifier))
+}
+
+// lambda_i = prod(x_j / (x_j - x_i)) for all j != i, the coefficient of the share of `identifier` when interpolating at 0.
+pub(super) fn lagrange_coefficient<I: Iterator<Item = u16>>(identifier: u16, identifiers: I) -> Option<jubjub::Scalar> {
+    let x = identifier_scalar(identifier);
+    let (numerator, denominator) = identifiers
+        .filter(|other| *other != identifier)
+        .map(identifier_scalar)
+        .fold((jubjub::Scalar::one(), jubjub::Scalar::one()), |(numerator, denominator), x_j| (numerator * x_j, denominator * (x_j - x)));
+
+    ct_unwrap(denominator.invert()).map(|inverse| numerator * inverse)
+}
+
+fn assert_identifier(identifier: u16, max_signers: u16) -> Result<(), SaplingError> {
+    assert_value_or_error(identifier >= 1 && identifier <= max_signers, FrostError::InvalidIdentifier(identifier)).map_err(SaplingError::caused_by)
+}
+
+fn group_ak(secret: &DkgRound1Secret, round1_packages: &[DkgRound1Package]) -> jubjub::SubgroupPoint {
+    round1_packages.iter().fold(SPENDING_KEY_GENERATOR * secret.coefficients[0], |ak, package| ak + package.commitment[0])
+}
+
+fn evaluate_polynomial(coefficients: &[jubjub::Scalar], identifier: u16) -> jubjub::Scalar {
+    let x = identifier_scalar(identifier);
+    coefficients.iter().rev().fold(jubjub::Scalar::zero(), |value, coefficient| value * x + coefficient)
+}
+
+fn evaluate_commitment(commitment: &[jubjub::SubgroupPoint], identifier: u16) -> jubjub::SubgroupPoint {
+    let x = identifier_scalar(identifier);
+    commitment.iter().rev().fold(jubjub::SubgroupPoint::identity(), |value, point| value * x + point)
+}
+
+fn proof_challenge(identifier: u16, commitment: &jubjub::SubgroupPoint, proof_r: &jubjub::SubgroupPoint) -> jubjub::Scalar {
+    dkg_hash(&[&[1u8], &identifier.to_le_bytes(), &commitment.to_bytes(), &proof_r.to_bytes()])
+}
+
+fn dkg_hash(parts: &[&[u8]]) -> jubjub::Scalar {
+    let mut state = Blake2bParams::new().hash_length(64).personal(DKG_PERSONALIZATION).to_state();
+    for part in parts {
+        state.update(part);
+    }
+
+    jubjub::Scalar::from_bytes_wide(state.finalize().as_array())
+}
+
+// tag || identifier (2 bytes, LE) || min signers (2 bytes, LE) || max signers (2 bytes, LE) || coefficients
+impl FrostMessage for DkgRound1Secret {
+    fn read(reader: &mut FrostReader) -> Result<Self, SaplingError> {
+        reader.read_tag(DKG_ROUND1_SECRET_TAG)?;
+        let identifier = reader.read_u16()?;
+        let min_signers = reader.read_u16()?;
+        let max_signers = reader.read_u16()?;
+        let coefficients = (0..min_signers).map(|_| reader.read_scalar()).collect::<Result<Vec<_>, _>>()?;
+
+        assert_value_or_error(min_signers >= 2 && min_signers <= max_signers, FrostError::InvalidSignerCount(min_signers, max_signers))
+            .map_err(SaplingError::caused_by)?;
+        assert_identifier(identifier, max_signers)?;
+
+        Ok(DkgRound1Secret { identifier, min_signers, max_signers, coefficients })
+    }
+
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.push(DKG_ROUND1_SECRET_TAG);
+        bytes.extend_from_slice(&self.identifier.to_le_bytes());
+        bytes.extend_from_slice(&self.min_signers.to_le_bytes());
+        bytes.extend_from_slice(&self.max_signers.to_le_bytes());
+        self.coefficients.iter().for_each(|coefficient| write_scalar(bytes, coefficient));
+    }
+}
+
+// tag || identifier (2 bytes, LE) || commitment length (2 bytes, LE) || commitment || proof R || proof mu
+impl FrostMessage for DkgRound1Package {
+    fn read(reader: &mut FrostReader) -> Result<Self, SaplingError> {
+        reader.read_tag(DKG_ROUND1_PACKAGE_TAG)?;
+        let identifier = reader.read_u16()?;
+        let commitment_len = reader.read_u16()?;
+        let commitment = (0..commitment_len).map(|_| reader.read_point()).collect::<Result<Vec<_>, _>>()?;
+        assert_value_or_error(!commitment.is_empty(), FrostError::InvalidCommitmentLength(identifier)).map_err(SaplingError::caused_by)?;
+        let proof_r = reader.read_point()?;
+        let proof_mu = reader.read_scalar()?;
+
+        Ok(DkgRound1Package { identifier, commitment, proof_r, proof_mu })
+    }
+
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.push(DKG_ROUND1_PACKAGE_TAG);
+        bytes.extend_from_slice(&self.identifier.to_le_bytes());
+        bytes.extend_from_slice(&(self.commitment.len() as u16).to_le_bytes());
+        self.commitment.iter().for_each(|point| write_point(bytes, point));
+        write_point(bytes, &self.proof_r);
+        write_scalar(bytes, &self.proof_mu);
+    }
+}
+
+// tag || sender (2 bytes, LE) || receiver (2 bytes, LE) || share
+impl FrostMessage for DkgRound2Package {
+    fn read(reader: &mut FrostReader) -> Result<Self, SaplingError> {
+        reader.read_tag(DKG_ROUND2_PACKAGE_TAG)?;
+
+        Ok(DkgRound2Package { sender: reader.read_u16()?, receiver: reader.read_u16()?, share: reader.read_scalar()? })
+    }
+
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.push(DKG_ROUND2_PACKAGE_TAG);
+        bytes.extend_from_slice(&self.sender.to_le_bytes());
+        bytes.extend_from_slice(&self.receiver.to_le_bytes());
+        write_scalar(bytes, &self.share);
+    }
+}
+
+// tag || identifier (2 bytes, LE) || min signers (2 bytes, LE) || secret share || ak
+impl FrostMessage for KeyPackage {
+    fn read(reader: &mut FrostReader) -> Result<Self, SaplingError> {
+        reader.read_tag(KEY_PACKAGE_TAG)?;
+
+        let key_package = KeyPackage {
+            identifier: reader.read_u16()?,
+            min_signers: reader.read_u16()?,
+            secret_share: reader.read_scalar()?,
+            ak: reader.read_point()?,
+        };
+        key_package.validate()?;
+
+        Ok(key_package)
+    }
+
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.push(KEY_PACKAGE_TAG);
+        bytes.extend_from_slice(&self.identifier.to_le_bytes());
+        bytes.extend_from_slice(&self.min_signers.to_le_bytes());
+        write_scalar(bytes, &self.secret_share);
+        write_point(bytes, &self.ak);
+    }
+}
+
+// tag || min signers (2 bytes, LE) || ak || share count (2 bytes, LE) || (identifier (2 bytes, LE) || verifying share)*
+impl FrostMessage for PublicKeyPackage {
+    fn read(reader: &mut FrostReader) -> Result<Self, SaplingError> {
+        reader.read_tag(PUBLIC_KEY_PACKAGE_TAG)?;
+        let min_signers = reader.read_u16()?;
+        let ak = reader.read_point()?;
+        let count = reader.read_u16()?;
+        let verifying_shares = (0..count)
+            .map(|_| Ok((reader.read_u16()?, reader.read_point()?)))
+            .collect::<Result<Vec<_>, SaplingError>>()?;
+
+        let public_key_package = PublicKeyPackage { min_signers, ak, verifying_shares };
+        public_key_package.validate()?;
+
+        Ok(public_key_package)
+    }
+
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.push(PUBLIC_KEY_PACKAGE_TAG);
+        bytes.extend_from_slice(&self.min_signers.to_le_bytes());
+        write_point(bytes, &self.ak);
+        bytes.extend_from_slice(&(self.verifying_shares.len() as u16).to_le_bytes());
+        for (identifier, share) in self.verifying_shares.iter() {
+            bytes.extend_from_slice(&identifier.to_le_bytes());
+            write_point(bytes, share);
+        }
+    }
+}
+
+impl Serializable<Vec<u8>, SaplingError> for DkgRound1Secret {
+    fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
+        read_message("FROST DKG round 1 secret", &serialized)
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
+        Ok(write_message(self))
+    }
+}
+
+impl Serializable<Vec<u8>, SaplingError> for DkgRound1Package {
+    fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
+        read_message("FROST DKG round 1 package", &serialized)
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
+        Ok(write_message(self))
+    }
+}
+
+impl Serializable<Vec<u8>, SaplingError> for DkgRound2Package {
+    fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
+        read_message("FROST DKG round 2 package", &serialized)
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
+        Ok(write_message(self))
+    }
+}
+
+impl Serializable<Vec<u8>, SaplingError> for KeyPackage {
+    fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
+        read_message("FROST key package", &serialized)
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
+        Ok(write_message(self))
+    }
+}
+
+impl Serializable<Vec<u8>, SaplingError> for PublicKeyPackage {
+    fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
+        read_message("FROST public key package", &serialized)
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
+        Ok(write_message(self))
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use crate::key::frost::encoding::read_frost_messages;
+    use crate::key::frost::encoding::tests::write_frost_messages;
+
+    use super::*;
+
+    pub(crate) fn run_dkg(min_signers: u16, max_signers: u16) -> (Vec<KeyPackage>, PublicKeyPackage) {
+        let secrets: Vec<DkgRound1Secret> = (1..=max_signers).map(|identifier| dkg_round1(identifier, min_signers, max_signers).unwrap()).collect();
+        let round1_packages: Vec<DkgRound1Package> = secrets.iter().map(|secret| secret.package()).collect();
+        let others = |identifier: u16| -> Vec<DkgRound1Package> {
+            round1_packages.iter().filter(|package| package.identifier != identifier).cloned().collect()
+        };
+
+        let round2_packages: Vec<DkgRound2Package> = secrets.iter()
+            .flat_map(|secret| dkg_round2(secret, &others(secret.identifier)).unwrap())
+            .collect();
+
+        let key_packages = secrets.iter()
+            .map(|secret| {
+                let received: Vec<DkgRound2Package> = round2_packages.iter().filter(|package| package.receiver == secret.identifier).cloned().collect();
+                dkg_round3(secret, &others(secret.identifier), &received).unwrap()
+            })
+            .collect();
+
+        let public_key_packages: Vec<PublicKeyPackage> = secrets.iter()
+            .map(|secret| dkg_public_key_package(secret, &others(secret.identifier)).unwrap())
+            .collect();
+        assert!(public_key_packages.iter().all(|package| *package == public_key_packages[0]));
+
+        (key_packages, public_key_packages[0].clone())
+    }
+
+    #[test]
+    fn generates_shares_of_the_same_key() {
+        let (key_packages, public_key_package) = run_dkg(2, 3);
+
+        for key_package in key_packages.iter() {
+            assert_eq!(key_package.ak, public_key_package.ak);
+            assert_eq!(Some(SPENDING_KEY_GENERATOR * key_package.secret_share), public_key_package.verifying_share(key_package.identifier));
+        }
+
+        // s = 2 * s_1 - s_2
+        let ask = key_packages[0].secret_share.double() - key_packages[1].secret_share;
+        assert_eq!(SPENDING_KEY_GENERATOR * ask, public_key_package.ak);
+    }
+
+    #[test]
+    fn rejects_invalid_round1_packages() {
+        let secret = dkg_round1(1, 2, 3).unwrap();
+        let mut packages = vec![dkg_round1(2, 2, 3).unwrap().package(), dkg_round1(3, 2, 3).unwrap().package()];
+
+        packages[1].proof_mu += jubjub::Scalar::one();
+        assert_eq!(dkg_round2(&secret, &packages).err(), Some(SaplingError::caused_by(FrostError::InvalidProofOfKnowledge(3))));
+
+        packages[1] = packages[0].clone();
+        assert_eq!(dkg_round2(&secret, &packages).err(), Some(SaplingError::caused_by(FrostError::DuplicateIdentifier(2))));
+
+        assert_eq!(dkg_round2(&secret, &packages[..1]).err(), Some(SaplingError::caused_by(FrostError::UnexpectedPackageCount(2, 1))));
+        assert_eq!(dkg_round1(1, 1, 3).err(), Some(SaplingError::caused_by(FrostError::InvalidSignerCount(1, 3))));
+        assert_eq!(dkg_round1(4, 2, 3).err(), Some(SaplingError::caused_by(FrostError::InvalidIdentifier(4))));
+    }
+
+    #[test]
+    fn rejects_inconsistent_key_packages() {
+        let (key_packages, public_key_package) = run_dkg(3, 5);
+        assert_eq!(PublicKeyPackage::deserialize(public_key_package.serialize().unwrap()).unwrap(), public_key_package);
+        assert_eq!(KeyPackage::deserialize(key_packages[0].serialize().unwrap()).unwrap(), key_packages[0]);
+
+        let mut modified = public_key_package.clone();
+        modified.verifying_shares[4].1 += SPENDING_KEY_GENERATOR;
+        assert_eq!(
+            PublicKeyPackage::deserialize(modified.serialize().unwrap()).err(),
+            Some(SaplingError::caused_by(FrostError::InconsistentVerifyingShares)),
+        );
+
+        let mut modified = public_key_package.clone();
+        modified.ak = key_packages[0].ak + SPENDING_KEY_GENERATOR;
+        assert_eq!(
+            PublicKeyPackage::deserialize(modified.serialize().unwrap()).err(),
+            Some(SaplingError::caused_by(FrostError::InconsistentVerifyingShares)),
+        );
+
+        let mut modified = public_key_package.clone();
+        modified.verifying_shares[1].0 = 1;
+        assert_eq!(
+            PublicKeyPackage::deserialize(modified.serialize().unwrap()).err(),
+            Some(SaplingError::caused_by(FrostError::DuplicateIdentifier(1))),
+        );
+
+        let mut modified = public_key_package;
+        modified.verifying_shares.truncate(2);
+        assert_eq!(
+            PublicKeyPackage::deserialize(modified.serialize().unwrap()).err(),
+            Some(SaplingError::caused_by(FrostError::InvalidSignerCount(3, 2))),
+        );
+
+        let mut modified = key_packages[0].clone();
+        modified.secret_share = jubjub::Scalar::zero();
+        assert_eq!(
+            KeyPackage::deserialize(modified.serialize().unwrap()).err(),
+            Some(SaplingError::caused_by(FrostError::InvalidEncoding("FROST key package"))),
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_secret_share() {
+        let secrets = [dkg_round1(1, 2, 2).unwrap(), dkg_round1(2, 2, 2).unwrap()];
+
+        let mut round2_packages = dkg_round2(&secrets[1], &[secrets[0].package()]).unwrap();
+        round2_packages[0].share += jubjub::Scalar::one();
+
+        let actual = dkg_round3(&secrets[0], &[secrets[1].package()], &round2_packages);
+
+        assert_eq!(actual.err(), Some(SaplingError::caused_by(FrostError::InvalidSecretShare(2))));
+    }
+
+    #[test]
+    fn serializes_messages() {
+        let secret = dkg_round1(1, 2, 3).unwrap();
+        let (key_packages, public_key_package) = run_dkg(2, 3);
+
+        assert_eq!(DkgRound1Secret::deserialize(secret.serialize().unwrap()).unwrap(), secret);
+        assert_eq!(DkgRound1Package::deserialize(secret.package().serialize().unwrap()).unwrap(), secret.package());
+        assert_eq!(KeyPackage::deserialize(key_packages[0].serialize().unwrap()).unwrap(), key_packages[0]);
+        assert_eq!(PublicKeyPackage::deserialize(public_key_package.serialize().unwrap()).unwrap(), public_key_package);
+
+        let packages = vec![secret.package(), dkg_round1(2, 2, 3).unwrap().package()];
+        assert_eq!(read_frost_messages::<DkgRound1Package>(&write_frost_messages(&packages)).unwrap(), packages);
+
+        let mut serialized = secret.package().serialize().unwrap();
+        assert_eq!(
+            DkgRound1Secret::deserialize(serialized.clone()).err(),
+            Some(SaplingError::caused_by(FrostError::UnexpectedMessage("FROST DKG round 1 secret", DKG_ROUND1_PACKAGE_TAG))),
+        );
+
+        serialized.push(0);
+        assert_eq!(
+            DkgRound1Package::deserialize(serialized).err(),
+            Some(SaplingError::caused_by(FrostError::InvalidEncoding("FROST DKG round 1 package"))),
+        );
+    }
+}
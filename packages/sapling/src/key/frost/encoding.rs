@@ -0,0 +1,133 @@
+use std::convert::TryInto;
+
+use group::GroupEncoding;
+
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::utils::option_utils::ct_unwrap;
+use crate::key::frost::errors::FrostError;
+
+// Every message starts with a tag, so that a message of one round can't be mistaken for another
+// when they are passed around (e.g. as QR codes). Messages are self-delimiting, lists are concatenated.
+// 0x06 is reserved, signing nonces are never serialized.
+pub(super) const DKG_ROUND1_SECRET_TAG: u8 = 0x01;
+pub(super) const DKG_ROUND1_PACKAGE_TAG: u8 = 0x02;
+pub(super) const DKG_ROUND2_PACKAGE_TAG: u8 = 0x03;
+pub(super) const KEY_PACKAGE_TAG: u8 = 0x04;
+pub(super) const PUBLIC_KEY_PACKAGE_TAG: u8 = 0x05;
+pub(super) const SIGNING_COMMITMENTS_TAG: u8 = 0x07;
+pub(super) const SIGNING_PACKAGE_TAG: u8 = 0x08;
+pub(super) const SIGNATURE_SHARE_TAG: u8 = 0x09;
+
+pub trait FrostMessage: Sized {
+    fn read(reader: &mut FrostReader) -> Result<Self, SaplingError>;
+    fn write(&self, bytes: &mut Vec<u8>);
+}
+
+pub struct FrostReader<'a> {
+    name: &'static str,
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> FrostReader<'a> {
+    pub(super) fn new(name: &'static str, bytes: &'a [u8]) -> Self {
+        FrostReader { name, bytes, offset: 0 }
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.offset == self.bytes.len()
+    }
+
+    pub(super) fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], SaplingError> {
+        let end = self.offset.saturating_add(len);
+        let bytes = self.bytes.get(self.offset..end).ok_or_else(|| self.invalid())?;
+        self.offset = end;
+
+        Ok(bytes)
+    }
+
+    pub(super) fn read_tag(&mut self, tag: u8) -> Result<(), SaplingError> {
+        let actual = self.read_u8()?;
+        if actual != tag {
+            return Err(SaplingError::caused_by(FrostError::UnexpectedMessage(self.name, actual)));
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn read_u8(&mut self) -> Result<u8, SaplingError> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    pub(super) fn read_u16(&mut self) -> Result<u16, SaplingError> {
+        Ok(u16::from_le_bytes(self.read_bytes(2)?.try_into().unwrap()))
+    }
+
+    pub(super) fn read_array(&mut self) -> Result<[u8; 32], SaplingError> {
+        Ok(self.read_bytes(32)?.try_into().unwrap())
+    }
+
+    pub(super) fn read_scalar(&mut self) -> Result<jubjub::Scalar, SaplingError> {
+        let bytes = self.read_array()?;
+        ct_unwrap(jubjub::Scalar::from_bytes(&bytes)).ok_or_else(|| self.invalid())
+    }
+
+    pub(super) fn read_point(&mut self) -> Result<jubjub::SubgroupPoint, SaplingError> {
+        let bytes = self.read_array()?;
+        ct_unwrap(jubjub::SubgroupPoint::from_bytes(&bytes)).ok_or_else(|| self.invalid())
+    }
+
+    fn invalid(&self) -> SaplingError {
+        SaplingError::caused_by(FrostError::InvalidEncoding(self.name))
+    }
+}
+
+pub(super) fn write_point(bytes: &mut Vec<u8>, point: &jubjub::SubgroupPoint) {
+    bytes.extend_from_slice(&point.to_bytes());
+}
+
+pub(super) fn write_scalar(bytes: &mut Vec<u8>, scalar: &jubjub::Scalar) {
+    bytes.extend_from_slice(&scalar.to_bytes());
+}
+
+pub(super) fn read_message<T: FrostMessage>(name: &'static str, bytes: &[u8]) -> Result<T, SaplingError> {
+    let mut reader = FrostReader::new(name, bytes);
+    let message = T::read(&mut reader)?;
+    if !reader.is_empty() {
+        return Err(reader.invalid());
+    }
+
+    Ok(message)
+}
+
+pub(super) fn write_message<T: FrostMessage>(message: &T) -> Vec<u8> {
+    let mut bytes = vec![];
+    message.write(&mut bytes);
+
+    bytes
+}
+
+// Concatenated messages, as passed through the bindings.
+pub fn read_frost_messages<T: FrostMessage>(bytes: &[u8]) -> Result<Vec<T>, SaplingError> {
+    let mut reader = FrostReader::new("FROST message list", bytes);
+    let mut messages = vec![];
+    while !reader.is_empty() {
+        messages.push(T::read(&mut reader)?);
+    }
+
+    Ok(messages)
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+
+    pub(crate) fn write_frost_messages<T: FrostMessage>(messages: &[T]) -> Vec<u8> {
+        let mut bytes = vec![];
+        for message in messages {
+            message.write(&mut bytes);
+        }
+
+        bytes
+    }
+}
@@ -0,0 +1,48 @@
+use crate::common::errors::DetailedError;
+
+#[derive(Debug, PartialEq)]
+pub enum FrostError {
+    InvalidSignerCount(u16, u16),
+    InvalidIdentifier(u16),
+    DuplicateIdentifier(u16),
+    UnexpectedPackageCount(usize, usize),
+    InvalidCommitmentLength(u16),
+    InvalidProofOfKnowledge(u16),
+    InvalidSecretShare(u16),
+    NotEnoughSigners(usize, u16),
+    MissingCommitment(u16),
+    IdentityCommitment(u16),
+    NoncesMismatch,
+    InvalidSignatureShare(u16),
+    InconsistentVerifyingShares,
+    InvalidSignature,
+    SigningPackageMismatch,
+    InvalidEncoding(&'static str),
+    UnexpectedMessage(&'static str, u8),
+}
+
+impl DetailedError for FrostError {
+    fn details(&self) -> String {
+        use FrostError::*;
+
+        match self {
+            InvalidSignerCount(min_signers, max_signers) => format!("Invalid threshold {} of {} signers", min_signers, max_signers),
+            InvalidIdentifier(identifier) => format!("Invalid participant identifier {}", identifier),
+            DuplicateIdentifier(identifier) => format!("Participant {} is listed more than once", identifier),
+            UnexpectedPackageCount(expected, actual) => format!("Expected {} packages, got {}", expected, actual),
+            InvalidCommitmentLength(identifier) => format!("Participant {} committed to a polynomial of the wrong degree", identifier),
+            InvalidProofOfKnowledge(identifier) => format!("Participant {} sent an invalid proof of knowledge", identifier),
+            InvalidSecretShare(identifier) => format!("Participant {} sent a secret share that doesn't match its commitment", identifier),
+            NotEnoughSigners(actual, min_signers) => format!("Expected at least {} signers, got {}", min_signers, actual),
+            MissingCommitment(identifier) => format!("The signing package has no commitments of participant {}", identifier),
+            IdentityCommitment(identifier) => format!("Participant {} committed to the identity", identifier),
+            NoncesMismatch => String::from("The signing nonces don't match the commitments in the signing package"),
+            InvalidSignatureShare(identifier) => format!("Participant {} sent an invalid signature share", identifier),
+            InconsistentVerifyingShares => String::from("The verifying shares don't match the group key"),
+            InvalidSignature => String::from("The aggregated signature is invalid"),
+            SigningPackageMismatch => String::from("The signing package was created for a different randomizer or sighash"),
+            InvalidEncoding(name) => format!("Invalid {} encoding", name),
+            UnexpectedMessage(name, tag) => format!("Expected {}, got a message tagged {}", name, tag),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub use dkg::{dkg_public_key_package, dkg_round1, dkg_round2, dkg_round3, DkgRound1Package, DkgRound1Secret, DkgRound2Package, KeyPackage, PublicKeyPackage};
+pub use encoding::read_frost_messages;
+pub use errors::FrostError;
+pub use signing::{aggregate, FrostSpendAuthSigner, sign, SignatureShare, signing_nonces, SigningCommitments, SigningNonces, SigningPackage};
+
+mod dkg;
+mod encoding;
+mod signing;
+mod errors;
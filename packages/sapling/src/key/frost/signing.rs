@@ -0,0 +1,498 @@
+use std::collections::BTreeSet;
+
+use blake2b_simd::Params as Blake2bParams;
+use group::{Group, GroupEncoding};
+use zcash_primitives::constants::SPENDING_KEY_GENERATOR;
+use zcash_primitives::redjubjub::{PublicKey, Signature};
+use zcash_primitives::util::hash_to_scalar;
+
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::traits::Serializable;
+use crate::common::utils::assert_utils::assert_value_or_error;
+use crate::key::frost::dkg::{KeyPackage, lagrange_coefficient, PublicKeyPackage};
+use crate::key::frost::encoding::{
+    FrostMessage,
+    FrostReader,
+    read_message,
+    SIGNATURE_SHARE_TAG,
+    SIGNING_COMMITMENTS_TAG,
+    SIGNING_PACKAGE_TAG,
+    write_message,
+    write_point,
+    write_scalar,
+};
+use crate::key::frost::errors::FrostError;
+use crate::transaction::{rand_bytes, SpendAuthSigner, verify_spend_auth_sig};
+
+const BINDING_FACTOR_PERSONALIZATION: &[u8; 16] = b"AirGap_FROST_Rho";
+const NONCE_PERSONALIZATION: &[u8; 16] = b"AirGap_FROST_Nce";
+
+// Single-use, must never be used for more than one signing package. The nonces can't be copied or serialized,
+// they only leave the library as an opaque reference that is freed by `sign`.
+pub struct SigningNonces {
+    identifier: u16,
+    hiding: jubjub::Scalar,
+    binding: jubjub::Scalar,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SigningCommitments {
+    identifier: u16,
+    hiding: jubjub::SubgroupPoint,
+    binding: jubjub::SubgroupPoint,
+}
+
+// Everything the signers need to agree on: the randomizer `ar`, the sighash and the commitments of the signers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SigningPackage {
+    ar: jubjub::Scalar,
+    sighash: [u8; 32],
+    commitments: Vec<SigningCommitments>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignatureShare {
+    identifier: u16,
+    share: jubjub::Scalar,
+}
+
+// Hedged nonces, a bad random number generator alone doesn't leak the secret share.
+pub fn signing_nonces(key_package: &KeyPackage) -> SigningNonces {
+    SigningNonces {
+        identifier: key_package.identifier(),
+        hiding: nonce(key_package.secret_share()),
+        binding: nonce(key_package.secret_share()),
+    }
+}
+
+// z_i = d_i + e_i * rho_i + lambda_i * s_i * c, the nonces are consumed even if signing fails.
+pub fn sign(key_package: &KeyPackage, nonces: SigningNonces, package: &SigningPackage) -> Result<SignatureShare, SaplingError> {
+    let identifier = key_package.identifier();
+    assert_value_or_error(nonces.identifier == identifier, FrostError::NoncesMismatch).map_err(SaplingError::caused_by)?;
+    package.assert_signers(key_package.min_signers())?;
+
+    let commitments = package.commitments_of(identifier)?;
+    assert_value_or_error(*commitments == nonces.commitments(), FrostError::NoncesMismatch).map_err(SaplingError::caused_by)?;
+
+    let context = package.context(key_package.ak());
+    let binding_factor = context.binding_factor(identifier);
+    let lambda = package.lagrange_coefficient(identifier)?;
+
+    Ok(SignatureShare {
+        identifier,
+        share: nonces.hiding + nonces.binding * binding_factor + lambda * key_package.secret_share() * context.challenge,
+    })
+}
+
+// Verifies each share against the verifying share of its signer, so that a misbehaving signer can be identified.
+pub fn aggregate(
+    package: &SigningPackage,
+    shares: &[SignatureShare],
+    public_key_package: &PublicKeyPackage,
+) -> Result<Signature, SaplingError> {
+    package.assert_signers(public_key_package.min_signers())?;
+    assert_value_or_error(shares.len() == package.commitments.len(), FrostError::UnexpectedPackageCount(package.commitments.len(), shares.len()))
+        .map_err(SaplingError::caused_by)?;
+
+    let context = package.context(public_key_package.ak());
+
+    let mut identifiers = BTreeSet::new();
+    let mut z = context.challenge * package.ar;
+    for share in shares {
+        assert_value_or_error(identifiers.insert(share.identifier), FrostError::DuplicateIdentifier(share.identifier)).map_err(SaplingError::caused_by)?;
+
+        let commitments = package.commitments_of(share.identifier)?;
+        let verifying_share = public_key_package.verifying_share(share.identifier)
+            .ok_or(FrostError::InvalidIdentifier(share.identifier))
+            .map_err(SaplingError::caused_by)?;
+        let lambda = package.lagrange_coefficient(share.identifier)?;
+
+        let expected = commitments.hiding + commitments.binding * context.binding_factor(share.identifier) + verifying_share * (context.challenge * lambda);
+        assert_value_or_error(SPENDING_KEY_GENERATOR * share.share == expected, FrostError::InvalidSignatureShare(share.identifier))
+            .map_err(SaplingError::caused_by)?;
+
+        z += share.share;
+    }
+
+    let mut signature = context.group_commitment.to_bytes().to_vec();
+    signature.extend_from_slice(&z.to_bytes());
+    let signature = Signature::deserialize(signature)?;
+
+    let rk = PublicKey(context.rk.into());
+    assert_value_or_error(verify_spend_auth_sig(&rk, package.sighash, &signature), FrostError::InvalidSignature).map_err(SaplingError::caused_by)?;
+
+    Ok(signature)
+}
+
+// Aggregates the shares when the spend description is signed.
+pub struct FrostSpendAuthSigner<'a> {
+    package: &'a SigningPackage,
+    shares: &'a [SignatureShare],
+    public_key_package: &'a PublicKeyPackage,
+}
+
+impl<'a> FrostSpendAuthSigner<'a> {
+    pub fn new(package: &'a SigningPackage, shares: &'a [SignatureShare], public_key_package: &'a PublicKeyPackage) -> Self {
+        FrostSpendAuthSigner { package, shares, public_key_package }
+    }
+}
+
+impl SpendAuthSigner for FrostSpendAuthSigner<'_> {
+    fn sign_spend_auth(&self, ar: jubjub::Scalar, sighash: [u8; 32]) -> Result<Signature, SaplingError> {
+        assert_value_or_error(self.package.ar == ar && self.package.sighash == sighash, FrostError::SigningPackageMismatch)
+            .map_err(SaplingError::caused_by)?;
+
+        aggregate(self.package, self.shares, self.public_key_package)
+    }
+}
+
+impl SigningNonces {
+    pub fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    pub fn commitments(&self) -> SigningCommitments {
+        SigningCommitments {
+            identifier: self.identifier,
+            hiding: SPENDING_KEY_GENERATOR * self.hiding,
+            binding: SPENDING_KEY_GENERATOR * self.binding,
+        }
+    }
+}
+
+impl SigningCommitments {
+    pub fn identifier(&self) -> u16 {
+        self.identifier
+    }
+}
+
+impl SigningPackage {
+    pub fn new(ar: jubjub::Scalar, sighash: [u8; 32], mut commitments: Vec<SigningCommitments>) -> Result<SigningPackage, SaplingError> {
+        commitments.sort_by_key(|commitments| commitments.identifier);
+        assert_commitments(&commitments)?;
+
+        Ok(SigningPackage { ar, sighash, commitments })
+    }
+
+    pub fn ar(&self) -> jubjub::Scalar {
+        self.ar
+    }
+
+    pub fn sighash(&self) -> [u8; 32] {
+        self.sighash
+    }
+
+    fn assert_signers(&self, min_signers: u16) -> Result<(), SaplingError> {
+        assert_value_or_error(self.commitments.len() >= min_signers as usize, FrostError::NotEnoughSigners(self.commitments.len(), min_signers))
+            .map_err(SaplingError::caused_by)
+    }
+
+    fn commitments_of(&self, identifier: u16) -> Result<&SigningCommitments, SaplingError> {
+        self.commitments.iter()
+            .find(|commitments| commitments.identifier == identifier)
+            .ok_or(FrostError::MissingCommitment(identifier))
+            .map_err(SaplingError::caused_by)
+    }
+
+    // rk = ak + ar * G, rho_i = H(rk || sighash || commitments || i), R = sum(D_i + rho_i * E_i), c = H*(R || rk || sighash)
+    fn context(&self, ak: jubjub::SubgroupPoint) -> SigningContext {
+        let rk = ak + SPENDING_KEY_GENERATOR * self.ar;
+
+        let mut prefix = rk.to_bytes().to_vec();
+        prefix.extend_from_slice(&self.sighash);
+        self.commitments.iter().for_each(|commitments| commitments.write(&mut prefix));
+
+        let binding_factors: Vec<(u16, jubjub::Scalar)> = self.commitments.iter()
+            .map(|commitments| (commitments.identifier, binding_factor(&prefix, commitments.identifier)))
+            .collect();
+
+        let group_commitment = self.commitments.iter()
+            .zip(binding_factors.iter())
+            .map(|(commitments, (_, binding_factor))| commitments.hiding + commitments.binding * binding_factor)
+            .sum::<jubjub::SubgroupPoint>();
+
+        let mut message = rk.to_bytes().to_vec();
+        message.extend_from_slice(&self.sighash);
+        let challenge = hash_to_scalar(b"Zcash_RedJubjubH", &group_commitment.to_bytes(), &message);
+
+        SigningContext { rk, binding_factors, group_commitment, challenge }
+    }
+
+    fn lagrange_coefficient(&self, identifier: u16) -> Result<jubjub::Scalar, SaplingError> {
+        lagrange_coefficient(identifier, self.commitments.iter().map(|commitments| commitments.identifier))
+            .ok_or(FrostError::DuplicateIdentifier(identifier))
+            .map_err(SaplingError::caused_by)
+    }
+}
+
+impl SignatureShare {
+    pub fn identifier(&self) -> u16 {
+        self.identifier
+    }
+}
+
+struct SigningContext {
+    rk: jubjub::SubgroupPoint,
+    binding_factors: Vec<(u16, jubjub::Scalar)>,
+    group_commitment: jubjub::SubgroupPoint,
+    challenge: jubjub::Scalar,
+}
+
+impl SigningContext {
+    fn binding_factor(&self, identifier: u16) -> jubjub::Scalar {
+        self.binding_factors.iter()
+            .find(|(id, _)| *id == identifier)
+            .map(|(_, binding_factor)| *binding_factor)
+            .unwrap_or_else(jubjub::Scalar::zero)
+    }
+}
+
+fn assert_commitments(commitments: &[SigningCommitments]) -> Result<(), SaplingError> {
+    assert_value_or_error(!commitments.is_empty(), FrostError::NotEnoughSigners(0, 1)).map_err(SaplingError::caused_by)?;
+
+    let mut identifiers = BTreeSet::new();
+    for commitments in commitments {
+        assert_value_or_error(commitments.identifier != 0, FrostError::InvalidIdentifier(commitments.identifier)).map_err(SaplingError::caused_by)?;
+        assert_value_or_error(identifiers.insert(commitments.identifier), FrostError::DuplicateIdentifier(commitments.identifier))
+            .map_err(SaplingError::caused_by)?;
+        assert_value_or_error(
+            !bool::from(commitments.hiding.is_identity() | commitments.binding.is_identity()),
+            FrostError::IdentityCommitment(commitments.identifier),
+        ).map_err(SaplingError::caused_by)?;
+    }
+
+    Ok(())
+}
+
+fn binding_factor(prefix: &[u8], identifier: u16) -> jubjub::Scalar {
+    let hash = Blake2bParams::new()
+        .hash_length(64)
+        .personal(BINDING_FACTOR_PERSONALIZATION)
+        .to_state()
+        .update(prefix)
+        .update(&identifier.to_le_bytes())
+        .finalize();
+
+    jubjub::Scalar::from_bytes_wide(hash.as_array())
+}
+
+fn nonce(secret: &jubjub::Scalar) -> jubjub::Scalar {
+    let hash = Blake2bParams::new()
+        .hash_length(64)
+        .personal(NONCE_PERSONALIZATION)
+        .to_state()
+        .update(&rand_bytes(32))
+        .update(&secret.to_bytes())
+        .finalize();
+
+    jubjub::Scalar::from_bytes_wide(hash.as_array())
+}
+
+// tag || identifier (2 bytes, LE) || hiding commitment || binding commitment
+impl FrostMessage for SigningCommitments {
+    fn read(reader: &mut FrostReader) -> Result<Self, SaplingError> {
+        reader.read_tag(SIGNING_COMMITMENTS_TAG)?;
+
+        Ok(SigningCommitments { identifier: reader.read_u16()?, hiding: reader.read_point()?, binding: reader.read_point()? })
+    }
+
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.push(SIGNING_COMMITMENTS_TAG);
+        bytes.extend_from_slice(&self.identifier.to_le_bytes());
+        write_point(bytes, &self.hiding);
+        write_point(bytes, &self.binding);
+    }
+}
+
+// tag || ar || sighash || commitment count (2 bytes, LE) || signing commitments, sorted by identifier
+impl FrostMessage for SigningPackage {
+    fn read(reader: &mut FrostReader) -> Result<Self, SaplingError> {
+        reader.read_tag(SIGNING_PACKAGE_TAG)?;
+        let ar = reader.read_scalar()?;
+        let sighash = reader.read_array()?;
+        let count = reader.read_u16()?;
+        let commitments = (0..count).map(|_| SigningCommitments::read(reader)).collect::<Result<Vec<_>, _>>()?;
+
+        SigningPackage::new(ar, sighash, commitments)
+    }
+
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.push(SIGNING_PACKAGE_TAG);
+        write_scalar(bytes, &self.ar);
+        bytes.extend_from_slice(&self.sighash);
+        bytes.extend_from_slice(&(self.commitments.len() as u16).to_le_bytes());
+        self.commitments.iter().for_each(|commitments| commitments.write(bytes));
+    }
+}
+
+// tag || identifier (2 bytes, LE) || share
+impl FrostMessage for SignatureShare {
+    fn read(reader: &mut FrostReader) -> Result<Self, SaplingError> {
+        reader.read_tag(SIGNATURE_SHARE_TAG)?;
+
+        Ok(SignatureShare { identifier: reader.read_u16()?, share: reader.read_scalar()? })
+    }
+
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.push(SIGNATURE_SHARE_TAG);
+        bytes.extend_from_slice(&self.identifier.to_le_bytes());
+        write_scalar(bytes, &self.share);
+    }
+}
+
+impl Serializable<Vec<u8>, SaplingError> for SigningCommitments {
+    fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
+        read_message("FROST signing commitments", &serialized)
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
+        Ok(write_message(self))
+    }
+}
+
+impl Serializable<Vec<u8>, SaplingError> for SigningPackage {
+    fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
+        read_message("FROST signing package", &serialized)
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
+        Ok(write_message(self))
+    }
+}
+
+impl Serializable<Vec<u8>, SaplingError> for SignatureShare {
+    fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
+        read_message("FROST signature share", &serialized)
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
+        Ok(write_message(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zcash_primitives::primitives::Nullifier;
+    use zcash_primitives::transaction::components::GROTH_PROOF_SIZE;
+
+    use crate::key::frost::dkg::tests::run_dkg;
+    use crate::key::frost::encoding::read_frost_messages;
+    use crate::key::frost::encoding::tests::write_frost_messages;
+    use crate::transaction::{rand_scalar, sign_spend_description, UnsignedSpendDescription};
+
+    use super::*;
+
+    const SIGHASH: [u8; 32] = [7u8; 32];
+
+    fn sign_with(key_packages: &[&KeyPackage], ar: jubjub::Scalar) -> (SigningPackage, Vec<SignatureShare>) {
+        let nonces: Vec<SigningNonces> = key_packages.iter().map(|key_package| signing_nonces(key_package)).collect();
+        let package = SigningPackage::new(ar, SIGHASH, nonces.iter().map(|nonces| nonces.commitments()).collect()).unwrap();
+
+        let shares = key_packages.iter()
+            .zip(nonces)
+            .map(|(key_package, nonces)| sign(key_package, nonces, &package).unwrap())
+            .collect();
+
+        (package, shares)
+    }
+
+    fn rk(public_key_package: &PublicKeyPackage, ar: jubjub::Scalar) -> PublicKey {
+        PublicKey((public_key_package.ak() + SPENDING_KEY_GENERATOR * ar).into())
+    }
+
+    #[test]
+    fn signs_with_any_threshold_of_signers() {
+        let (key_packages, public_key_package) = run_dkg(2, 3);
+        let ar = rand_scalar();
+
+        for signers in [[0, 1], [0, 2], [2, 1]].iter() {
+            let (package, shares) = sign_with(&[&key_packages[signers[0]], &key_packages[signers[1]]], ar);
+            let signature = aggregate(&package, &shares, &public_key_package).unwrap();
+
+            assert!(verify_spend_auth_sig(&rk(&public_key_package, ar), SIGHASH, &signature));
+            assert!(!verify_spend_auth_sig(&rk(&public_key_package, ar), [8u8; 32], &signature));
+        }
+
+        let (package, shares) = sign_with(&key_packages.iter().collect::<Vec<_>>(), ar);
+        let signature = aggregate(&package, &shares, &public_key_package).unwrap();
+        assert!(verify_spend_auth_sig(&rk(&public_key_package, ar), SIGHASH, &signature));
+    }
+
+    #[test]
+    fn signs_spend_description() {
+        let (key_packages, public_key_package) = run_dkg(2, 3);
+        let ar = rand_scalar();
+        let (package, shares) = sign_with(&[&key_packages[0], &key_packages[2]], ar);
+
+        let unsigned = || UnsignedSpendDescription {
+            cv: jubjub::ExtendedPoint::identity(),
+            anchor: bls12_381::Scalar::zero(),
+            nullifier: Nullifier([0u8; 32]),
+            rk: rk(&public_key_package, ar),
+            zkproof: [0u8; GROTH_PROOF_SIZE],
+        };
+        let signer = FrostSpendAuthSigner::new(&package, &shares, &public_key_package);
+
+        let spend_description = sign_spend_description(unsigned(), &signer, ar, SIGHASH).unwrap();
+        assert!(verify_spend_auth_sig(&spend_description.rk, SIGHASH, &spend_description.spend_auth_sig.unwrap()));
+
+        let actual = sign_spend_description(unsigned(), &signer, rand_scalar(), SIGHASH);
+        assert_eq!(actual.err(), Some(SaplingError::caused_by(FrostError::SigningPackageMismatch)));
+    }
+
+    #[test]
+    fn rejects_invalid_signature_share() {
+        let (key_packages, public_key_package) = run_dkg(2, 3);
+        let (package, mut shares) = sign_with(&[&key_packages[0], &key_packages[1]], rand_scalar());
+
+        shares[1].share += jubjub::Scalar::one();
+        let actual = aggregate(&package, &shares, &public_key_package);
+
+        assert_eq!(actual.err(), Some(SaplingError::caused_by(FrostError::InvalidSignatureShare(2))));
+        assert_eq!(
+            aggregate(&package, &shares[..1], &public_key_package).err(),
+            Some(SaplingError::caused_by(FrostError::UnexpectedPackageCount(2, 1))),
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_signing_package() {
+        let (key_packages, _) = run_dkg(2, 3);
+        let nonces = signing_nonces(&key_packages[0]);
+        let commitments = nonces.commitments();
+
+        let package = SigningPackage::new(rand_scalar(), SIGHASH, vec![commitments.clone()]).unwrap();
+        assert_eq!(sign(&key_packages[0], nonces, &package).err(), Some(SaplingError::caused_by(FrostError::NotEnoughSigners(1, 2))));
+
+        let other_commitments = signing_nonces(&key_packages[1]).commitments();
+        let package = SigningPackage::new(rand_scalar(), SIGHASH, vec![other_commitments, signing_nonces(&key_packages[0]).commitments()]).unwrap();
+        assert_eq!(sign(&key_packages[0], signing_nonces(&key_packages[0]), &package).err(), Some(SaplingError::caused_by(FrostError::NoncesMismatch)));
+        assert_eq!(sign(&key_packages[2], signing_nonces(&key_packages[0]), &package).err(), Some(SaplingError::caused_by(FrostError::NoncesMismatch)));
+
+        let actual = SigningPackage::new(rand_scalar(), SIGHASH, vec![commitments.clone(), commitments.clone()]);
+        assert_eq!(actual.err(), Some(SaplingError::caused_by(FrostError::DuplicateIdentifier(1))));
+
+        let identity = SigningCommitments { identifier: 2, hiding: jubjub::SubgroupPoint::identity(), binding: commitments.binding };
+        let actual = SigningPackage::new(rand_scalar(), SIGHASH, vec![commitments.clone(), identity]);
+        assert_eq!(actual.err(), Some(SaplingError::caused_by(FrostError::IdentityCommitment(2))));
+
+        let mut serialized = SigningPackage::new(rand_scalar(), SIGHASH, vec![commitments]).unwrap().serialize().unwrap();
+        let binding_offset = serialized.len() - 32;
+        serialized[binding_offset..].copy_from_slice(&jubjub::SubgroupPoint::identity().to_bytes());
+        assert_eq!(SigningPackage::deserialize(serialized).err(), Some(SaplingError::caused_by(FrostError::IdentityCommitment(1))));
+    }
+
+    #[test]
+    fn serializes_messages() {
+        let (key_packages, _) = run_dkg(2, 3);
+        let (package, shares) = sign_with(&[&key_packages[2], &key_packages[0]], rand_scalar());
+        let commitments = signing_nonces(&key_packages[1]).commitments();
+
+        assert_eq!(SigningCommitments::deserialize(commitments.serialize().unwrap()).unwrap(), commitments);
+        assert_eq!(SigningPackage::deserialize(package.serialize().unwrap()).unwrap(), package);
+        assert_eq!(read_frost_messages::<SignatureShare>(&write_frost_messages(&shares)).unwrap(), shares);
+
+        assert!(SigningPackage::deserialize(commitments.serialize().unwrap()).is_err());
+        assert!(read_frost_messages::<SignatureShare>(&shares[0].serialize().unwrap()[1..]).is_err());
+    }
+}
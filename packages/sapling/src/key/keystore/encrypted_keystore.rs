@@ -0,0 +1,389 @@
+use std::convert::TryInto;
+
+use crypto_api_chachapoly::ChachaPolyIetf;
+use scrypt::{scrypt, ScryptParams};
+use zcash_primitives::zip32::{ExtendedFullViewingKey, ExtendedSpendingKey};
+
+use crate::common::errors::{CausedBy, DeserializationError, SaplingError};
+use crate::common::traits::Serializable;
+use crate::common::utils::deserialize_utils::{read_all, to_array};
+use crate::common::utils::memory_utils::zeroize;
+use crate::key::SpendingKeyError;
+use crate::key::viewing_key::xfvk_fingerprint;
+use crate::transaction::rand_bytes;
+
+use super::errors::KeystoreError;
+
+const MAGIC: &[u8; 4] = b"SAPK";
+const VERSION: u8 = 1;
+
+const SALT_SIZE: usize = 32;
+const NONCE_SIZE: usize = 12;
+const FINGERPRINT_SIZE: usize = 32;
+const TAG_SIZE: usize = 16;
+const KEY_SIZE: usize = 32;
+
+// magic || version || kind || log_n || r (LE) || p (LE) || salt || nonce || fingerprint
+const HEADER_SIZE: usize = 4 + 1 + 1 + 1 + 4 + 4 + SALT_SIZE + NONCE_SIZE + FINGERPRINT_SIZE;
+
+// scrypt needs 128 * r * 2^log_n bytes of memory, anything above 1 GiB is rejected so a crafted keystore can't exhaust the device.
+// The running time grows with p * r as well, which is capped separately.
+const MAX_LOG_N: u8 = 20;
+const MAX_MEMORY: u64 = 1 << 30;
+const MAX_R_P: u64 = 64;
+
+const MIN_SEED_SIZE: usize = 32;
+const MAX_SEED_SIZE: usize = 252;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KdfParams {
+    pub log_n: u8,
+    pub r: u32,
+    pub p: u32,
+}
+
+impl KdfParams {
+    fn scrypt_params(&self) -> Result<ScryptParams, KeystoreError> {
+        let invalid = || KeystoreError::InvalidKdfParams(self.log_n, self.r, self.p);
+        if self.log_n > MAX_LOG_N {
+            return Err(invalid());
+        }
+
+        let memory = (128 * u64::from(self.r)) << self.log_n;
+        if memory > MAX_MEMORY || u64::from(self.r) * u64::from(self.p) > MAX_R_P {
+            return Err(invalid());
+        }
+
+        ScryptParams::new(self.log_n, self.r, self.p).map_err(|_| invalid())
+    }
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        KdfParams { log_n: 15, r: 8, p: 1 }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KeystoreKind {
+    Xsk,
+    Seed,
+}
+
+impl KeystoreKind {
+    fn to_byte(self) -> u8 {
+        match self {
+            KeystoreKind::Xsk => 0,
+            KeystoreKind::Seed => 1,
+        }
+    }
+
+    fn from_byte(byte: u8) -> Result<Self, KeystoreError> {
+        match byte {
+            0 => Ok(KeystoreKind::Xsk),
+            1 => Ok(KeystoreKind::Seed),
+            _ => Err(KeystoreError::UnknownKind(byte)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum KeystoreContent {
+    Xsk(ExtendedSpendingKey),
+    Seed(Vec<u8>),
+}
+
+impl KeystoreContent {
+    pub fn kind(&self) -> KeystoreKind {
+        match self {
+            KeystoreContent::Xsk(_) => KeystoreKind::Xsk,
+            KeystoreContent::Seed(_) => KeystoreKind::Seed,
+        }
+    }
+
+    // The ZIP-32 fingerprint of the full viewing key, for a seed the one of its master key.
+    pub fn fingerprint(&self) -> [u8; FINGERPRINT_SIZE] {
+        let xsk = match self {
+            KeystoreContent::Xsk(xsk) => xsk.clone(),
+            KeystoreContent::Seed(seed) => ExtendedSpendingKey::master(seed),
+        };
+
+        xfvk_fingerprint(&ExtendedFullViewingKey::from(&xsk))
+    }
+
+    fn to_plaintext(&self) -> Result<Vec<u8>, SaplingError> {
+        match self {
+            KeystoreContent::Xsk(xsk) => xsk.serialize(),
+            KeystoreContent::Seed(seed) => {
+                assert_seed_length(seed)?;
+
+                Ok(seed.clone())
+            }
+        }
+    }
+
+    // Reads from the decrypted buffer directly, so that the caller can zeroize it.
+    fn from_plaintext(kind: KeystoreKind, plaintext: &[u8]) -> Result<Self, SaplingError> {
+        match kind {
+            KeystoreKind::Xsk => {
+                let xsk = read_all("extended spending key", plaintext, |reader| ExtendedSpendingKey::read(reader))
+                    .map_err(|err| SaplingError::caused_by(SpendingKeyError::ReadFailed(err)))?;
+
+                Ok(KeystoreContent::Xsk(xsk))
+            }
+            KeystoreKind::Seed => {
+                assert_seed_length(plaintext)?;
+
+                Ok(KeystoreContent::Seed(plaintext.to_vec()))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncryptedKeystore {
+    pub kind: KeystoreKind,
+    pub kdf_params: KdfParams,
+    pub salt: [u8; SALT_SIZE],
+    pub nonce: [u8; NONCE_SIZE],
+    pub fingerprint: [u8; FINGERPRINT_SIZE],
+    ciphertext: Vec<u8>,
+}
+
+impl EncryptedKeystore {
+    pub fn encrypt(content: &KeystoreContent, password: &[u8]) -> Result<Self, SaplingError> {
+        Self::encrypt_with_params(content, password, KdfParams::default())
+    }
+
+    pub fn encrypt_with_params(content: &KeystoreContent, password: &[u8], kdf_params: KdfParams) -> Result<Self, SaplingError> {
+        let mut plaintext = content.to_plaintext()?;
+
+        let mut keystore = EncryptedKeystore {
+            kind: content.kind(),
+            kdf_params,
+            salt: rand_bytes(SALT_SIZE)[..].try_into().unwrap(),
+            nonce: rand_bytes(NONCE_SIZE)[..].try_into().unwrap(),
+            fingerprint: content.fingerprint(),
+            ciphertext: vec![],
+        };
+
+        let mut key = keystore.derive_key(password)?;
+        let mut ciphertext = vec![0u8; plaintext.len() + TAG_SIZE];
+        let sealed = ChachaPolyIetf::aead_cipher().seal_to(&mut ciphertext, &plaintext, &keystore.header(), &key, &keystore.nonce);
+        zeroize(&mut key);
+        zeroize(&mut plaintext);
+
+        sealed.map_err(|_| SaplingError::caused_by(KeystoreError::EncryptionFailed))?;
+        keystore.ciphertext = ciphertext;
+
+        Ok(keystore)
+    }
+
+    pub fn decrypt(&self, password: &[u8]) -> Result<KeystoreContent, SaplingError> {
+        let mut key = self.derive_key(password)?;
+
+        let mut plaintext = vec![0u8; self.ciphertext.len()];
+        let content = ChachaPolyIetf::aead_cipher()
+            .open_to(&mut plaintext, &self.ciphertext, &self.header(), &key, &self.nonce)
+            .map_err(|_| SaplingError::caused_by(KeystoreError::DecryptionFailed))
+            .and_then(|len| KeystoreContent::from_plaintext(self.kind, &plaintext[..len]));
+        zeroize(&mut key);
+        zeroize(&mut plaintext);
+
+        let content = content?;
+        if content.fingerprint() != self.fingerprint {
+            return Err(SaplingError::caused_by(KeystoreError::FingerprintMismatch));
+        }
+
+        Ok(content)
+    }
+
+    pub fn decrypt_xsk(&self, password: &[u8]) -> Result<ExtendedSpendingKey, SaplingError> {
+        match self.decrypt(password)? {
+            KeystoreContent::Xsk(xsk) => Ok(xsk),
+            _ => Err(SaplingError::caused_by(KeystoreError::KindMismatch)),
+        }
+    }
+
+    pub fn decrypt_seed(&self, password: &[u8]) -> Result<Vec<u8>, SaplingError> {
+        match self.decrypt(password)? {
+            KeystoreContent::Seed(seed) => Ok(seed),
+            _ => Err(SaplingError::caused_by(KeystoreError::KindMismatch)),
+        }
+    }
+
+    fn derive_key(&self, password: &[u8]) -> Result<[u8; KEY_SIZE], SaplingError> {
+        let params = self.kdf_params.scrypt_params().map_err(SaplingError::caused_by)?;
+
+        let mut key = [0u8; KEY_SIZE];
+        scrypt(password, &self.salt, &params, &mut key).map_err(|_| SaplingError::caused_by(KeystoreError::KeyDerivationFailed))?;
+
+        Ok(key)
+    }
+
+    // The whole header is authenticated, so the metadata can't be swapped without invalidating the keystore.
+    fn header(&self) -> Vec<u8> {
+        let mut header = Vec::with_capacity(HEADER_SIZE);
+        header.extend_from_slice(MAGIC);
+        header.push(VERSION);
+        header.push(self.kind.to_byte());
+        header.push(self.kdf_params.log_n);
+        header.extend_from_slice(&self.kdf_params.r.to_le_bytes());
+        header.extend_from_slice(&self.kdf_params.p.to_le_bytes());
+        header.extend_from_slice(&self.salt);
+        header.extend_from_slice(&self.nonce);
+        header.extend_from_slice(&self.fingerprint);
+
+        header
+    }
+}
+
+impl Serializable<Vec<u8>, SaplingError> for EncryptedKeystore {
+    fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> where Self: Sized {
+        if serialized.len() < HEADER_SIZE + TAG_SIZE {
+            return Err(SaplingError::caused_by(DeserializationError::InvalidLength("keystore", HEADER_SIZE + TAG_SIZE, serialized.len())));
+        }
+        if &serialized[0..4] != MAGIC {
+            return Err(SaplingError::caused_by(KeystoreError::InvalidMagic));
+        }
+        if serialized[4] != VERSION {
+            return Err(SaplingError::caused_by(KeystoreError::UnsupportedVersion(serialized[4])));
+        }
+
+        let kind = KeystoreKind::from_byte(serialized[5]).map_err(SaplingError::caused_by)?;
+        let kdf_params = KdfParams {
+            log_n: serialized[6],
+            r: u32::from_le_bytes(to_array("keystore scrypt r", &serialized[7..11]).map_err(SaplingError::caused_by)?),
+            p: u32::from_le_bytes(to_array("keystore scrypt p", &serialized[11..15]).map_err(SaplingError::caused_by)?),
+        };
+        kdf_params.scrypt_params().map_err(SaplingError::caused_by)?;
+
+        let salt_end = 15 + SALT_SIZE;
+        let nonce_end = salt_end + NONCE_SIZE;
+
+        Ok(EncryptedKeystore {
+            kind,
+            kdf_params,
+            salt: to_array("keystore salt", &serialized[15..salt_end]).map_err(SaplingError::caused_by)?,
+            nonce: to_array("keystore nonce", &serialized[salt_end..nonce_end]).map_err(SaplingError::caused_by)?,
+            fingerprint: to_array("keystore fingerprint", &serialized[nonce_end..HEADER_SIZE]).map_err(SaplingError::caused_by)?,
+            ciphertext: serialized[HEADER_SIZE..].to_vec(),
+        })
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
+        let mut bytes = self.header();
+        bytes.extend_from_slice(&self.ciphertext);
+
+        Ok(bytes)
+    }
+}
+
+fn assert_seed_length(seed: &[u8]) -> Result<(), SaplingError> {
+    if seed.len() < MIN_SEED_SIZE || seed.len() > MAX_SEED_SIZE {
+        return Err(SaplingError::caused_by(KeystoreError::InvalidSeedLength(seed.len())));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::key::SaplingKey;
+
+    use super::*;
+
+    const SEED: [u8; 32] = [
+        0,   1,  2,  3,  4,  5,  6,  7,
+        8,   9, 10, 11, 12, 13, 14, 15,
+        16, 17, 18, 19, 20, 21, 22, 23,
+        24, 25, 26, 27, 28, 29, 30, 31
+    ];
+
+    const TEST_PARAMS: KdfParams = KdfParams { log_n: 4, r: 8, p: 1 };
+
+    #[test]
+    fn encrypts_and_decrypts_xsk_and_seed() {
+        let xsk = ExtendedSpendingKey::from_seed(&SEED, "m/32'/1729'/0'").unwrap();
+        let contents = vec![KeystoreContent::Xsk(xsk), KeystoreContent::Seed(SEED.to_vec())];
+
+        for content in contents {
+            let keystore = EncryptedKeystore::encrypt_with_params(&content, b"password", TEST_PARAMS).unwrap();
+            let serialized = keystore.serialize().unwrap();
+            let keystore = EncryptedKeystore::deserialize(serialized).unwrap();
+
+            assert_eq!(keystore.kind, content.kind());
+            assert_eq!(keystore.fingerprint, content.fingerprint());
+            assert_eq!(keystore.decrypt(b"password").unwrap(), content);
+        }
+    }
+
+    #[test]
+    fn computes_zip32_fingerprint() {
+        // the first 4 bytes are the parent_fvk_tag of a child of the master key
+        let child = ExtendedSpendingKey::from_seed(&SEED, "m/1").unwrap().serialize().unwrap();
+        let fingerprint = KeystoreContent::Seed(SEED.to_vec()).fingerprint();
+
+        assert_eq!(&fingerprint[0..4], &child[1..5]);
+    }
+
+    #[test]
+    fn fails_to_decrypt_with_wrong_password_or_modified_keystore() {
+        let content = KeystoreContent::Seed(SEED.to_vec());
+        let keystore = EncryptedKeystore::encrypt_with_params(&content, b"password", TEST_PARAMS).unwrap();
+
+        assert_eq!(keystore.decrypt(b"passw0rd").unwrap_err(), SaplingError::caused_by(KeystoreError::DecryptionFailed));
+        assert_eq!(keystore.decrypt_xsk(b"password").unwrap_err(), SaplingError::caused_by(KeystoreError::KindMismatch));
+
+        let serialized = keystore.serialize().unwrap();
+        for index in [5, 6, 20, HEADER_SIZE - 1, serialized.len() - 1] {
+            let mut modified = serialized.clone();
+            modified[index] ^= 1;
+
+            let decrypted = EncryptedKeystore::deserialize(modified).and_then(|keystore| keystore.decrypt(b"password"));
+            assert!(decrypted.is_err());
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_keystores() {
+        let keystore = EncryptedKeystore::encrypt_with_params(&KeystoreContent::Seed(SEED.to_vec()), b"password", TEST_PARAMS)
+            .unwrap()
+            .serialize()
+            .unwrap();
+
+        let mut magic = keystore.clone();
+        magic[0] = b'X';
+        let mut version = keystore.clone();
+        version[4] = 2;
+        let mut log_n = keystore.clone();
+        log_n[6] = 40;
+        // r = 32 at log_n = 20 would need 4 GiB
+        let mut memory = keystore.clone();
+        memory[6] = 20;
+        memory[7] = 32;
+        let mut r_p = keystore.clone();
+        r_p[11] = 9;
+
+        let test_data = vec![
+            (keystore[..HEADER_SIZE].to_vec(), SaplingError::caused_by(DeserializationError::InvalidLength("keystore", HEADER_SIZE + TAG_SIZE, HEADER_SIZE))),
+            (magic, SaplingError::caused_by(KeystoreError::InvalidMagic)),
+            (version, SaplingError::caused_by(KeystoreError::UnsupportedVersion(2))),
+            (log_n, SaplingError::caused_by(KeystoreError::InvalidKdfParams(40, 8, 1))),
+            (memory, SaplingError::caused_by(KeystoreError::InvalidKdfParams(20, 32, 1))),
+            (r_p, SaplingError::caused_by(KeystoreError::InvalidKdfParams(4, 8, 9))),
+        ];
+
+        for (bytes, expected) in test_data {
+            assert_eq!(EncryptedKeystore::deserialize(bytes).unwrap_err(), expected);
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_seed_length() {
+        let content = KeystoreContent::Seed(vec![0u8; 16]);
+        let actual = EncryptedKeystore::encrypt_with_params(&content, b"password", TEST_PARAMS).unwrap_err();
+
+        assert_eq!(actual, SaplingError::caused_by(KeystoreError::InvalidSeedLength(16)));
+    }
+}
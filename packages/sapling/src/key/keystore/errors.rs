@@ -0,0 +1,34 @@
+use crate::common::errors::DetailedError;
+
+#[derive(Debug, PartialEq)]
+pub enum KeystoreError {
+    InvalidMagic,
+    UnsupportedVersion(u8),
+    UnknownKind(u8),
+    KindMismatch,
+    InvalidKdfParams(u8, u32, u32),
+    InvalidSeedLength(usize),
+    KeyDerivationFailed,
+    EncryptionFailed,
+    DecryptionFailed,
+    FingerprintMismatch,
+}
+
+impl DetailedError for KeystoreError {
+    fn details(&self) -> String {
+        use KeystoreError::*;
+
+        match self {
+            InvalidMagic => String::from("not a sapling keystore"),
+            UnsupportedVersion(version) => format!("unsupported keystore version {}", version),
+            UnknownKind(kind) => format!("unknown keystore content kind {}", kind),
+            KindMismatch => String::from("the keystore holds a different kind of key"),
+            InvalidKdfParams(log_n, r, p) => format!("invalid scrypt parameters log_n={}, r={}, p={}", log_n, r, p),
+            InvalidSeedLength(len) => format!("invalid seed length {}, expected 32 to 252 bytes", len),
+            KeyDerivationFailed => String::from("keystore key derivation failed"),
+            EncryptionFailed => String::from("keystore encryption failed"),
+            DecryptionFailed => String::from("keystore decryption failed, wrong password or corrupted keystore"),
+            FingerprintMismatch => String::from("decrypted key does not match the keystore fingerprint"),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub use encrypted_keystore::{EncryptedKeystore, KeystoreContent};
+
+mod encrypted_keystore;
+
+mod errors;
@@ -1,10 +1,37 @@
+pub use account::{Account, account_path, CoinType, get_accounts, validate_zip32_path};
+pub use bip32::{Bip32Path, split_bip32_path};
+pub use frost::{
+    aggregate as frost_aggregate,
+    dkg_public_key_package,
+    dkg_round1,
+    dkg_round2,
+    dkg_round3,
+    DkgRound1Package,
+    DkgRound1Secret,
+    DkgRound2Package,
+    FrostError,
+    FrostSpendAuthSigner,
+    KeyPackage,
+    PublicKeyPackage,
+    read_frost_messages,
+    sign as frost_sign,
+    SignatureShare,
+    signing_nonces,
+    SigningCommitments,
+    SigningNonces,
+    SigningPackage,
+};
+pub use keystore::{EncryptedKeystore, KeystoreContent};
 pub use sapling_key::SaplingKey;
 pub use spending_key::SpendingKeyError;
-pub use viewing_key::{crh_ivk, ViewingKeyError};
+pub use viewing_key::{crh_ivk, IncomingViewingKey, IVK_HRP, ViewingKeyError, xfvk_fingerprint};
 
+mod account;
+mod keystore;
 mod sapling_key;
 mod spending_key;
 mod authorizing_key;
 mod viewing_key;
 mod bip32;
+mod frost;
 
@@ -2,6 +2,7 @@ use zcash_primitives::zip32::ExtendedSpendingKey;
 
 use crate::common::errors::{CausedBy, SaplingError};
 use crate::common::traits::Serializable;
+use crate::common::utils::deserialize_utils::read_all;
 use crate::key::bip32::split_bip32_path;
 use crate::key::sapling_key::SaplingKey;
 
@@ -19,7 +20,7 @@ impl SaplingKey for ExtendedSpendingKey {
 
 impl Serializable<Vec<u8>, SaplingError> for ExtendedSpendingKey {
     fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> where Self: Sized {
-        ExtendedSpendingKey::read(&serialized[..]).map_err(|err| SaplingError::caused_by(SpendingKeyError::ReadFailed(err)))
+        read_all("extended spending key", &serialized, |reader| ExtendedSpendingKey::read(reader)).map_err(|err| SaplingError::caused_by(SpendingKeyError::ReadFailed(err)))
     }
 
     fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
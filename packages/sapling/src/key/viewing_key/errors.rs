@@ -6,13 +6,17 @@ use crate::common::errors::DetailedError;
 pub enum ViewingKeyError {
     WriteFailed(io::Error),
     ReadFailed(io::Error),
+    InvalidIncomingViewingKey,
+    InvalidIncomingViewingKeyEncoding(String),
 }
 
 impl DetailedError for ViewingKeyError {
     fn details(&self) -> String {
         match self {
             ViewingKeyError::WriteFailed(err) => err.to_string(),
-            ViewingKeyError::ReadFailed(err) => err.to_string()
+            ViewingKeyError::ReadFailed(err) => err.to_string(),
+            ViewingKeyError::InvalidIncomingViewingKey => String::from("invalid incoming viewing key"),
+            ViewingKeyError::InvalidIncomingViewingKeyEncoding(cause) => format!("invalid incoming viewing key encoding, {}", cause),
         }
     }
 }
@@ -22,7 +26,9 @@ impl PartialEq for ViewingKeyError {
         match (self, other) {
             (ViewingKeyError::WriteFailed(err), ViewingKeyError::WriteFailed(other_err)) => err.to_string() == other_err.to_string(),
             (ViewingKeyError::ReadFailed(err), ViewingKeyError::ReadFailed(other_err)) => err.to_string() == other_err.to_string(),
+            (ViewingKeyError::InvalidIncomingViewingKey, ViewingKeyError::InvalidIncomingViewingKey) => true,
+            (ViewingKeyError::InvalidIncomingViewingKeyEncoding(cause), ViewingKeyError::InvalidIncomingViewingKeyEncoding(other_cause)) => cause == other_cause,
             _ => false
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,145 @@
+use bech32::{FromBase32, ToBase32, Variant};
+use zcash_primitives::primitives::{Diversifier, PaymentAddress, SaplingIvk};
+use zcash_primitives::zip32::ExtendedFullViewingKey;
+
+use crate::address::get_ivk_address;
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::traits::Serializable;
+use crate::common::utils::deserialize_utils::to_array;
+
+use super::errors::ViewingKeyError;
+
+// Zcash mainnet Sapling incoming viewing key prefix
+pub const IVK_HRP: &str = "zivks";
+
+// Can detect and decrypt incoming notes only, it can neither recover outgoing notes nor compute nullifiers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IncomingViewingKey(pub jubjub::Scalar);
+
+impl IncomingViewingKey {
+    pub fn to_sapling_ivk(self) -> SaplingIvk {
+        SaplingIvk(self.0)
+    }
+
+    pub fn address(&self, diversifier: Diversifier) -> Result<PaymentAddress, SaplingError> {
+        get_ivk_address(self.0, diversifier)
+    }
+
+    pub fn encode(&self) -> String {
+        bech32::encode(IVK_HRP, self.0.to_bytes().to_base32(), Variant::Bech32).unwrap()
+    }
+
+    pub fn decode(encoded: &str) -> Result<Self, SaplingError> {
+        let invalid_encoding = |cause: String| SaplingError::caused_by(ViewingKeyError::InvalidIncomingViewingKeyEncoding(cause));
+
+        let (hrp, data, variant) = bech32::decode(encoded).map_err(|err| invalid_encoding(err.to_string()))?;
+        if hrp != IVK_HRP || variant != Variant::Bech32 {
+            return Err(invalid_encoding(String::from("invalid prefix")));
+        }
+        let bytes = Vec::<u8>::from_base32(&data).map_err(|err| invalid_encoding(err.to_string()))?;
+
+        IncomingViewingKey::deserialize(bytes)
+    }
+}
+
+impl From<&ExtendedFullViewingKey> for IncomingViewingKey {
+    fn from(xfvk: &ExtendedFullViewingKey) -> Self {
+        IncomingViewingKey(xfvk.fvk.vk.ivk().0)
+    }
+}
+
+impl Serializable<Vec<u8>, SaplingError> for IncomingViewingKey {
+    fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> where Self: Sized {
+        let bytes: [u8; 32] = to_array("incoming viewing key", &serialized).map_err(SaplingError::caused_by)?;
+
+        // ivk is the output of CRH^ivk truncated to 251 bits and is never zero
+        let ivk: Option<jubjub::Scalar> = jubjub::Scalar::from_bytes(&bytes).into();
+        match ivk {
+            Some(ivk) if bytes[31] & 0b1111_1000 == 0 && ivk != jubjub::Scalar::zero() => Ok(IncomingViewingKey(ivk)),
+            _ => Err(SaplingError::caused_by(ViewingKeyError::InvalidIncomingViewingKey)),
+        }
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
+        Ok(self.0.to_bytes().to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::errors::DeserializationError;
+    use crate::key::{crh_ivk, SaplingKey};
+    use crate::transaction::{create_note, decrypt_note_ciphertext, encrypt_note_plaintext, rand_scalar};
+
+    use super::*;
+
+    fn xfvk(seed: u8) -> ExtendedFullViewingKey {
+        ExtendedFullViewingKey::from_seed(&[seed; 32], "m/").unwrap()
+    }
+
+    #[test]
+    fn derives_ivk_from_xfvk() {
+        let xfvk = xfvk(0);
+        let ivk = IncomingViewingKey::from(&xfvk);
+        let (index, address) = xfvk.default_address().unwrap();
+
+        assert_eq!(ivk.serialize().unwrap(), crh_ivk(&xfvk));
+        assert_eq!(ivk.address(*address.diversifier()).unwrap(), address);
+        assert_eq!(xfvk.address(index).unwrap().1, address);
+    }
+
+    #[test]
+    fn encodes_and_decodes_ivk() {
+        let ivk = IncomingViewingKey::from(&xfvk(0));
+        let encoded = ivk.encode();
+
+        assert!(encoded.starts_with("zivks1"));
+        assert_eq!(IncomingViewingKey::decode(&encoded).unwrap(), ivk);
+        assert_eq!(IncomingViewingKey::deserialize(ivk.serialize().unwrap()).unwrap(), ivk);
+
+        let other_hrp = bech32::encode("zivktestsapling", ivk.0.to_bytes().to_base32(), Variant::Bech32).unwrap();
+        assert_eq!(
+            IncomingViewingKey::decode(&other_hrp).unwrap_err(),
+            SaplingError::caused_by(ViewingKeyError::InvalidIncomingViewingKeyEncoding(String::from("invalid prefix")))
+        );
+        let mut invalid_checksum = encoded.clone();
+        let last = if invalid_checksum.pop() == Some('q') { 'p' } else { 'q' };
+        invalid_checksum.push(last);
+        assert!(IncomingViewingKey::decode(&invalid_checksum).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_ivk_bytes() {
+        let mut not_truncated = IncomingViewingKey::from(&xfvk(0)).serialize().unwrap();
+        not_truncated[31] |= 0b0000_1000;
+
+        let test_data = vec![
+            (vec![0u8; 31], SaplingError::caused_by(DeserializationError::InvalidLength("incoming viewing key", 32, 31))),
+            (vec![0u8; 32], SaplingError::caused_by(ViewingKeyError::InvalidIncomingViewingKey)),
+            (vec![0xffu8; 32], SaplingError::caused_by(ViewingKeyError::InvalidIncomingViewingKey)),
+            (not_truncated, SaplingError::caused_by(ViewingKeyError::InvalidIncomingViewingKey)),
+        ];
+
+        for (bytes, expected) in test_data {
+            assert_eq!(IncomingViewingKey::deserialize(bytes).unwrap_err(), expected);
+        }
+    }
+
+    #[test]
+    fn decrypts_incoming_notes_only() {
+        let ivk = IncomingViewingKey::from(&xfvk(0));
+        let (_, address) = xfvk(0).default_address().unwrap();
+        let (_, other_address) = xfvk(1).default_address().unwrap();
+
+        let decrypt = |address: &PaymentAddress| {
+            let note = create_note(address, 100, rand_scalar()).unwrap();
+            let esk = rand_scalar();
+            let ciphertext = encrypt_note_plaintext(&note, address, &esk, None, 8).unwrap();
+
+            decrypt_note_ciphertext(&ivk.to_sapling_ivk(), &(note.g_d * esk).into(), &note.cmu(), &ciphertext, 8)
+        };
+
+        assert_eq!(decrypt(&address).unwrap().note.value, 100);
+        assert!(decrypt(&other_address).is_err());
+    }
+}
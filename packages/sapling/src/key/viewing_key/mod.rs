@@ -1,6 +1,8 @@
 pub use errors::ViewingKeyError;
-pub use xfvk::crh_ivk;
+pub use ivk::{IncomingViewingKey, IVK_HRP};
+pub use xfvk::{crh_ivk, xfvk_fingerprint};
 
+mod ivk;
 mod ovk;
 mod xfvk;
 
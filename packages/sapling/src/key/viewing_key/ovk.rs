@@ -1,16 +1,14 @@
-use std::convert::TryInto;
-
 use zcash_primitives::keys::OutgoingViewingKey;
 
-use crate::common::errors::SaplingError;
+use crate::common::errors::{CausedBy, SaplingError};
 use crate::common::traits::Serializable;
-use crate::common::utils::assert_utils::assert_value_or_error;
+use crate::common::utils::deserialize_utils::to_array;
 
 impl Serializable<Vec<u8>, SaplingError> for OutgoingViewingKey {
     fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> where Self: Sized {
-        assert_value_or_error(serialized.len() == 32, SaplingError::new())?;
+        let ovk = to_array::<32>("outgoing viewing key", &serialized).map_err(SaplingError::caused_by)?;
 
-        Ok(OutgoingViewingKey(serialized[..32].try_into().unwrap()))
+        Ok(OutgoingViewingKey(ovk))
     }
 
     fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
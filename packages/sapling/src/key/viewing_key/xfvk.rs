@@ -1,7 +1,10 @@
-use zcash_primitives::zip32::{ExtendedFullViewingKey, ExtendedSpendingKey};
+use std::convert::TryInto;
+
+use zcash_primitives::zip32::{ExtendedFullViewingKey, ExtendedSpendingKey, ZIP32_SAPLING_FVFP_PERSONALIZATION};
 
 use crate::common::errors::{CausedBy, SaplingError};
 use crate::common::traits::Serializable;
+use crate::common::utils::deserialize_utils::read_all;
 use crate::key::sapling_key::SaplingKey;
 
 use super::errors::ViewingKeyError;
@@ -19,7 +22,7 @@ impl SaplingKey for ExtendedFullViewingKey {
 
 impl Serializable<Vec<u8>, SaplingError> for ExtendedFullViewingKey {
     fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> where Self: Sized {
-        ExtendedFullViewingKey::read(&serialized[..]).map_err(|err| SaplingError::caused_by(ViewingKeyError::ReadFailed(err)))
+        read_all("extended full viewing key", &serialized, |reader| ExtendedFullViewingKey::read(reader)).map_err(|err| SaplingError::caused_by(ViewingKeyError::ReadFailed(err)))
     }
 
     fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
@@ -48,6 +51,18 @@ pub fn crh_ivk(xfvk: &ExtendedFullViewingKey) -> Vec<u8> {
     hash
 }
 
+// The ZIP-32 fingerprint, BLAKE2b-256("ZcashSaplingFVFP", ak || nk || ovk).
+pub fn xfvk_fingerprint(xfvk: &ExtendedFullViewingKey) -> [u8; 32] {
+    let hash = blake2b_simd::Params::new()
+        .hash_length(32)
+        .personal(ZIP32_SAPLING_FVFP_PERSONALIZATION)
+        .to_state()
+        .update(&xfvk.fvk.to_bytes())
+        .finalize();
+
+    hash.as_bytes().try_into().unwrap()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::key::bip32::{Bip32IndexError, Bip32PathError};
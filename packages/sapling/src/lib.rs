@@ -3,38 +3,67 @@
 #[cfg(feature = "c_bindings")]
 #[macro_use] extern crate log;
 
+#[cfg(all(feature = "parallel_proving", target_arch = "wasm32"))]
+compile_error!("feature \"parallel_proving\" is not supported on wasm32");
+
 #[cfg(feature = "c_bindings")]
 pub use c_bindings::{
+    account::*,
     authorizing_key::*,
     commitment::*,
+    frontier::*,
+    frost::*,
     init::*,
     key_agreement::*,
+    keystore::*,
+    memo::*,
     merkle_tree::*,
+    message_encryption::*,
     output_description::*,
     payment_address::*,
+    payment_disclosure::*,
+    payment_uri::*,
+    proving_builder::*,
     proving_context::*,
     rand::*,
+    sapling_state::*,
+    sapling_transaction::*,
     signature::*,
     spend_description::*,
     spending_key::*,
+    value_commitment::*,
     viewing_key::*,
 };
 #[cfg(feature = "wasm_bindings")]
 pub use wasm_bindings::{
+    account::*,
     authorizing_key::*,
     commitment::*,
+    frontier::*,
+    frost::*,
     init::*,
     key_agreement::*,
+    keystore::*,
+    memo::*,
     merkle_tree::*,
+    message_encryption::*,
     output_description::*,
     payment_address::*,
+    payment_disclosure::*,
+    payment_uri::*,
+    proving_builder::*,
     proving_context::*,
     rand::*,
+    sapling_state::*,
+    sapling_transaction::*,
     signature::*,
     spend_description::*,
     spending_key::*,
+    value_commitment::*,
     viewing_key::*,
 };
+#[cfg(feature = "wasm_bindings")]
+pub use wasm_bindings::json::*;
 
 use crate::state::State;
 
@@ -44,8 +73,16 @@ mod c_bindings;
 #[cfg(feature = "wasm_bindings")]
 mod wasm_bindings;
 
+#[cfg(feature = "cli")]
+pub mod cli;
+
+#[cfg(any(test, feature = "fuzzing"))]
+pub mod fuzz;
+
 mod address;
 mod common;
+#[cfg(feature = "serde")]
+mod json;
 mod key;
 mod transaction;
 
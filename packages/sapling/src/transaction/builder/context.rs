@@ -0,0 +1,224 @@
+use std::ops::Neg;
+
+use bellman::gadgets::multipack;
+use bellman::groth16::{create_random_proof, Parameters, PreparedVerifyingKey, Proof, verify_proof};
+use bls12_381::Bls12;
+use ff::Field;
+use group::{Curve, GroupEncoding};
+use rand_core::OsRng;
+use zcash_primitives::constants::{SPENDING_KEY_GENERATOR, VALUE_COMMITMENT_RANDOMNESS_GENERATOR};
+use zcash_primitives::merkle_tree::MerklePath;
+use zcash_primitives::primitives::{Diversifier, Note, PaymentAddress, ProofGenerationKey, Rseed, ValueCommitment};
+use zcash_primitives::redjubjub::{PrivateKey, PublicKey, Signature};
+use zcash_primitives::sapling::Node;
+use zcash_primitives::transaction::components::Amount;
+use zcash_proofs::circuit::sapling::{Output, Spend};
+
+use crate::transaction::proof::ProvingContext;
+use crate::transaction::value_commitment::compute_value_balance;
+
+// Based on [`SaplingProvingContext`](https://github.com/zcash/librustzcash/blob/master/zcash_proofs/src/sapling/prover.rs#L21),
+// but the accumulated `bsk` and `cv_sum` can be merged with other contexts,
+// so every description can be proved with its own context on a separate thread.
+#[derive(Clone)]
+pub struct MergeableProvingContext {
+    bsk: jubjub::Scalar,
+    cv_sum: jubjub::ExtendedPoint,
+}
+
+impl MergeableProvingContext {
+    pub fn new() -> MergeableProvingContext {
+        MergeableProvingContext {
+            bsk: jubjub::Scalar::zero(),
+            cv_sum: jubjub::ExtendedPoint::identity(),
+        }
+    }
+
+    pub fn merge(&mut self, other: &MergeableProvingContext) {
+        self.bsk += other.bsk;
+        self.cv_sum += other.cv_sum;
+    }
+}
+
+impl Default for MergeableProvingContext {
+    fn default() -> Self {
+        MergeableProvingContext::new()
+    }
+}
+
+impl ProvingContext for MergeableProvingContext {
+    fn spend_proof(
+        &mut self,
+        proof_generation_key: ProofGenerationKey,
+        diversifier: Diversifier,
+        rseed: Rseed,
+        ar: jubjub::Scalar,
+        value: u64,
+        anchor: bls12_381::Scalar,
+        merkle_path: MerklePath<Node>,
+        proving_key: &Parameters<Bls12>,
+        verifying_key: &PreparedVerifyingKey<Bls12>,
+    ) -> Result<(Proof<Bls12>, jubjub::ExtendedPoint, PublicKey), ()> {
+        let mut rng = OsRng;
+
+        let rcv = jubjub::Scalar::random(&mut rng);
+        let value_commitment = ValueCommitment { value, randomness: rcv };
+
+        let viewing_key = proof_generation_key.to_viewing_key();
+        let payment_address = viewing_key.to_payment_address(diversifier).ok_or(())?;
+
+        let rk = PublicKey(proof_generation_key.ak.into()).randomize(ar, SPENDING_KEY_GENERATOR);
+
+        let note = Note {
+            value,
+            g_d: diversifier.g_d().ok_or(())?,
+            pk_d: *payment_address.pk_d(),
+            rseed,
+        };
+        let nullifier = note.nf(&viewing_key, merkle_path.position);
+
+        let instance = Spend {
+            value_commitment: Some(value_commitment.clone()),
+            proof_generation_key: Some(proof_generation_key),
+            payment_address: Some(payment_address),
+            commitment_randomness: Some(note.rcm()),
+            ar: Some(ar),
+            auth_path: merkle_path.auth_path.iter().map(|(node, b)| Some(((*node).into(), *b))).collect(),
+            anchor: Some(anchor),
+        };
+
+        let proof = create_random_proof(instance, proving_key, &mut rng).map_err(|_| ())?;
+
+        let mut public_input = [bls12_381::Scalar::zero(); 7];
+        {
+            let affine = rk.0.to_affine();
+            public_input[0] = affine.get_u();
+            public_input[1] = affine.get_v();
+        }
+        {
+            let affine = jubjub::ExtendedPoint::from(value_commitment.commitment()).to_affine();
+            public_input[2] = affine.get_u();
+            public_input[3] = affine.get_v();
+        }
+        public_input[4] = anchor;
+        {
+            let nullifier = multipack::bytes_to_bits_le(&nullifier.0);
+            let nullifier = multipack::compute_multipacking(&nullifier);
+            public_input[5] = nullifier[0];
+            public_input[6] = nullifier[1];
+        }
+
+        verify_proof(verifying_key, &proof, &public_input[..]).map_err(|_| ())?;
+
+        let cv: jubjub::ExtendedPoint = value_commitment.commitment().into();
+
+        self.bsk += rcv;
+        self.cv_sum += cv;
+
+        Ok((proof, cv, rk))
+    }
+
+    fn output_proof(
+        &mut self,
+        esk: jubjub::Scalar,
+        payment_address: PaymentAddress,
+        rcm: jubjub::Scalar,
+        value: u64,
+        proving_key: &Parameters<Bls12>,
+    ) -> (Proof<Bls12>, jubjub::ExtendedPoint) {
+        let mut rng = OsRng;
+
+        let rcv = jubjub::Scalar::random(&mut rng);
+        let value_commitment = ValueCommitment { value, randomness: rcv };
+
+        let instance = Output {
+            value_commitment: Some(value_commitment.clone()),
+            payment_address: Some(payment_address),
+            commitment_randomness: Some(rcm),
+            esk: Some(esk),
+        };
+
+        let proof = create_random_proof(instance, proving_key, &mut rng).expect("proving should not fail");
+
+        let cv: jubjub::ExtendedPoint = value_commitment.commitment().into();
+
+        // Outputs subtract from the total.
+        self.bsk += rcv.neg();
+        self.cv_sum -= cv;
+
+        (proof, cv)
+    }
+
+    fn binding_sig(&self, value_balance: Amount, sighash: &[u8; 32]) -> Result<Signature, ()> {
+        let mut rng = OsRng;
+
+        let bsk = PrivateKey(self.bsk);
+        let bvk = PublicKey::from_private(&bsk, VALUE_COMMITMENT_RANDOMNESS_GENERATOR);
+
+        let value_balance = compute_value_balance(value_balance).ok_or(())?;
+        if bvk.0 != self.cv_sum - value_balance {
+            return Err(());
+        }
+
+        let mut data_to_be_signed = [0u8; 64];
+        data_to_be_signed[0..32].copy_from_slice(&bvk.0.to_bytes());
+        data_to_be_signed[32..64].copy_from_slice(&sighash[..]);
+
+        Ok(bsk.sign(&data_to_be_signed, &mut rng, VALUE_COMMITMENT_RANDOMNESS_GENERATOR))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zcash_primitives::constants::VALUE_COMMITMENT_VALUE_GENERATOR;
+
+    use crate::transaction::rand_scalar;
+
+    use super::*;
+
+    #[test]
+    fn merges_contexts_into_valid_binding_sig_context() {
+        let sighash = [1u8; 32];
+        let value = 100u64;
+
+        let spend_rcv = rand_scalar();
+        let spend_ctx = MergeableProvingContext {
+            bsk: spend_rcv,
+            cv_sum: (VALUE_COMMITMENT_VALUE_GENERATOR * jubjub::Scalar::from(value)
+                + VALUE_COMMITMENT_RANDOMNESS_GENERATOR * spend_rcv).into(),
+        };
+
+        let output_rcv = rand_scalar();
+        let output_ctx = MergeableProvingContext {
+            bsk: output_rcv.neg(),
+            cv_sum: (-(VALUE_COMMITMENT_VALUE_GENERATOR * jubjub::Scalar::from(value)
+                + VALUE_COMMITMENT_RANDOMNESS_GENERATOR * output_rcv)).into(),
+        };
+
+        assert!(spend_ctx.binding_sig(Amount::zero(), &sighash).is_err());
+        assert!(output_ctx.binding_sig(Amount::zero(), &sighash).is_err());
+
+        let mut ctx = MergeableProvingContext::new();
+        ctx.merge(&spend_ctx);
+        ctx.merge(&output_ctx);
+
+        let binding_sig = ctx.binding_sig(Amount::zero(), &sighash);
+        assert!(binding_sig.is_ok());
+
+        let bvk = PublicKey::from_private(&PrivateKey(ctx.bsk), VALUE_COMMITMENT_RANDOMNESS_GENERATOR);
+        let mut data_to_be_signed = [0u8; 64];
+        data_to_be_signed[0..32].copy_from_slice(&bvk.0.to_bytes());
+        data_to_be_signed[32..64].copy_from_slice(&sighash[..]);
+
+        assert!(bvk.verify(&data_to_be_signed, &binding_sig.unwrap(), VALUE_COMMITMENT_RANDOMNESS_GENERATOR));
+    }
+
+    #[test]
+    fn fails_to_create_binding_sig_with_unbalanced_value() {
+        let mut ctx = MergeableProvingContext::new();
+        ctx.merge(&MergeableProvingContext::default());
+
+        assert!(ctx.binding_sig(Amount::zero(), &[0u8; 32]).is_ok());
+        assert!(ctx.binding_sig(Amount::from_i64(1).unwrap(), &[0u8; 32]).is_err());
+    }
+}
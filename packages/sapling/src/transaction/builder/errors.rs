@@ -0,0 +1,16 @@
+use crate::common::errors::DetailedError;
+
+#[derive(Debug, PartialEq)]
+pub enum ProvingBuilderError {
+    Cancelled,
+}
+
+impl DetailedError for ProvingBuilderError {
+    fn details(&self) -> String {
+        use ProvingBuilderError::*;
+
+        match self {
+            Cancelled => String::from("Proving has been cancelled"),
+        }
+    }
+}
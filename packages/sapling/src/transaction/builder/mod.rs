@@ -0,0 +1,10 @@
+pub use progress::CancellationToken;
+pub use proving::{OutputInput, ProvedDescriptions, ProvingBuilder, SpendInput};
+
+mod context;
+mod progress;
+mod proving;
+#[cfg(feature = "parallel_proving")]
+mod parallel;
+
+mod errors;
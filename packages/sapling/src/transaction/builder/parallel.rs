@@ -0,0 +1,120 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rayon::prelude::*;
+use zcash_primitives::transaction::components::OutputDescription;
+use zcash_proofs::ZcashParameters;
+
+use crate::common::errors::SaplingError;
+use crate::transaction::builder::context::MergeableProvingContext;
+use crate::transaction::builder::progress::CancellationToken;
+use crate::transaction::builder::proving::{OutputInput, ProvedDescriptions, ProvingBuilder, prove_output, prove_spend, SpendInput};
+use crate::transaction::spend::UnsignedSpendDescription;
+
+impl ProvingBuilder {
+    // Every description is proved with its own context on the rayon thread pool, the contexts are merged afterwards,
+    // so `ctx` of the result can be used to create the binding signature.
+    // `on_progress` may be called from any of the worker threads.
+    pub fn prove_parallel<F>(
+        &self,
+        params: &ZcashParameters,
+        on_progress: F,
+        cancellation: &CancellationToken
+    ) -> Result<ProvedDescriptions, SaplingError> where F: Fn(usize, usize) + Sync {
+        self.prove_parallel_with(
+            |ctx, spend| prove_spend(ctx, spend, params),
+            |ctx, output| prove_output(ctx, output, params),
+            on_progress,
+            cancellation,
+        )
+    }
+
+    fn prove_parallel_with<S, O, F>(
+        &self,
+        spend_prover: S,
+        output_prover: O,
+        on_progress: F,
+        cancellation: &CancellationToken
+    ) -> Result<ProvedDescriptions, SaplingError>
+        where S: Fn(&mut MergeableProvingContext, &SpendInput) -> Result<UnsignedSpendDescription, SaplingError> + Sync,
+              O: Fn(&mut MergeableProvingContext, &OutputInput) -> Result<OutputDescription, SaplingError> + Sync,
+              F: Fn(usize, usize) + Sync {
+
+        let total = self.total();
+        let completed = AtomicUsize::new(0);
+        let report_progress = || on_progress(completed.fetch_add(1, Ordering::SeqCst) + 1, total);
+
+        let (spends, outputs) = rayon::join(
+            || self.spends.par_iter()
+                .map(|spend| {
+                    cancellation.assert_not_cancelled()?;
+
+                    let mut ctx = MergeableProvingContext::new();
+                    let description = spend_prover(&mut ctx, spend)?;
+                    report_progress();
+
+                    Ok((description, ctx))
+                })
+                .collect::<Result<Vec<(UnsignedSpendDescription, MergeableProvingContext)>, SaplingError>>(),
+            || self.outputs.par_iter()
+                .map(|output| {
+                    cancellation.assert_not_cancelled()?;
+
+                    let mut ctx = MergeableProvingContext::new();
+                    let description = output_prover(&mut ctx, output)?;
+                    report_progress();
+
+                    Ok((description, ctx))
+                })
+                .collect::<Result<Vec<(OutputDescription, MergeableProvingContext)>, SaplingError>>(),
+        );
+
+        let mut ctx = MergeableProvingContext::new();
+
+        let spend_descriptions = spends?.into_iter()
+            .map(|(description, spend_ctx)| {
+                ctx.merge(&spend_ctx);
+                description
+            })
+            .collect();
+
+        let output_descriptions = outputs?.into_iter()
+            .map(|(description, output_ctx)| {
+                ctx.merge(&output_ctx);
+                description
+            })
+            .collect();
+
+        Ok(ProvedDescriptions { spend_descriptions, output_descriptions, ctx })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::transaction::builder::proving::tests::{output_input, spend_input, unproven_output, unproven_spend};
+
+    use super::*;
+
+    #[test]
+    fn reports_progress_once_per_description() {
+        let mut builder = ProvingBuilder::new();
+        builder.add_spend(spend_input());
+        builder.add_spend(spend_input());
+        builder.add_output(output_input());
+        builder.add_output(output_input());
+
+        let progress = std::sync::Mutex::new(vec![]);
+        let proved = builder.prove_parallel_with(
+            unproven_spend,
+            unproven_output,
+            |completed, total| progress.lock().unwrap().push((completed, total)),
+            &CancellationToken::new(),
+        ).unwrap();
+
+        let mut progress = progress.into_inner().unwrap();
+        progress.sort_unstable();
+
+        assert_eq!(proved.spend_descriptions.len(), 2);
+        assert_eq!(proved.output_descriptions.len(), 2);
+        assert_eq!(progress, vec![(1, 4), (2, 4), (3, 4), (4, 4)]);
+    }
+}
@@ -0,0 +1,49 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::utils::assert_utils::assert_value_or_error;
+use crate::transaction::builder::errors::ProvingBuilderError;
+
+// Can be shared with another thread to abort proving, it's checked before each proof is created.
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    pub(super) fn assert_not_cancelled(&self) -> Result<(), SaplingError> {
+        assert_value_or_error(!self.is_cancelled(), ProvingBuilderError::Cancelled).map_err(SaplingError::caused_by)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shares_cancellation_between_clones() {
+        let token = CancellationToken::new();
+        let cloned = token.clone();
+
+        assert!(!token.is_cancelled());
+        assert!(token.assert_not_cancelled().is_ok());
+
+        cloned.cancel();
+
+        assert!(token.is_cancelled());
+        assert_eq!(token.assert_not_cancelled().unwrap_err(), SaplingError::caused_by(ProvingBuilderError::Cancelled));
+    }
+}
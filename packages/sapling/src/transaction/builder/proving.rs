@@ -0,0 +1,292 @@
+use zcash_primitives::keys::OutgoingViewingKey;
+use zcash_primitives::merkle_tree::MerklePath;
+use zcash_primitives::primitives::{PaymentAddress, ProofGenerationKey};
+use zcash_primitives::sapling::Node;
+use zcash_primitives::transaction::components::OutputDescription;
+use zcash_proofs::ZcashParameters;
+
+use crate::common::errors::SaplingError;
+use crate::transaction::builder::context::MergeableProvingContext;
+use crate::transaction::builder::progress::CancellationToken;
+use crate::transaction::output::{OutputDetails, prepare_output_description, prepare_output_description_with_esk};
+use crate::transaction::proof::ProvingContext;
+use crate::transaction::spend::{prepare_spend_description, SpendDetails, SpendParameters, UnsignedSpendDescription};
+
+#[derive(Clone)]
+pub struct SpendInput {
+    pub pak: ProofGenerationKey,
+    pub address: PaymentAddress,
+    pub value: u64,
+    pub rcm: jubjub::Scalar,
+    pub ar: jubjub::Scalar,
+    pub anchor: bls12_381::Scalar,
+    pub merkle_path: MerklePath<Node>,
+}
+
+#[derive(Clone)]
+pub struct OutputInput {
+    pub ovk: OutgoingViewingKey,
+    pub address: PaymentAddress,
+    pub value: u64,
+    pub rcm: jubjub::Scalar,
+    // A random `esk` is used if none is given.
+    pub esk: Option<jubjub::Scalar>,
+    pub memo: Option<Vec<u8>>,
+}
+
+pub struct ProvedDescriptions {
+    pub spend_descriptions: Vec<UnsignedSpendDescription>,
+    pub output_descriptions: Vec<OutputDescription>,
+    pub ctx: MergeableProvingContext,
+}
+
+#[derive(Default)]
+pub struct ProvingBuilder {
+    pub(super) spends: Vec<SpendInput>,
+    pub(super) outputs: Vec<OutputInput>,
+}
+
+impl ProvingBuilder {
+    pub fn new() -> ProvingBuilder {
+        ProvingBuilder::default()
+    }
+
+    pub fn add_spend(&mut self, spend: SpendInput) {
+        self.spends.push(spend);
+    }
+
+    pub fn add_output(&mut self, output: OutputInput) {
+        self.outputs.push(output);
+    }
+
+    pub fn total(&self) -> usize {
+        self.spends.len() + self.outputs.len()
+    }
+
+    // `on_progress` is called with the number of completed and total descriptions after each proof,
+    // `cancellation` is checked before each proof. The inputs are kept, so proving can be retried.
+    pub fn prove<F>(
+        &self,
+        params: &ZcashParameters,
+        on_progress: F,
+        cancellation: &CancellationToken
+    ) -> Result<ProvedDescriptions, SaplingError> where F: FnMut(usize, usize) {
+        self.prove_with(
+            |ctx, spend| prove_spend(ctx, spend, params),
+            |ctx, output| prove_output(ctx, output, params),
+            on_progress,
+            cancellation,
+        )
+    }
+
+    fn prove_with<S, O, F>(
+        &self,
+        mut spend_prover: S,
+        mut output_prover: O,
+        mut on_progress: F,
+        cancellation: &CancellationToken
+    ) -> Result<ProvedDescriptions, SaplingError>
+        where S: FnMut(&mut MergeableProvingContext, &SpendInput) -> Result<UnsignedSpendDescription, SaplingError>,
+              O: FnMut(&mut MergeableProvingContext, &OutputInput) -> Result<OutputDescription, SaplingError>,
+              F: FnMut(usize, usize) {
+
+        let total = self.total();
+        let mut completed = 0;
+        let mut ctx = MergeableProvingContext::new();
+
+        let mut spend_descriptions = Vec::with_capacity(self.spends.len());
+        for spend in self.spends.iter() {
+            cancellation.assert_not_cancelled()?;
+            spend_descriptions.push(spend_prover(&mut ctx, spend)?);
+
+            completed += 1;
+            on_progress(completed, total);
+        }
+
+        let mut output_descriptions = Vec::with_capacity(self.outputs.len());
+        for output in self.outputs.iter() {
+            cancellation.assert_not_cancelled()?;
+            output_descriptions.push(output_prover(&mut ctx, output)?);
+
+            completed += 1;
+            on_progress(completed, total);
+        }
+
+        Ok(ProvedDescriptions { spend_descriptions, output_descriptions, ctx })
+    }
+}
+
+pub(super) fn prove_spend(
+    ctx: &mut MergeableProvingContext,
+    spend: &SpendInput,
+    params: &ZcashParameters
+) -> Result<UnsignedSpendDescription, SaplingError> {
+    prepare_spend_description(
+        ctx,
+        SpendDetails { from_pak: &spend.pak, to_address: &spend.address, value: spend.value },
+        spend.rcm,
+        spend.ar,
+        spend.anchor,
+        spend.merkle_path.clone(),
+        SpendParameters { proving_key: &params.spend_params, verifying_key: &params.spend_vk },
+    )
+}
+
+pub(super) fn prove_output(
+    ctx: &mut impl ProvingContext,
+    output: &OutputInput,
+    params: &ZcashParameters
+) -> Result<OutputDescription, SaplingError> {
+    let output_details = OutputDetails { to_address: output.address.clone(), value: output.value };
+    match output.esk {
+        Some(esk) => prepare_output_description_with_esk(ctx, output.ovk, output_details, output.rcm, esk, output.memo.as_deref(), &params.output_params),
+        None => prepare_output_description(ctx, output.ovk, output_details, output.rcm, output.memo.as_deref(), &params.output_params),
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use group::Group;
+    use zcash_primitives::primitives::{Diversifier, Nullifier};
+    use zcash_primitives::redjubjub::PublicKey;
+    use zcash_primitives::transaction::components::GROTH_PROOF_SIZE;
+
+    use crate::common::errors::CausedBy;
+    use crate::transaction::builder::errors::ProvingBuilderError;
+    use crate::transaction::output::derive_epk;
+    use crate::transaction::proof::tests::{dummy_params, UnprovenContext};
+
+    use super::*;
+
+    pub(crate) fn output_input() -> OutputInput {
+        let g_d = jubjub::SubgroupPoint::generator();
+
+        OutputInput {
+            ovk: OutgoingViewingKey([0u8; 32]),
+            address: PaymentAddress::from_parts(Diversifier([0u8; 11]), g_d).unwrap(),
+            value: 1,
+            rcm: jubjub::Scalar::one(),
+            esk: None,
+            memo: None,
+        }
+    }
+
+    pub(crate) fn spend_input() -> SpendInput {
+        let g_d = jubjub::SubgroupPoint::generator();
+
+        SpendInput {
+            pak: ProofGenerationKey { ak: g_d, nsk: jubjub::Scalar::one() },
+            address: PaymentAddress::from_parts(Diversifier([0u8; 11]), g_d).unwrap(),
+            value: 1,
+            rcm: jubjub::Scalar::one(),
+            ar: jubjub::Scalar::one(),
+            anchor: bls12_381::Scalar::zero(),
+            merkle_path: MerklePath { auth_path: vec![], position: 0 },
+        }
+    }
+
+    // Stands in for a proof, there are no real parameters in the tests.
+    pub(crate) fn unproven_spend(_ctx: &mut MergeableProvingContext, _spend: &SpendInput) -> Result<UnsignedSpendDescription, SaplingError> {
+        Ok(UnsignedSpendDescription {
+            cv: jubjub::ExtendedPoint::identity(),
+            anchor: bls12_381::Scalar::zero(),
+            nullifier: Nullifier([0u8; 32]),
+            rk: PublicKey(jubjub::ExtendedPoint::identity()),
+            zkproof: [0u8; GROTH_PROOF_SIZE],
+        })
+    }
+
+    pub(crate) fn unproven_output(_ctx: &mut MergeableProvingContext, _output: &OutputInput) -> Result<OutputDescription, SaplingError> {
+        Ok(OutputDescription {
+            cv: jubjub::ExtendedPoint::identity(),
+            cmu: bls12_381::Scalar::zero(),
+            ephemeral_key: jubjub::ExtendedPoint::identity(),
+            enc_ciphertext: [0u8; 580],
+            out_ciphertext: [0u8; 80],
+            zkproof: [0u8; GROTH_PROOF_SIZE],
+        })
+    }
+
+    #[test]
+    fn reports_progress_once_per_description() {
+        let mut builder = ProvingBuilder::new();
+        builder.add_spend(spend_input());
+        builder.add_output(output_input());
+        builder.add_output(output_input());
+
+        let mut progress = vec![];
+        let proved = builder.prove_with(unproven_spend, unproven_output, |completed, total| progress.push((completed, total)), &CancellationToken::new()).unwrap();
+
+        assert_eq!(proved.spend_descriptions.len(), 1);
+        assert_eq!(proved.output_descriptions.len(), 2);
+        assert_eq!(progress, vec![(1, 3), (2, 3), (3, 3)]);
+    }
+
+    #[test]
+    fn stops_proving_when_cancelled_during_progress() {
+        let mut builder = ProvingBuilder::new();
+        builder.add_spend(spend_input());
+        builder.add_output(output_input());
+
+        let cancellation = CancellationToken::new();
+
+        let mut progress = vec![];
+        let error = builder.prove_with(
+            unproven_spend,
+            unproven_output,
+            |completed, total| {
+                progress.push((completed, total));
+                cancellation.cancel();
+            },
+            &cancellation,
+        ).err().unwrap();
+
+        assert_eq!(error, SaplingError::caused_by(ProvingBuilderError::Cancelled));
+        assert_eq!(progress, vec![(1, 2)]);
+    }
+
+    #[test]
+    fn proves_output_with_given_esk() {
+        let esk = jubjub::Scalar::from(7);
+        let output = OutputInput { esk: Some(esk), ..output_input() };
+
+        let first = prove_output(&mut UnprovenContext, &output, &dummy_params()).unwrap();
+        let second = prove_output(&mut UnprovenContext, &output, &dummy_params()).unwrap();
+
+        let epk: jubjub::ExtendedPoint = derive_epk(*output.address.diversifier(), esk).unwrap().into();
+        assert_eq!(first.ephemeral_key, epk);
+        assert_eq!(first.enc_ciphertext[..], second.enc_ciphertext[..]);
+        assert_eq!(first.out_ciphertext[..], second.out_ciphertext[..]);
+
+        let random = prove_output(&mut UnprovenContext, &output_input(), &dummy_params()).unwrap();
+        assert_ne!(random.ephemeral_key, epk);
+    }
+
+    #[test]
+    fn does_not_prove_if_cancelled() {
+        let mut builder = ProvingBuilder::new();
+        builder.add_output(output_input());
+
+        let cancellation = CancellationToken::new();
+        cancellation.cancel();
+
+        let mut progress = vec![];
+        let error = builder.prove(&dummy_params(), |completed, total| progress.push((completed, total)), &cancellation).err().unwrap();
+
+        assert_eq!(error, SaplingError::caused_by(ProvingBuilderError::Cancelled));
+        assert!(progress.is_empty());
+        assert_eq!(builder.total(), 1);
+    }
+
+    #[test]
+    fn reports_no_progress_for_empty_builder() {
+        let builder = ProvingBuilder::new();
+
+        let mut progress = vec![];
+        let proved = builder.prove(&dummy_params(), |completed, total| progress.push((completed, total)), &CancellationToken::new()).unwrap();
+
+        assert!(proved.spend_descriptions.is_empty());
+        assert!(proved.output_descriptions.is_empty());
+        assert!(progress.is_empty());
+    }
+}
@@ -83,4 +83,173 @@ impl PartialEq for ProofError {
     fn eq(&self, other: &Self) -> bool {
         self.details() == other.details()
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MemoError {
+    TooLong(usize, usize),
+    InvalidText(String),
+    InvalidFutureMemo,
+}
+
+impl DetailedError for MemoError {
+    fn details(&self) -> String {
+        use MemoError::*;
+
+        match self {
+            TooLong(len, memo_size) => format!("Memo is too long, expected at most {} bytes, got {}", memo_size, len),
+            InvalidText(cause) => format!("Invalid text memo: {}", cause),
+            InvalidFutureMemo => String::from("Memo does not start with a byte reserved for future use"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum NoteEncryptionError {
+    EncryptionFailed,
+    DecryptionFailed,
+    InvalidCiphertextLength(usize, usize),
+    InvalidPlaintext,
+    CommitmentMismatch,
+}
+
+impl DetailedError for NoteEncryptionError {
+    fn details(&self) -> String {
+        use NoteEncryptionError::*;
+
+        match self {
+            EncryptionFailed => String::from("Could not encrypt the note plaintext"),
+            DecryptionFailed => String::from("Could not decrypt the note ciphertext"),
+            InvalidCiphertextLength(expected, actual) => format!("Invalid ciphertext length, expected {}, got {}", expected, actual),
+            InvalidPlaintext => String::from("Decrypted note plaintext is invalid"),
+            CommitmentMismatch => String::from("Decrypted note does not match the note commitment"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FrontierError {
+    InvalidSize(u64),
+    InvalidOmmerCount(usize, usize),
+    InvalidNode,
+    NonEmptyRightSibling(usize),
+    TreeFull,
+    SizeMismatch(u64, u64),
+    RootMismatch,
+}
+
+impl DetailedError for FrontierError {
+    fn details(&self) -> String {
+        use FrontierError::*;
+
+        match self {
+            InvalidSize(size) => format!("Invalid commitment tree size {}", size),
+            InvalidOmmerCount(expected, actual) => format!("Invalid number of frontier nodes, expected {}, got {}", expected, actual),
+            InvalidNode => String::from("Commitment tree node is not a valid field element"),
+            NonEmptyRightSibling(level) => format!("The right-most path has a non-empty right sibling at level {}", level),
+            TreeFull => String::from("The commitment tree is full"),
+            SizeMismatch(expected, actual) => format!("Commitment tree size mismatch, expected {}, got {}", expected, actual),
+            RootMismatch => String::from("The computed commitment tree root does not match the expected root"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SaplingStateError {
+    InvalidRootsCount(usize),
+    RootMismatch,
+    TooManyInputs(usize),
+    TooManyOutputs(usize),
+    InvalidAntiReplayLength(usize),
+    MemoSizeMismatch(usize, u16),
+    NullifierAlreadySpent(usize),
+    DuplicateNullifier(usize),
+    UnknownAnchor,
+    InvalidSpendAuthSig(usize),
+    InvalidSpend(usize),
+    InvalidOutput(usize),
+    BalanceOutOfRange(i64),
+    InvalidBindingSig,
+}
+
+impl DetailedError for SaplingStateError {
+    fn details(&self) -> String {
+        use SaplingStateError::*;
+
+        match self {
+            InvalidRootsCount(count) => format!("Invalid number of sapling state roots {}", count),
+            RootMismatch => String::from("The last sapling state root does not match the commitment tree"),
+            TooManyInputs(count) => format!("Too many spend descriptions, got {}", count),
+            TooManyOutputs(count) => format!("Too many output descriptions, got {}", count),
+            InvalidAntiReplayLength(len) => format!("Anti-replay string of {} bytes is longer than the maximum BLAKE2b key of 64 bytes", len),
+            MemoSizeMismatch(index, memo_size) => format!("Output description {} does not match the state memo size of {} bytes", index, memo_size),
+            NullifierAlreadySpent(index) => format!("The nullifier of spend description {} has already been spent", index),
+            DuplicateNullifier(index) => format!("The nullifier of spend description {} is used more than once", index),
+            UnknownAnchor => String::from("The transaction root is not among the recent roots of the state"),
+            InvalidSpendAuthSig(index) => format!("Spend description {} has an invalid spend authorization signature", index),
+            InvalidSpend(index) => format!("Spend description {} has an invalid proof or signature", index),
+            InvalidOutput(index) => format!("Output description {} has an invalid proof", index),
+            BalanceOutOfRange(balance) => format!("Transaction balance {} is out of range", balance),
+            InvalidBindingSig => String::from("Invalid binding signature"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PaymentDisclosureError {
+    UnknownSpendKey,
+    InvalidSignature,
+    ValueCommitmentMismatch,
+    InvalidRcvFlag(u8),
+}
+
+impl DetailedError for PaymentDisclosureError {
+    fn details(&self) -> String {
+        use PaymentDisclosureError::*;
+
+        match self {
+            UnknownSpendKey => String::from("The payment disclosure is not signed with a spend key of the transaction"),
+            InvalidSignature => String::from("Invalid payment disclosure signature"),
+            ValueCommitmentMismatch => String::from("The disclosed value does not match the value commitment of the output"),
+            InvalidRcvFlag(flag) => format!("Invalid payment disclosure rcv flag {}", flag),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MessageEncryptionError {
+    InvalidDiversifier,
+    UnsupportedVersion(u8),
+    EncryptionFailed,
+    DecryptionFailed,
+}
+
+impl DetailedError for MessageEncryptionError {
+    fn details(&self) -> String {
+        use MessageEncryptionError::*;
+
+        match self {
+            InvalidDiversifier => String::from("The payment address has an invalid diversifier"),
+            UnsupportedVersion(version) => format!("Unsupported encrypted message version {}", version),
+            EncryptionFailed => String::from("Could not encrypt the message"),
+            DecryptionFailed => String::from("Could not decrypt the message"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum HistoryError {
+    UnorderedLevel(u64, u64),
+    BalanceOutOfRange,
+}
+
+impl DetailedError for HistoryError {
+    fn details(&self) -> String {
+        use HistoryError::*;
+
+        match self {
+            UnorderedLevel(previous, level) => format!("Transactions are not ordered by level, got level {} after {}", level, previous),
+            BalanceOutOfRange => String::from("The balance is out of range"),
+        }
+    }
+}
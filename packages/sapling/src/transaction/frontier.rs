@@ -0,0 +1,330 @@
+use std::convert::TryInto;
+
+use zcash_primitives::merkle_tree::Hashable;
+use zcash_primitives::sapling::{Node, SAPLING_COMMITMENT_TREE_DEPTH};
+
+use crate::common::errors::{CausedBy, DeserializationError, SaplingError};
+use crate::common::traits::Serializable;
+use crate::common::utils::assert_utils::assert_value_or_error;
+use crate::common::utils::deserialize_utils::{read_chunks, to_array};
+use crate::transaction::errors::FrontierError;
+
+const MAX_TREE_SIZE: u64 = 1 << SAPLING_COMMITMENT_TREE_DEPTH;
+
+// The right-most leaf of a commitment tree and the left siblings (ommers) on its path to the root,
+// which is all that's needed to compute the root and to keep appending commitments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommitmentTreeFrontier {
+    size: u64,
+    leaf: Option<Node>,
+    ommers: Vec<Node>,
+}
+
+impl CommitmentTreeFrontier {
+    pub fn empty() -> Self {
+        CommitmentTreeFrontier { size: 0, leaf: None, ommers: vec![] }
+    }
+
+    // `ommers` are ordered bottom-up, there's one for each level at which the last leaf is in a right subtree.
+    pub fn new(size: u64, leaf: Node, ommers: Vec<Node>) -> Result<Self, SaplingError> {
+        assert_value_or_error(size > 0 && size <= MAX_TREE_SIZE, FrontierError::InvalidSize(size)).map_err(SaplingError::caused_by)?;
+
+        let expected = (size - 1).count_ones() as usize;
+        assert_value_or_error(ommers.len() == expected, FrontierError::InvalidOmmerCount(expected, ommers.len())).map_err(SaplingError::caused_by)?;
+
+        std::iter::once(&leaf).chain(ommers.iter()).try_for_each(assert_node)?;
+
+        Ok(CommitmentTreeFrontier { size, leaf: Some(leaf), ommers })
+    }
+
+    // `path` holds the siblings of the last leaf bottom-up, the right ones must be empty.
+    pub fn from_rightmost_path(size: u64, leaf: Node, path: &[Node]) -> Result<Self, SaplingError> {
+        assert_value_or_error(size > 0 && size <= MAX_TREE_SIZE, FrontierError::InvalidSize(size)).map_err(SaplingError::caused_by)?;
+        assert_value_or_error(path.len() == SAPLING_COMMITMENT_TREE_DEPTH, FrontierError::InvalidOmmerCount(SAPLING_COMMITMENT_TREE_DEPTH, path.len()))
+            .map_err(SaplingError::caused_by)?;
+
+        let position = size - 1;
+        let mut ommers = vec![];
+        for (level, sibling) in path.iter().enumerate() {
+            if (position >> level) & 1 == 1 {
+                ommers.push(*sibling);
+            } else if *sibling != Node::empty_root(level) {
+                return Err(SaplingError::caused_by(FrontierError::NonEmptyRightSibling(level)));
+            }
+        }
+
+        Self::new(size, leaf, ommers)
+    }
+
+    pub fn from_commitments(commitments: &[Node]) -> Result<Self, SaplingError> {
+        let mut frontier = Self::empty();
+        for commitment in commitments {
+            frontier.append(*commitment)?;
+        }
+
+        Ok(frontier)
+    }
+
+    // Tezos stores only the size and the root of the tree, so the frontier can be checked against them.
+    pub fn from_tezos_state(size: u64, root: Node, frontier: CommitmentTreeFrontier) -> Result<Self, SaplingError> {
+        frontier.validate(size, root)?;
+
+        Ok(frontier)
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn append(&mut self, commitment: Node) -> Result<(), SaplingError> {
+        assert_value_or_error(self.size < MAX_TREE_SIZE, FrontierError::TreeFull).map_err(SaplingError::caused_by)?;
+        assert_node(&commitment)?;
+
+        if let Some(leaf) = self.leaf {
+            // the ommers of the levels at which the last leaf was a right child are merged into a single new one
+            let position = self.size - 1;
+            let merged = position.trailing_ones() as usize;
+            let carry = self.ommers.iter()
+                .take(merged)
+                .enumerate()
+                .fold(leaf, |carry, (level, ommer)| Node::combine(level, ommer, &carry));
+
+            self.ommers.splice(0..merged, [carry]);
+        }
+
+        self.leaf = Some(commitment);
+        self.size += 1;
+
+        Ok(())
+    }
+
+    pub fn root(&self) -> Node {
+        let leaf = match self.leaf {
+            Some(leaf) => leaf,
+            None => return Node::empty_root(SAPLING_COMMITMENT_TREE_DEPTH),
+        };
+
+        let position = self.size - 1;
+        let mut ommers = self.ommers.iter();
+
+        (0..SAPLING_COMMITMENT_TREE_DEPTH).fold(leaf, |node, level| {
+            if (position >> level) & 1 == 1 {
+                Node::combine(level, ommers.next().unwrap(), &node)
+            } else {
+                Node::combine(level, &node, &Node::empty_root(level))
+            }
+        })
+    }
+
+    // The root as expected by `prepare_spend_description`.
+    pub fn anchor(&self) -> bls12_381::Scalar {
+        bls12_381::Scalar::from(self.root())
+    }
+
+    pub fn validate(&self, size: u64, root: Node) -> Result<(), SaplingError> {
+        assert_value_or_error(self.size == size, FrontierError::SizeMismatch(size, self.size)).map_err(SaplingError::caused_by)?;
+        assert_value_or_error(self.root() == root, FrontierError::RootMismatch).map_err(SaplingError::caused_by)?;
+
+        Ok(())
+    }
+}
+
+// size (8 bytes, LE) || leaf (32 bytes, if not empty) || ommers (32 bytes each)
+impl Serializable<Vec<u8>, SaplingError> for CommitmentTreeFrontier {
+    fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> where Self: Sized {
+        let invalid_length = |expected: usize| SaplingError::caused_by(DeserializationError::InvalidLength("commitment tree frontier", expected, serialized.len()));
+
+        let size: [u8; 8] = serialized.get(0..8).and_then(|size| size.try_into().ok()).ok_or_else(|| invalid_length(8))?;
+        let size = u64::from_le_bytes(size);
+        if size == 0 {
+            return if serialized.len() == 8 { Ok(Self::empty()) } else { Err(invalid_length(8)) };
+        }
+        assert_value_or_error(size <= MAX_TREE_SIZE, FrontierError::InvalidSize(size)).map_err(SaplingError::caused_by)?;
+
+        let expected = 8 + 32 * (1 + (size - 1).count_ones() as usize);
+        assert_value_or_error(serialized.len() == expected, invalid_length(expected))?;
+
+        let mut nodes = serialized[8..].chunks(32).map(|node| Node::new(node.try_into().unwrap()));
+        let leaf = nodes.next().unwrap();
+
+        Self::new(size, leaf, nodes.collect())
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
+        let mut bytes = self.size.to_le_bytes().to_vec();
+        for node in self.leaf.iter().chain(self.ommers.iter()) {
+            bytes.extend_from_slice(&node_bytes(node));
+        }
+
+        Ok(bytes)
+    }
+}
+
+pub fn node_bytes(node: &Node) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    node.write(&mut bytes[..]).unwrap();
+
+    bytes
+}
+
+pub fn node_from_bytes(bytes: &[u8]) -> Result<Node, SaplingError> {
+    let node = Node::new(to_array("commitment tree node", bytes).map_err(SaplingError::caused_by)?);
+    assert_node(&node)?;
+
+    Ok(node)
+}
+
+// Concatenated 32 byte nodes, as passed through the bindings.
+pub fn nodes_from_bytes(bytes: &[u8]) -> Result<Vec<Node>, SaplingError> {
+    read_chunks("commitment tree nodes", bytes, |chunk| {
+        let node = Node::new(*chunk);
+        assert_node(&node)?;
+
+        Ok(node)
+    })
+}
+
+// `Node` accepts any bytes, but turning a non-canonical one into an anchor panics.
+fn assert_node(node: &Node) -> Result<(), SaplingError> {
+    let scalar: Option<bls12_381::Scalar> = bls12_381::Scalar::from_bytes(&node_bytes(node)).into();
+    assert_value_or_error(scalar.is_some(), FrontierError::InvalidNode).map_err(SaplingError::caused_by)
+}
+
+#[cfg(test)]
+mod tests {
+    use zcash_primitives::merkle_tree::{CommitmentTree, IncrementalWitness};
+
+    use crate::transaction::merkle_hash;
+
+    use super::*;
+
+    fn commitment(index: u64) -> Node {
+        Node::new(bls12_381::Scalar::from(index + 1).to_bytes())
+    }
+
+    #[test]
+    fn computes_same_root_as_commitment_tree() {
+        let mut tree = CommitmentTree::<Node>::empty();
+        let mut frontier = CommitmentTreeFrontier::empty();
+        assert_eq!(frontier.root(), tree.root());
+
+        for index in 0..33 {
+            tree.append(commitment(index)).unwrap();
+            frontier.append(commitment(index)).unwrap();
+
+            assert_eq!(frontier.size(), tree.size() as u64);
+            assert_eq!(frontier.root(), tree.root());
+        }
+    }
+
+    #[test]
+    fn interoperates_with_merkle_hash() {
+        let frontier = CommitmentTreeFrontier::from_commitments(&[commitment(0), commitment(1)]).unwrap();
+
+        let leaves = merkle_hash(0, node_bytes(&commitment(0)), node_bytes(&commitment(1)));
+        let root = (1..SAPLING_COMMITMENT_TREE_DEPTH).fold(leaves, |node, level| merkle_hash(level, node, node_bytes(&Node::empty_root(level))));
+
+        assert_eq!(node_bytes(&frontier.root()), root);
+    }
+
+    #[test]
+    fn produces_anchor_of_witnessed_notes() {
+        let mut tree = CommitmentTree::<Node>::empty();
+        for index in 0..5 {
+            tree.append(commitment(index)).unwrap();
+        }
+        let mut witness = IncrementalWitness::from_tree(&tree);
+        let mut frontier = CommitmentTreeFrontier::from_commitments(&(0..5).map(commitment).collect::<Vec<_>>()).unwrap();
+
+        for index in 5..11 {
+            witness.append(commitment(index)).unwrap();
+            frontier.append(commitment(index)).unwrap();
+        }
+
+        let path = witness.path().unwrap();
+        assert_eq!(path.root(commitment(4)), frontier.root());
+        assert_eq!(bls12_381::Scalar::from(witness.root()), frontier.anchor());
+    }
+
+    #[test]
+    fn imports_frontier_from_rightmost_path() {
+        let commitments: Vec<Node> = (0..11).map(commitment).collect();
+        let expected = CommitmentTreeFrontier::from_commitments(&commitments).unwrap();
+
+        let mut tree = CommitmentTree::<Node>::empty();
+        commitments.iter().for_each(|commitment| tree.append(*commitment).unwrap());
+        let path: Vec<Node> = IncrementalWitness::from_tree(&tree).path().unwrap().auth_path.iter().map(|(node, _)| *node).collect();
+
+        let frontier = CommitmentTreeFrontier::from_rightmost_path(11, commitment(10), &path).unwrap();
+        assert_eq!(frontier, expected);
+        assert_eq!(frontier.root(), tree.root());
+
+        let mut invalid_path = path.clone();
+        invalid_path[2] = commitment(0);
+        assert_eq!(
+            CommitmentTreeFrontier::from_rightmost_path(11, commitment(10), &invalid_path).unwrap_err(),
+            SaplingError::caused_by(FrontierError::NonEmptyRightSibling(2))
+        );
+    }
+
+    #[test]
+    fn validates_frontier_against_tezos_state() {
+        let frontier = CommitmentTreeFrontier::from_commitments(&(0..3).map(commitment).collect::<Vec<_>>()).unwrap();
+        let root = frontier.root();
+
+        assert_eq!(CommitmentTreeFrontier::from_tezos_state(3, root, frontier.clone()).unwrap(), frontier);
+        assert_eq!(
+            CommitmentTreeFrontier::from_tezos_state(4, root, frontier.clone()).unwrap_err(),
+            SaplingError::caused_by(FrontierError::SizeMismatch(4, 3))
+        );
+        assert_eq!(
+            CommitmentTreeFrontier::from_tezos_state(3, commitment(0), frontier).unwrap_err(),
+            SaplingError::caused_by(FrontierError::RootMismatch)
+        );
+    }
+
+    #[test]
+    fn serializes_and_deserializes_frontier() {
+        for size in [0u64, 1, 2, 7, 8, 13] {
+            let frontier = CommitmentTreeFrontier::from_commitments(&(0..size).map(commitment).collect::<Vec<_>>()).unwrap();
+            let serialized = frontier.serialize().unwrap();
+
+            assert_eq!(serialized.len(), 8 + 32 * (size.min(1) as usize + frontier.ommers.len()));
+            assert_eq!(CommitmentTreeFrontier::deserialize(serialized).unwrap(), frontier);
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_frontier() {
+        let frontier = CommitmentTreeFrontier::from_commitments(&(0..3).map(commitment).collect::<Vec<_>>()).unwrap().serialize().unwrap();
+        let mut non_canonical = frontier.clone();
+        non_canonical[8..40].copy_from_slice(&[0xff; 32]);
+
+        let test_data = vec![
+            (vec![], SaplingError::caused_by(DeserializationError::InvalidLength("commitment tree frontier", 8, 0))),
+            (frontier[..40].to_vec(), SaplingError::caused_by(DeserializationError::InvalidLength("commitment tree frontier", 72, 40))),
+            ([&(MAX_TREE_SIZE + 1).to_le_bytes()[..], &[0u8; 32]].concat(), SaplingError::caused_by(FrontierError::InvalidSize(MAX_TREE_SIZE + 1))),
+            (non_canonical, SaplingError::caused_by(FrontierError::InvalidNode)),
+        ];
+
+        for (bytes, expected) in test_data {
+            assert_eq!(CommitmentTreeFrontier::deserialize(bytes).unwrap_err(), expected);
+        }
+
+        assert_eq!(
+            CommitmentTreeFrontier::new(3, commitment(2), vec![]).unwrap_err(),
+            SaplingError::caused_by(FrontierError::InvalidOmmerCount(1, 0))
+        );
+        assert_eq!(
+            nodes_from_bytes(&[0u8; 33]).unwrap_err(),
+            SaplingError::caused_by(DeserializationError::InvalidLength("commitment tree nodes", 32, 33))
+        );
+    }
+
+    #[test]
+    fn rejects_append_to_full_tree() {
+        let mut frontier = CommitmentTreeFrontier::new(MAX_TREE_SIZE, commitment(0), vec![commitment(1); SAPLING_COMMITMENT_TREE_DEPTH]).unwrap();
+
+        assert_eq!(frontier.append(commitment(2)).unwrap_err(), SaplingError::caused_by(FrontierError::TreeFull));
+    }
+}
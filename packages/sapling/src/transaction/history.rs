@@ -0,0 +1,345 @@
+use std::collections::HashMap;
+
+use zcash_primitives::primitives::{Nullifier, PaymentAddress};
+use zcash_primitives::zip32::ExtendedFullViewingKey;
+
+use crate::address::encode_payment_address;
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::utils::assert_utils::assert_value_or_error;
+use crate::key::xfvk_fingerprint;
+use crate::transaction::errors::HistoryError;
+use crate::transaction::memo::Memo;
+use crate::transaction::note_encryption::{decrypt_note_ciphertext, recover_note_ciphertext};
+use crate::transaction::output::SizedOutputDescription;
+use crate::transaction::spend::compute_nullifier;
+
+const CSV_HEADER: &str = "fingerprint,level,transaction_hash,position,kind,address,value,balance,memo,memo_text";
+
+// A transaction of the shielded pool as seen by a scanner, the outputs are at their position in the commitment tree.
+pub struct HistoryTransaction {
+    pub hash: String,
+    pub level: u64,
+    pub nullifiers: Vec<Nullifier>,
+    pub outputs: Vec<HistoryOutput>,
+}
+
+pub struct HistoryOutput {
+    pub position: u64,
+    pub description: SizedOutputDescription,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HistoryEntryKind {
+    Received,
+    Spent,
+    Sent,
+}
+
+impl HistoryEntryKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            HistoryEntryKind::Received => "received",
+            HistoryEntryKind::Spent => "spent",
+            HistoryEntryKind::Sent => "sent",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<HistoryEntryKind> {
+        match name {
+            "received" => Some(HistoryEntryKind::Received),
+            "spent" => Some(HistoryEntryKind::Spent),
+            "sent" => Some(HistoryEntryKind::Sent),
+            _ => None,
+        }
+    }
+}
+
+// `position` is the position of the note in the commitment tree, for a spent note the one of the note it spends.
+// `balance` is the balance after the entry, sent entries don't change it, the notes they spend do.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryEntry {
+    pub kind: HistoryEntryKind,
+    pub level: u64,
+    pub transaction_hash: String,
+    pub position: u64,
+    pub address: PaymentAddress,
+    pub value: u64,
+    pub memo: Vec<u8>,
+    pub balance: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LevelBalance {
+    pub level: u64,
+    pub balance: u64,
+}
+
+// The history of an extended full viewing key, identified by its ZIP-32 fingerprint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct History {
+    pub fingerprint: [u8; 32],
+    pub entries: Vec<HistoryEntry>,
+    pub balances: Vec<LevelBalance>,
+}
+
+// Notes are received if they can be decrypted with the `ivk` and spent if their nullifier appears in a later transaction.
+// Outputs that can only be recovered with the `ovk` are sent to someone else, change sent to the key itself is received.
+pub fn build_history(xfvk: &ExtendedFullViewingKey, transactions: &[HistoryTransaction]) -> Result<History, SaplingError> {
+    let ivk = xfvk.fvk.vk.ivk();
+
+    let mut unspent: HashMap<[u8; 32], HistoryEntry> = HashMap::new();
+    let mut entries = vec![];
+    let mut balances: Vec<LevelBalance> = vec![];
+    let mut balance = 0u64;
+
+    for transaction in transactions {
+        if let Some(last) = balances.last() {
+            assert_value_or_error(transaction.level >= last.level, HistoryError::UnorderedLevel(last.level, transaction.level))
+                .map_err(SaplingError::caused_by)?;
+        }
+
+        for nullifier in transaction.nullifiers.iter() {
+            let note = match unspent.remove(&nullifier.0) {
+                Some(note) => note,
+                None => continue,
+            };
+            balance -= note.value;
+
+            entries.push(HistoryEntry {
+                kind: HistoryEntryKind::Spent,
+                level: transaction.level,
+                transaction_hash: transaction.hash.clone(),
+                balance,
+                ..note
+            });
+        }
+
+        for output in transaction.outputs.iter() {
+            let description = &output.description;
+            let memo_size = description.memo_size();
+
+            let received = decrypt_note_ciphertext(&ivk, &description.ephemeral_key, &description.cmu, &description.enc_ciphertext, memo_size);
+            if let Ok(received) = received {
+                let nullifier = compute_nullifier(&xfvk.fvk.vk, &received.address, received.note.value, received.note.rcm(), output.position)?;
+                balance = balance.checked_add(received.note.value)
+                    .ok_or(HistoryError::BalanceOutOfRange)
+                    .map_err(SaplingError::caused_by)?;
+
+                let entry = HistoryEntry {
+                    kind: HistoryEntryKind::Received,
+                    level: transaction.level,
+                    transaction_hash: transaction.hash.clone(),
+                    position: output.position,
+                    address: received.address,
+                    value: received.note.value,
+                    memo: received.memo,
+                    balance,
+                };
+                unspent.insert(nullifier.0, entry.clone());
+                entries.push(entry);
+
+                continue;
+            }
+
+            let sent = recover_note_ciphertext(
+                &xfvk.fvk.ovk,
+                &description.cv,
+                &description.cmu,
+                &description.ephemeral_key,
+                &description.enc_ciphertext,
+                &description.out_ciphertext,
+                memo_size,
+            );
+            if let Ok(sent) = sent {
+                entries.push(HistoryEntry {
+                    kind: HistoryEntryKind::Sent,
+                    level: transaction.level,
+                    transaction_hash: transaction.hash.clone(),
+                    position: output.position,
+                    address: sent.address,
+                    value: sent.note.value,
+                    memo: sent.memo,
+                    balance,
+                });
+            }
+        }
+
+        match balances.last_mut() {
+            Some(last) if last.level == transaction.level => last.balance = balance,
+            _ => balances.push(LevelBalance { level: transaction.level, balance }),
+        }
+    }
+
+    Ok(History { fingerprint: xfvk_fingerprint(xfvk), entries, balances })
+}
+
+impl History {
+    // One row per entry, addresses are `zet1` encoded and memos hex encoded,
+    // text memos are also included as text.
+    pub fn to_csv(&self) -> String {
+        let fingerprint = hex::encode(self.fingerprint);
+        let mut csv = String::from(CSV_HEADER);
+        csv.push_str("\r\n");
+
+        for entry in self.entries.iter() {
+            let row = [
+                fingerprint.clone(),
+                entry.level.to_string(),
+                csv_field(&entry.transaction_hash),
+                entry.position.to_string(),
+                String::from(entry.kind.name()),
+                encode_payment_address(&entry.address),
+                entry.value.to_string(),
+                entry.balance.to_string(),
+                hex::encode(&entry.memo),
+                csv_field(&memo_text(&entry.memo).unwrap_or_default()),
+            ];
+            csv.push_str(&row.join(","));
+            csv.push_str("\r\n");
+        }
+
+        csv
+    }
+}
+
+pub fn memo_text(memo: &[u8]) -> Option<String> {
+    match Memo::decode(memo) {
+        Ok(Memo::Text(text)) => Some(text),
+        _ => None,
+    }
+}
+
+// Quoted as in RFC 4180. Text starting with a formula character is prefixed with `'`,
+// so a spreadsheet doesn't evaluate a memo chosen by the sender.
+fn csv_field(value: &str) -> String {
+    let value = match value.chars().next() {
+        Some('=') | Some('+') | Some('-') | Some('@') | Some('\t') | Some('\r') => format!("'{}", value),
+        _ => value.to_string(),
+    };
+
+    if value.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zcash_primitives::constants::SPENDING_KEY_GENERATOR;
+    use zcash_primitives::transaction::components::GROTH_PROOF_SIZE;
+    use zcash_primitives::zip32::ExtendedSpendingKey;
+
+    use crate::key::SaplingKey;
+    use crate::transaction::create_note;
+    use crate::transaction::note_encryption::{encrypt_note_plaintext, encrypt_outgoing_plaintext};
+    use crate::transaction::rand::rand_scalar;
+
+    use super::*;
+
+    fn prepare_keys() -> (ExtendedFullViewingKey, ExtendedFullViewingKey) {
+        let xfvk = ExtendedFullViewingKey::from(&ExtendedSpendingKey::from_seed(&[0u8; 32], "m/").unwrap());
+        let other_xfvk = ExtendedFullViewingKey::from(&ExtendedSpendingKey::from_seed(&[1u8; 32], "m/").unwrap());
+
+        (xfvk, other_xfvk)
+    }
+
+    fn output(sender: &ExtendedFullViewingKey, recipient: &ExtendedFullViewingKey, position: u64, value: u64, memo: &[u8]) -> HistoryOutput {
+        let (_, address) = recipient.default_address().unwrap();
+        let note = create_note(&address, value, rand_scalar()).unwrap();
+        let esk = rand_scalar();
+        let cv: jubjub::ExtendedPoint = (SPENDING_KEY_GENERATOR * rand_scalar()).into();
+
+        let description = SizedOutputDescription {
+            cv,
+            cmu: note.cmu(),
+            ephemeral_key: (note.g_d * esk).into(),
+            enc_ciphertext: encrypt_note_plaintext(&note, &address, &esk, Some(memo), 16).unwrap(),
+            out_ciphertext: encrypt_outgoing_plaintext(&sender.fvk.ovk, &note, &esk, &cv, &note.cmu()).unwrap(),
+            zkproof: [0u8; GROTH_PROOF_SIZE],
+        };
+
+        HistoryOutput { position, description }
+    }
+
+    fn nullifier(xfvk: &ExtendedFullViewingKey, output: &HistoryOutput) -> Nullifier {
+        let description = &output.description;
+        let note = decrypt_note_ciphertext(&xfvk.fvk.vk.ivk(), &description.ephemeral_key, &description.cmu, &description.enc_ciphertext, 16).unwrap();
+
+        compute_nullifier(&xfvk.fvk.vk, &note.address, note.note.value, note.note.rcm(), output.position).unwrap()
+    }
+
+    fn prepare_transactions(xfvk: &ExtendedFullViewingKey, other_xfvk: &ExtendedFullViewingKey) -> Vec<HistoryTransaction> {
+        let received = output(other_xfvk, xfvk, 0, 1000, b"salary");
+        let foreign = output(other_xfvk, other_xfvk, 1, 500, &[]);
+        let spent_nullifier = nullifier(xfvk, &received);
+
+        vec![
+            HistoryTransaction { hash: String::from("op1"), level: 10, nullifiers: vec![], outputs: vec![received, foreign] },
+            HistoryTransaction {
+                hash: String::from("op2"),
+                level: 12,
+                nullifiers: vec![Nullifier([7u8; 32]), spent_nullifier],
+                outputs: vec![output(xfvk, other_xfvk, 2, 300, b"=cmd"), output(xfvk, xfvk, 3, 700, &[])],
+            },
+            HistoryTransaction { hash: String::from("op3"), level: 12, nullifiers: vec![spent_nullifier], outputs: vec![] },
+        ]
+    }
+
+    #[test]
+    fn builds_history_with_received_spent_and_sent_entries() {
+        let (xfvk, other_xfvk) = prepare_keys();
+        let history = build_history(&xfvk, &prepare_transactions(&xfvk, &other_xfvk)).unwrap();
+
+        let actual: Vec<(HistoryEntryKind, &str, u64, u64, u64)> = history.entries.iter()
+            .map(|entry| (entry.kind, entry.transaction_hash.as_str(), entry.position, entry.value, entry.balance))
+            .collect();
+
+        assert_eq!(history.fingerprint, xfvk_fingerprint(&xfvk));
+        assert_eq!(actual, vec![
+            (HistoryEntryKind::Received, "op1", 0, 1000, 1000),
+            (HistoryEntryKind::Spent, "op2", 0, 1000, 0),
+            (HistoryEntryKind::Sent, "op2", 2, 300, 0),
+            (HistoryEntryKind::Received, "op2", 3, 700, 700),
+        ]);
+        assert_eq!(memo_text(&history.entries[0].memo), Some(String::from("salary")));
+        assert_eq!(history.entries[2].address, other_xfvk.default_address().unwrap().1);
+        assert_eq!(history.balances, vec![LevelBalance { level: 10, balance: 1000 }, LevelBalance { level: 12, balance: 700 }]);
+    }
+
+    #[test]
+    fn exports_history_as_csv() {
+        let (xfvk, other_xfvk) = prepare_keys();
+        let history = build_history(&xfvk, &prepare_transactions(&xfvk, &other_xfvk)).unwrap();
+
+        let csv = history.to_csv();
+        let rows: Vec<&str> = csv.split("\r\n").collect();
+
+        assert_eq!(rows.len(), 6);
+        assert_eq!(rows[0], CSV_HEADER);
+        assert!(rows[1].starts_with(&format!("{},10,op1,0,received,zet1", hex::encode(history.fingerprint))));
+        assert!(rows[1].ends_with(",1000,1000,73616c61727900000000000000000000,salary"));
+        assert!(rows[3].ends_with(",'=cmd"));
+        assert_eq!(rows[5], "");
+    }
+
+    #[test]
+    fn quotes_csv_fields() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("-1,5"), "\"'-1,5\"");
+    }
+
+    #[test]
+    fn fails_on_unordered_transactions() {
+        let (xfvk, other_xfvk) = prepare_keys();
+        let mut transactions = prepare_transactions(&xfvk, &other_xfvk);
+        transactions.swap(0, 1);
+
+        assert_eq!(
+            build_history(&xfvk, &transactions).err(),
+            Some(SaplingError::caused_by(HistoryError::UnorderedLevel(12, 10))),
+        );
+    }
+}
@@ -0,0 +1,225 @@
+use std::str;
+
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::utils::assert_utils::assert_value_or_error;
+use crate::transaction::errors::MemoError;
+
+pub const DEFAULT_MEMO_SIZE: usize = 512;
+
+const MAX_TEXT_MEMO_MARKER: u8 = 0xF4;
+const EMPTY_MEMO_MARKER: u8 = 0xF6;
+const ARBITRARY_MEMO_MARKER: u8 = 0xFF;
+
+// Based on [`Memo`](https://github.com/zcash/librustzcash/blob/master/zcash_primitives/src/memo.rs#L149),
+// interprets the leading byte as specified in [ZIP-302](https://zips.z.cash/zip-0302) for memo fields of any size.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Memo {
+    Empty,
+    Text(String),
+    Arbitrary(Vec<u8>),
+    Future(Vec<u8>),
+}
+
+impl Memo {
+    pub fn decode(memo: &[u8]) -> Result<Memo, SaplingError> {
+        let memo = match memo.first() {
+            None => Memo::Empty,
+            Some(&EMPTY_MEMO_MARKER) if memo[1..].iter().all(|byte| *byte == 0) => Memo::Empty,
+            Some(&ARBITRARY_MEMO_MARKER) => Memo::Arbitrary(memo[1..].to_vec()),
+            Some(&marker) if marker <= MAX_TEXT_MEMO_MARKER => {
+                let len = memo.iter().rposition(|byte| *byte != 0).map_or(0, |position| position + 1);
+                let text = str::from_utf8(&memo[..len]).map_err(|err| SaplingError::caused_by(MemoError::InvalidText(err.to_string())))?;
+
+                Memo::Text(String::from(text))
+            },
+            Some(_) => Memo::Future(memo.to_vec()),
+        };
+
+        Ok(memo)
+    }
+
+    pub fn encode(&self, memo_size: usize) -> Result<Vec<u8>, SaplingError> {
+        match self {
+            Memo::Empty => prepare_memo(None, memo_size),
+            Memo::Text(text) => {
+                assert_value_or_error(!text.ends_with('\0'), MemoError::InvalidText(String::from("text cannot end with a null character")))
+                    .map_err(SaplingError::caused_by)?;
+
+                prepare_memo(Some(text.as_bytes()), memo_size)
+            },
+            Memo::Arbitrary(data) => {
+                assert_value_or_error(data.len() < memo_size, MemoError::TooLong(data.len() + 1, memo_size))
+                    .map_err(SaplingError::caused_by)?;
+
+                prepare_memo(Some(&[&[ARBITRARY_MEMO_MARKER], &data[..]].concat()), memo_size)
+            },
+            Memo::Future(memo) => {
+                let reserved = matches!(memo.first(), Some(marker) if is_reserved_marker(*marker, &memo[1..]));
+                assert_value_or_error(reserved, MemoError::InvalidFutureMemo)
+                    .map_err(SaplingError::caused_by)?;
+
+                prepare_memo(Some(memo), memo_size)
+            },
+        }
+    }
+}
+
+impl Memo {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Memo::Empty => "empty",
+            Memo::Text(_) => "text",
+            Memo::Arbitrary(_) => "arbitrary",
+            Memo::Future(_) => "future",
+        }
+    }
+
+    // The UTF-8 text, the arbitrary data without the leading byte or the whole memo reserved for future use.
+    pub fn content(&self) -> &[u8] {
+        match self {
+            Memo::Empty => &[],
+            Memo::Text(text) => text.as_bytes(),
+            Memo::Arbitrary(data) => data,
+            Memo::Future(memo) => memo,
+        }
+    }
+}
+
+fn is_reserved_marker(marker: u8, rest: &[u8]) -> bool {
+    match marker {
+        EMPTY_MEMO_MARKER => rest.iter().any(|byte| *byte != 0),
+        ARBITRARY_MEMO_MARKER => false,
+        marker => marker > MAX_TEXT_MEMO_MARKER,
+    }
+}
+
+pub fn prepare_memo(memo: Option<&[u8]>, memo_size: usize) -> Result<Vec<u8>, SaplingError> {
+    let mut padded = vec![0u8; memo_size];
+    match memo {
+        Some(memo) => {
+            assert_value_or_error(memo.len() <= memo_size, MemoError::TooLong(memo.len(), memo_size))
+                .map_err(SaplingError::caused_by)?;
+
+            padded[..memo.len()].copy_from_slice(memo);
+        },
+        None => {
+            if memo_size > 0 {
+                padded[0] = EMPTY_MEMO_MARKER;
+            }
+        }
+    }
+
+    Ok(padded)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+    use std::str::FromStr;
+
+    use zcash_primitives::memo::MemoBytes;
+
+    use super::*;
+
+    #[test]
+    fn pads_memo_to_memo_size() {
+        let test_data = [
+            (Some(vec![1u8, 2, 3]), 8, vec![1u8, 2, 3, 0, 0, 0, 0, 0]),
+            (Some(vec![1u8, 2, 3]), 3, vec![1u8, 2, 3]),
+            (Some(vec![]), 2, vec![0u8, 0]),
+            (None, 4, vec![0xF6, 0, 0, 0]),
+            (None, 0, vec![]),
+        ];
+
+        let actual_expected = test_data.iter()
+            .map(|(memo, memo_size, expected)| {
+                let actual = prepare_memo(memo.as_deref(), *memo_size).unwrap();
+
+                (actual, expected)
+            });
+
+        for (actual, expected) in actual_expected {
+            assert_eq!(&actual, expected);
+        }
+    }
+
+    #[test]
+    fn pads_memo_like_memo_bytes_with_default_memo_size() {
+        let memo = [7u8; 100];
+
+        let actual = prepare_memo(Some(&memo), DEFAULT_MEMO_SIZE).unwrap();
+        let expected = MemoBytes::from_bytes(&memo).unwrap();
+        assert_eq!(&actual[..], &expected.as_array()[..]);
+
+        let actual_empty = prepare_memo(None, DEFAULT_MEMO_SIZE).unwrap();
+        let expected_empty = MemoBytes::empty();
+        assert_eq!(&actual_empty[..], &expected_empty.as_array()[..]);
+    }
+
+    #[test]
+    fn fails_to_prepare_memo_longer_than_memo_size() {
+        let error = prepare_memo(Some(&[0u8; 9]), 8).unwrap_err();
+
+        assert_eq!(error, SaplingError::caused_by(MemoError::TooLong(9, 8)));
+    }
+
+    #[test]
+    fn decodes_memo_by_leading_byte() {
+        let test_data = [
+            (vec![0xF6, 0, 0, 0], Memo::Empty),
+            (vec![], Memo::Empty),
+            (vec![b'h', b'i', 0, 0], Memo::Text(String::from("hi"))),
+            (vec![0, 0, 0, 0], Memo::Text(String::new())),
+            (vec![0xFF, 1, 2, 0], Memo::Arbitrary(vec![1, 2, 0])),
+            (vec![0xF6, 1, 0, 0], Memo::Future(vec![0xF6, 1, 0, 0])),
+            (vec![0xF5, 0, 0, 0], Memo::Future(vec![0xF5, 0, 0, 0])),
+            (vec![0xFE, 9, 9, 9], Memo::Future(vec![0xFE, 9, 9, 9])),
+        ];
+
+        for (memo, expected) in test_data.iter() {
+            assert_eq!(&Memo::decode(memo).unwrap(), expected);
+        }
+
+        assert!(Memo::decode(&[0xC3, 0x28, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn encodes_memo_like_zcash_memo_with_default_memo_size() {
+        let test_data = [
+            Memo::Empty,
+            Memo::Text(String::from("Thank you for your purchase")),
+            Memo::Arbitrary(vec![7u8; 511]),
+            Memo::Future(vec![0xFE; 512]),
+        ];
+
+        for memo in test_data.iter() {
+            let expected = match memo {
+                Memo::Empty => zcash_primitives::memo::Memo::Empty,
+                Memo::Text(text) => zcash_primitives::memo::Memo::from_str(text).unwrap(),
+                Memo::Arbitrary(data) => zcash_primitives::memo::Memo::Arbitrary(Box::new(data[..].try_into().unwrap())),
+                Memo::Future(memo) => zcash_primitives::memo::Memo::Future(MemoBytes::from_bytes(memo).unwrap()),
+            };
+            let expected = MemoBytes::from(expected);
+
+            let encoded = memo.encode(DEFAULT_MEMO_SIZE).unwrap();
+            assert_eq!(&encoded[..], &expected.as_array()[..]);
+            assert_eq!(&Memo::decode(&encoded).unwrap(), memo);
+        }
+    }
+
+    #[test]
+    fn fails_to_encode_malformed_memo() {
+        let test_data = [
+            (Memo::Text(String::from("too long")), 4, MemoError::TooLong(8, 4)),
+            (Memo::Text(String::from("null\0")), 8, MemoError::InvalidText(String::from("text cannot end with a null character"))),
+            (Memo::Arbitrary(vec![1, 2, 3, 4]), 4, MemoError::TooLong(5, 4)),
+            (Memo::Future(vec![b'a', 0, 0, 0]), 4, MemoError::InvalidFutureMemo),
+            (Memo::Future(vec![0xF6, 0, 0, 0]), 4, MemoError::InvalidFutureMemo),
+            (Memo::Future(vec![]), 4, MemoError::InvalidFutureMemo),
+        ];
+
+        for (memo, memo_size, expected) in test_data.iter() {
+            assert_eq!(memo.encode(*memo_size).unwrap_err(), SaplingError::caused_by(expected.clone()));
+        }
+    }
+}
@@ -1,12 +1,21 @@
 use zcash_primitives::merkle_tree::{Hashable, MerklePath};
-use zcash_primitives::sapling::merkle_hash;
+use zcash_primitives::sapling::{merkle_hash, SAPLING_COMMITMENT_TREE_DEPTH};
 
-use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::errors::{CausedBy, DeserializationError, SaplingError};
 use crate::common::traits::Serializable;
+use crate::common::utils::assert_utils::assert_value_or_error;
 use crate::transaction::errors::MerklePathError;
 
+// depth (1 byte) || (length (1 byte) || sibling (32 bytes)) * depth || position (8 bytes, LE)
+const MERKLE_PATH_SIZE: usize = 1 + 33 * SAPLING_COMMITMENT_TREE_DEPTH + 8;
+
 impl <Node: Hashable> Serializable<Vec<u8>, SaplingError> for MerklePath<Node> {
     fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
+        // `from_slice` indexes into the bytes before checking their length.
+        let len = serialized.len();
+        assert_value_or_error(len == MERKLE_PATH_SIZE, DeserializationError::InvalidLength("merkle path", MERKLE_PATH_SIZE, len))
+            .map_err(SaplingError::caused_by)?;
+
         MerklePath::from_slice(&serialized[..])
             .map_err(|_| MerklePathError::ReadFailed)
             .map_err(SaplingError::caused_by)
@@ -0,0 +1,162 @@
+use crypto_api_chachapoly::ChachaPolyIetf;
+use zcash_primitives::note_encryption::sapling_ka_agree;
+use zcash_primitives::primitives::{PaymentAddress, SaplingIvk};
+
+use crate::common::errors::{CausedBy, DeserializationError, SaplingError};
+use crate::common::traits::Serializable;
+use crate::common::utils::assert_utils::assert_value_or_error;
+use crate::transaction::errors::MessageEncryptionError;
+use crate::transaction::note_encryption::kdf_sapling;
+use crate::transaction::rand::rand_scalar;
+
+const ENCRYPTED_MESSAGE_VERSION: u8 = 0x01;
+const AEAD_TAG_SIZE: usize = 16;
+const ENCRYPTED_MESSAGE_MIN_SIZE: usize = 1 + // version
+    32 + // epk
+    AEAD_TAG_SIZE;
+
+// Encrypted like a note plaintext: the key is derived with the Sapling KDF from `esk * pk_d`
+// and a fresh `epk = esk * g_d`, so the recipient can decrypt it with `ivk * epk` without knowing the diversifier.
+// Since every message has its own key, the nonce is fixed at zero.
+pub struct EncryptedMessage {
+    pub epk: jubjub::ExtendedPoint,
+    pub ciphertext: Vec<u8>,
+}
+
+pub fn encrypt_message(to: &PaymentAddress, message: &[u8]) -> Result<EncryptedMessage, SaplingError> {
+    let g_d = to.g_d().ok_or(MessageEncryptionError::InvalidDiversifier).map_err(SaplingError::caused_by)?;
+    let esk = rand_scalar();
+    let epk: jubjub::ExtendedPoint = (g_d * esk).into();
+
+    let shared_secret = sapling_ka_agree(&esk, &(*to.pk_d()).into());
+    let key = kdf_sapling(shared_secret, &epk);
+
+    let mut ciphertext = vec![0u8; message.len() + AEAD_TAG_SIZE];
+    ChachaPolyIetf::aead_cipher()
+        .seal_to(&mut ciphertext, message, &[ENCRYPTED_MESSAGE_VERSION], key.as_bytes(), &[0u8; 12])
+        .map_err(|_| MessageEncryptionError::EncryptionFailed)
+        .map_err(SaplingError::caused_by)?;
+
+    Ok(EncryptedMessage { epk, ciphertext })
+}
+
+pub fn decrypt_message(ivk: &SaplingIvk, encrypted: &EncryptedMessage) -> Result<Vec<u8>, SaplingError> {
+    let shared_secret = sapling_ka_agree(&ivk.0, &encrypted.epk);
+    let key = kdf_sapling(shared_secret, &encrypted.epk);
+
+    let mut message = vec![0u8; encrypted.ciphertext.len()];
+    let len = ChachaPolyIetf::aead_cipher()
+        .open_to(&mut message, &encrypted.ciphertext, &[ENCRYPTED_MESSAGE_VERSION], key.as_bytes(), &[0u8; 12])
+        .map_err(|_| MessageEncryptionError::DecryptionFailed)
+        .map_err(SaplingError::caused_by)?;
+    message.truncate(len);
+
+    Ok(message)
+}
+
+// version (1 byte) || epk (32 bytes) || ciphertext (message length + 16 bytes)
+impl Serializable<Vec<u8>, SaplingError> for EncryptedMessage {
+    fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
+        assert_value_or_error(
+            serialized.len() >= ENCRYPTED_MESSAGE_MIN_SIZE,
+            DeserializationError::InvalidLength("encrypted message", ENCRYPTED_MESSAGE_MIN_SIZE, serialized.len()),
+        ).map_err(SaplingError::caused_by)?;
+        assert_value_or_error(serialized[0] == ENCRYPTED_MESSAGE_VERSION, MessageEncryptionError::UnsupportedVersion(serialized[0]))
+            .map_err(SaplingError::caused_by)?;
+
+        let epk = jubjub::ExtendedPoint::deserialize(serialized[1..33].to_vec())?;
+        let ciphertext = serialized[33..].to_vec();
+
+        Ok(EncryptedMessage { epk, ciphertext })
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
+        let epk = self.epk.serialize()?;
+
+        Ok([vec![ENCRYPTED_MESSAGE_VERSION], epk, self.ciphertext.clone()].concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zcash_primitives::primitives::Diversifier;
+    use zcash_primitives::zip32::{DiversifierIndex, ExtendedFullViewingKey, ExtendedSpendingKey};
+
+    use crate::key::SaplingKey;
+
+    use super::*;
+
+    fn prepare_recipient(seed: [u8; 32]) -> (SaplingIvk, PaymentAddress) {
+        let xfvk = ExtendedFullViewingKey::from(&ExtendedSpendingKey::from_seed(&seed, "m/").unwrap());
+        let (_, address) = xfvk.default_address().unwrap();
+
+        (xfvk.fvk.vk.ivk(), address)
+    }
+
+    #[test]
+    fn encrypts_and_decrypts_message() {
+        let (ivk, address) = prepare_recipient([0u8; 32]);
+        let message = b"refund to zet1...";
+
+        let encrypted = encrypt_message(&address, message).unwrap();
+        assert_eq!(encrypted.ciphertext.len(), message.len() + AEAD_TAG_SIZE);
+
+        let encrypted = EncryptedMessage::deserialize(encrypted.serialize().unwrap()).unwrap();
+        assert_eq!(decrypt_message(&ivk, &encrypted).unwrap(), message.to_vec());
+
+        let encrypted = encrypt_message(&address, &[]).unwrap();
+        assert_eq!(decrypt_message(&ivk, &encrypted).unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn decrypts_message_sent_to_other_diversified_address() {
+        let (ivk, _) = prepare_recipient([0u8; 32]);
+        let xfvk = ExtendedFullViewingKey::from(&ExtendedSpendingKey::from_seed(&[0u8; 32], "m/").unwrap());
+        let (_, address) = xfvk.address(DiversifierIndex([5u8; 11])).unwrap();
+
+        let encrypted = encrypt_message(&address, b"invoice 42").unwrap();
+
+        assert_eq!(decrypt_message(&ivk, &encrypted).unwrap(), b"invoice 42".to_vec());
+    }
+
+    #[test]
+    fn fails_to_decrypt_message_with_other_key_or_modified_ciphertext() {
+        let (_, address) = prepare_recipient([0u8; 32]);
+        let (other_ivk, _) = prepare_recipient([1u8; 32]);
+
+        let mut encrypted = encrypt_message(&address, b"invoice 42").unwrap();
+        assert_eq!(
+            decrypt_message(&other_ivk, &encrypted).err(),
+            Some(SaplingError::caused_by(MessageEncryptionError::DecryptionFailed)),
+        );
+
+        let (ivk, _) = prepare_recipient([0u8; 32]);
+        encrypted.ciphertext[0] ^= 1;
+        assert_eq!(
+            decrypt_message(&ivk, &encrypted).err(),
+            Some(SaplingError::caused_by(MessageEncryptionError::DecryptionFailed)),
+        );
+
+        let mut serialized = encrypted.serialize().unwrap();
+        serialized[0] = 0x02;
+        assert_eq!(
+            EncryptedMessage::deserialize(serialized).err(),
+            Some(SaplingError::caused_by(MessageEncryptionError::UnsupportedVersion(0x02))),
+        );
+    }
+
+    #[test]
+    fn fails_to_encrypt_message_to_address_with_invalid_diversifier() {
+        let (_, address) = prepare_recipient([0u8; 32]);
+        let invalid = (0u8..=255)
+            .map(|byte| Diversifier([byte; 11]))
+            .find(|diversifier| diversifier.g_d().is_none())
+            .unwrap();
+        let address = PaymentAddress::from_parts(invalid, *address.pk_d()).unwrap();
+
+        assert_eq!(
+            encrypt_message(&address, b"message").err(),
+            Some(SaplingError::caused_by(MessageEncryptionError::InvalidDiversifier)),
+        );
+    }
+}
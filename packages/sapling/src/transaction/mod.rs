@@ -1,18 +1,58 @@
+pub use builder::{CancellationToken, OutputInput, ProvedDescriptions, ProvingBuilder, SpendInput};
+pub use frontier::{CommitmentTreeFrontier, node_bytes, node_from_bytes, nodes_from_bytes};
+pub use history::{build_history, History, HistoryEntry, HistoryEntryKind, HistoryOutput, HistoryTransaction, LevelBalance, memo_text};
+pub use memo::Memo;
 pub use merkle_tree::hash as merkle_hash;
+pub use message_encryption::{decrypt_message, EncryptedMessage, encrypt_message};
 pub use note::create_note;
-pub use output::{derive_epk, OutputDetails, PartialOutputDescription, prepare_output_description, prepare_partial_output_description};
+pub use note_encryption::{DecryptedNote, decrypt_note_ciphertext};
+#[cfg(test)]
+pub use note_encryption::encrypt_note_plaintext;
+pub use output::{derive_epk, OutputDetails, PartialOutputDescription, prepare_output_description, prepare_partial_output_description, prepare_sized_output_description, prepare_sized_output_description_with_esk, SizedOutputDescription};
+pub use payment_disclosure::{create_payment_disclosure, PaymentDisclosure, spend_rks_from_bytes, verify_payment_disclosure};
 pub use proof::prepare_proof_parameters;
-pub use rand::rand_scalar;
-pub use signature::create_binding_sig;
-pub use spend::{compute_nullifier, prepare_spend_description, sign_spend_description, SpendDetails, SpendParameters, UnsignedSpendDescription};
+pub use rand::{derive_rand_scalar, DerivedRandKind, rand_bytes, rand_scalar};
+pub use sapling_state::{SaplingState, verify_update};
+pub use sapling_transaction::{KeyHash, SaplingTransaction, SaplingTransactionCiphertext, SaplingTransactionOutput, ShieldedPoolParameters, ShieldedTransfer};
+pub use signature::{create_binding_sig, create_binding_sig_with_bsk, create_spend_sig};
+pub use spend::{
+    compute_nullifier,
+    LocalSpendAuthSigner,
+    prepare_spend_description,
+    sign_spend_description,
+    SpendAuthSigner,
+    SpendDetails,
+    SpendParameters,
+    UnsignedSpendDescription,
+    verify_spend_auth_sig,
+};
+pub use value_commitment::{
+    compute_bsk,
+    compute_bvk,
+    compute_value_commitment,
+    sum_trapdoors,
+    sum_value_commitments,
+    trapdoors_from_bytes,
+    value_commitments_from_bytes,
+};
 
+mod builder;
 mod output;
+mod sapling_transaction;
 mod spend;
 mod signature;
 
+mod frontier;
+mod history;
+mod memo;
 mod merkle_tree;
+mod message_encryption;
 mod note;
+mod note_encryption;
+mod payment_disclosure;
 mod proof;
 mod rand;
+mod sapling_state;
+mod value_commitment;
 
 mod errors;
@@ -0,0 +1,402 @@
+use std::convert::TryInto;
+
+use blake2b_simd::{Hash as Blake2bHash, Params as Blake2bParams};
+use crypto_api_chachapoly::ChachaPolyIetf;
+use ff::PrimeField;
+use group::GroupEncoding;
+use zcash_primitives::keys::OutgoingViewingKey;
+use zcash_primitives::note_encryption::{KDF_SAPLING_PERSONALIZATION, OUT_CIPHERTEXT_SIZE, prf_ock, sapling_ka_agree};
+use zcash_primitives::primitives::{Diversifier, Note, PaymentAddress, Rseed, SaplingIvk};
+
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::traits::Serializable;
+use crate::common::utils::assert_utils::assert_value_or_error;
+use crate::common::utils::option_utils::ct_unwrap;
+use crate::transaction::errors::NoteEncryptionError;
+use crate::transaction::memo::prepare_memo;
+
+const COMPACT_NOTE_SIZE: usize = 1 + // version
+    11 + // diversifier
+    8  + // value
+    32; // rcm
+const OUT_PLAINTEXT_SIZE: usize = 32 + // pk_d
+    32; // esk
+const AEAD_TAG_SIZE: usize = 16;
+
+#[derive(Debug)]
+pub struct DecryptedNote {
+    pub note: Note,
+    pub address: PaymentAddress,
+    pub memo: Vec<u8>,
+}
+
+// address (43 bytes) || value (8 bytes, LE) || lead byte (1 byte) || rseed (32 bytes) || memo
+// The lead byte is the one of the note plaintext, rseed is `rcm` before ZIP 212 and the seed it is derived from after.
+impl Serializable<Vec<u8>, SaplingError> for DecryptedNote {
+    fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
+        assert_value_or_error(serialized.len() >= 84, NoteEncryptionError::InvalidPlaintext).map_err(SaplingError::caused_by)?;
+
+        let address = PaymentAddress::deserialize(serialized[..43].to_vec())?;
+        let value = u64::from_le_bytes(serialized[43..51].try_into().unwrap());
+        let rseed = match serialized[51] {
+            0x01 => Rseed::BeforeZip212(jubjub::Scalar::deserialize(serialized[52..84].to_vec())?),
+            0x02 => Rseed::AfterZip212(serialized[52..84].try_into().unwrap()),
+            _ => return Err(SaplingError::caused_by(NoteEncryptionError::InvalidPlaintext)),
+        };
+        let memo = serialized[84..].to_vec();
+
+        let note = address.create_note(value, rseed)
+            .ok_or(NoteEncryptionError::InvalidPlaintext)
+            .map_err(SaplingError::caused_by)?;
+
+        Ok(DecryptedNote { note, address, memo })
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
+        let address = self.address.serialize()?;
+        let value = self.note.value.to_le_bytes().to_vec();
+        let lead_byte = vec![get_leadbyte(&self.note.rseed)];
+        let rseed = get_rseed_bytes(&self.note.rseed).to_vec();
+
+        Ok([address, value, lead_byte, rseed, self.memo.clone()].concat())
+    }
+}
+
+pub fn enc_ciphertext_size(memo_size: usize) -> usize {
+    COMPACT_NOTE_SIZE + memo_size + AEAD_TAG_SIZE
+}
+
+// Based on [`SaplingNoteEncryption`](https://github.com/zcash/librustzcash/blob/master/zcash_primitives/src/note_encryption.rs#L142),
+// with the memo field sized by the caller instead of being fixed at 512 bytes.
+pub fn encrypt_note_plaintext(
+    note: &Note,
+    to: &PaymentAddress,
+    esk: &jubjub::Scalar,
+    memo: Option<&[u8]>,
+    memo_size: usize
+) -> Result<Vec<u8>, SaplingError> {
+    let memo = prepare_memo(memo, memo_size)?;
+
+    let epk = note.g_d * esk;
+    let shared_secret = sapling_ka_agree(esk, &(*to.pk_d()).into());
+    let key = kdf_sapling(shared_secret, &epk.into());
+
+    let mut input = vec![0u8; COMPACT_NOTE_SIZE + memo_size];
+    input[0] = get_leadbyte(&note.rseed);
+    input[1..12].copy_from_slice(&to.diversifier().0);
+    input[12..20].copy_from_slice(&note.value.to_le_bytes());
+    input[20..COMPACT_NOTE_SIZE].copy_from_slice(&get_rseed_bytes(&note.rseed));
+    input[COMPACT_NOTE_SIZE..].copy_from_slice(&memo);
+
+    let mut output = vec![0u8; enc_ciphertext_size(memo_size)];
+    ChachaPolyIetf::aead_cipher()
+        .seal_to(&mut output, &input, &[], key.as_bytes(), &[0u8; 12])
+        .map_err(|_| NoteEncryptionError::EncryptionFailed)
+        .map_err(SaplingError::caused_by)?;
+
+    Ok(output)
+}
+
+pub fn encrypt_outgoing_plaintext(
+    ovk: &OutgoingViewingKey,
+    note: &Note,
+    esk: &jubjub::Scalar,
+    cv: &jubjub::ExtendedPoint,
+    cmu: &bls12_381::Scalar
+) -> Result<[u8; OUT_CIPHERTEXT_SIZE], SaplingError> {
+    let epk = note.g_d * esk;
+    let ock = prf_ock(ovk, cv, cmu, &epk.into());
+
+    let mut input = [0u8; OUT_PLAINTEXT_SIZE];
+    input[0..32].copy_from_slice(&note.pk_d.to_bytes());
+    input[32..OUT_PLAINTEXT_SIZE].copy_from_slice(esk.to_repr().as_ref());
+
+    let mut output = [0u8; OUT_CIPHERTEXT_SIZE];
+    ChachaPolyIetf::aead_cipher()
+        .seal_to(&mut output, &input, &[], ock.as_ref(), &[0u8; 12])
+        .map_err(|_| NoteEncryptionError::EncryptionFailed)
+        .map_err(SaplingError::caused_by)?;
+
+    Ok(output)
+}
+
+pub fn decrypt_note_ciphertext(
+    ivk: &SaplingIvk,
+    epk: &jubjub::ExtendedPoint,
+    cmu: &bls12_381::Scalar,
+    enc_ciphertext: &[u8],
+    memo_size: usize
+) -> Result<DecryptedNote, SaplingError> {
+    assert_ciphertext_length(enc_ciphertext, memo_size).map_err(SaplingError::caused_by)?;
+
+    let shared_secret = sapling_ka_agree(&ivk.0, epk);
+    let key = kdf_sapling(shared_secret, epk);
+
+    let plaintext = open(key.as_bytes(), enc_ciphertext)?;
+
+    parse_note_plaintext(&plaintext, epk, cmu, |diversifier| diversifier.g_d().map(|g_d| g_d * ivk.0))
+}
+
+pub fn recover_note_ciphertext(
+    ovk: &OutgoingViewingKey,
+    cv: &jubjub::ExtendedPoint,
+    cmu: &bls12_381::Scalar,
+    epk: &jubjub::ExtendedPoint,
+    enc_ciphertext: &[u8],
+    out_ciphertext: &[u8; OUT_CIPHERTEXT_SIZE],
+    memo_size: usize
+) -> Result<DecryptedNote, SaplingError> {
+    let ock = prf_ock(ovk, cv, cmu, epk);
+    let ock: &[u8; 32] = ock.as_ref().try_into().unwrap();
+
+    recover_note_ciphertext_with_ock(ock, cmu, epk, enc_ciphertext, out_ciphertext, memo_size)
+}
+
+// Only `ock` of a single output has to be revealed to let someone else recover it, not the `ovk` it was derived from.
+pub fn recover_note_ciphertext_with_ock(
+    ock: &[u8; 32],
+    cmu: &bls12_381::Scalar,
+    epk: &jubjub::ExtendedPoint,
+    enc_ciphertext: &[u8],
+    out_ciphertext: &[u8; OUT_CIPHERTEXT_SIZE],
+    memo_size: usize
+) -> Result<DecryptedNote, SaplingError> {
+    assert_ciphertext_length(enc_ciphertext, memo_size).map_err(SaplingError::caused_by)?;
+
+    let out_plaintext = open(ock, out_ciphertext)?;
+
+    let pk_d: [u8; 32] = out_plaintext[0..32].try_into().unwrap();
+    let pk_d = ct_unwrap(jubjub::SubgroupPoint::from_bytes(&pk_d))
+        .ok_or(NoteEncryptionError::InvalidPlaintext)
+        .map_err(SaplingError::caused_by)?;
+
+    let esk: [u8; 32] = out_plaintext[32..OUT_PLAINTEXT_SIZE].try_into().unwrap();
+    let esk = jubjub::Scalar::from_repr(esk)
+        .ok_or(NoteEncryptionError::InvalidPlaintext)
+        .map_err(SaplingError::caused_by)?;
+
+    let shared_secret = sapling_ka_agree(&esk, &pk_d.into());
+    let key = kdf_sapling(shared_secret, epk);
+
+    let plaintext = open(key.as_bytes(), enc_ciphertext)?;
+    let decrypted = parse_note_plaintext(&plaintext, epk, cmu, |_| Some(pk_d))?;
+
+    assert_value_or_error((decrypted.note.g_d * esk).to_bytes() == epk.to_bytes(), NoteEncryptionError::InvalidPlaintext)
+        .map_err(SaplingError::caused_by)?;
+
+    Ok(decrypted)
+}
+
+pub(super) fn kdf_sapling(dhsecret: jubjub::SubgroupPoint, epk: &jubjub::ExtendedPoint) -> Blake2bHash {
+    Blake2bParams::new()
+        .hash_length(32)
+        .personal(KDF_SAPLING_PERSONALIZATION)
+        .to_state()
+        .update(&dhsecret.to_bytes())
+        .update(&epk.to_bytes())
+        .finalize()
+}
+
+fn open(key: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, SaplingError> {
+    let mut plaintext = vec![0u8; ciphertext.len()];
+    let len = ChachaPolyIetf::aead_cipher()
+        .open_to(&mut plaintext, ciphertext, &[], key, &[0u8; 12])
+        .map_err(|_| NoteEncryptionError::DecryptionFailed)
+        .map_err(SaplingError::caused_by)?;
+    plaintext.truncate(len);
+
+    Ok(plaintext)
+}
+
+fn parse_note_plaintext<F>(
+    plaintext: &[u8],
+    epk: &jubjub::ExtendedPoint,
+    cmu: &bls12_381::Scalar,
+    get_pk_d: F
+) -> Result<DecryptedNote, SaplingError>
+    where F: FnOnce(&Diversifier) -> Option<jubjub::SubgroupPoint> {
+
+    let invalid_plaintext = || SaplingError::caused_by(NoteEncryptionError::InvalidPlaintext);
+
+    let diversifier = Diversifier(plaintext[1..12].try_into().unwrap());
+    let value = u64::from_le_bytes(plaintext[12..20].try_into().unwrap());
+    let r: [u8; 32] = plaintext[20..COMPACT_NOTE_SIZE].try_into().unwrap();
+
+    let rseed = match plaintext[0] {
+        0x01 => jubjub::Scalar::from_repr(r).map(Rseed::BeforeZip212).ok_or_else(invalid_plaintext)?,
+        0x02 => Rseed::AfterZip212(r),
+        _ => return Err(invalid_plaintext()),
+    };
+
+    let pk_d = get_pk_d(&diversifier).ok_or_else(invalid_plaintext)?;
+    let address = PaymentAddress::from_parts(diversifier, pk_d).ok_or_else(invalid_plaintext)?;
+    let note = address.create_note(value, rseed).ok_or_else(invalid_plaintext)?;
+
+    assert_value_or_error(note.cmu() == *cmu, NoteEncryptionError::CommitmentMismatch).map_err(SaplingError::caused_by)?;
+
+    if let Some(derived_esk) = note.derive_esk() {
+        assert_value_or_error((note.g_d * derived_esk).to_bytes() == epk.to_bytes(), invalid_plaintext())?;
+    }
+
+    Ok(DecryptedNote { note, address, memo: plaintext[COMPACT_NOTE_SIZE..].to_vec() })
+}
+
+fn get_leadbyte(rseed: &Rseed) -> u8 {
+    match rseed {
+        Rseed::BeforeZip212(_) => 0x01,
+        Rseed::AfterZip212(_) => 0x02,
+    }
+}
+
+fn get_rseed_bytes(rseed: &Rseed) -> [u8; 32] {
+    match rseed {
+        Rseed::BeforeZip212(rcm) => rcm.to_repr(),
+        Rseed::AfterZip212(rseed) => *rseed,
+    }
+}
+
+fn assert_ciphertext_length(ciphertext: &[u8], memo_size: usize) -> Result<(), NoteEncryptionError> {
+    let expected = enc_ciphertext_size(memo_size);
+    assert_value_or_error(ciphertext.len() == expected, NoteEncryptionError::InvalidCiphertextLength(expected, ciphertext.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+    use zcash_primitives::memo::MemoBytes;
+    use zcash_primitives::note_encryption::SaplingNoteEncryption;
+    use zcash_primitives::zip32::{ExtendedFullViewingKey, ExtendedSpendingKey};
+
+    use crate::key::SaplingKey;
+    use crate::transaction::create_note;
+    use crate::transaction::memo::DEFAULT_MEMO_SIZE;
+    use crate::transaction::rand::rand_scalar;
+
+    use super::*;
+
+    const SEED: [u8; 32] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+        17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31];
+
+    fn prepare_recipient() -> (ExtendedFullViewingKey, PaymentAddress) {
+        let xsk = ExtendedSpendingKey::from_seed(&SEED, "m/").unwrap();
+        let xfvk = ExtendedFullViewingKey::from(&xsk);
+        let (_, address) = xfvk.default_address().unwrap();
+
+        (xfvk, address)
+    }
+
+    #[test]
+    fn encrypts_note_like_sapling_note_encryption_with_default_memo_size() {
+        let (xfvk, address) = prepare_recipient();
+        let note = create_note(&address, 1000, rand_scalar()).unwrap();
+        let memo = [1u8, 2, 3];
+
+        let encryptor = SaplingNoteEncryption::new(
+            Some(xfvk.fvk.ovk),
+            note.clone(),
+            address.clone(),
+            MemoBytes::from_bytes(&memo).unwrap(),
+            OsRng
+        );
+
+        let actual = encrypt_note_plaintext(&note, &address, encryptor.esk(), Some(&memo), DEFAULT_MEMO_SIZE).unwrap();
+        let expected = encryptor.encrypt_note_plaintext();
+
+        assert_eq!(actual, expected.to_vec());
+    }
+
+    #[test]
+    fn decrypts_note_with_custom_memo_size() {
+        let (xfvk, address) = prepare_recipient();
+        let ivk = xfvk.fvk.vk.ivk();
+
+        let test_data = [
+            (Some(vec![1u8, 2, 3]), 8, vec![1u8, 2, 3, 0, 0, 0, 0, 0]),
+            (None, 8, vec![0xF6, 0, 0, 0, 0, 0, 0, 0]),
+            (None, 0, vec![]),
+            (Some(vec![4u8, 5]), DEFAULT_MEMO_SIZE, [vec![4u8, 5], vec![0; DEFAULT_MEMO_SIZE - 2]].concat()),
+        ];
+
+        for (memo, memo_size, expected_memo) in test_data.iter() {
+            let note = create_note(&address, 1000, rand_scalar()).unwrap();
+            let esk = rand_scalar();
+            let epk: jubjub::ExtendedPoint = (note.g_d * esk).into();
+            let cmu = note.cmu();
+
+            let ciphertext = encrypt_note_plaintext(&note, &address, &esk, memo.as_deref(), *memo_size).unwrap();
+            assert_eq!(ciphertext.len(), enc_ciphertext_size(*memo_size));
+
+            let decrypted = decrypt_note_ciphertext(&ivk, &epk, &cmu, &ciphertext, *memo_size).unwrap();
+
+            assert_eq!(decrypted.note, note);
+            assert_eq!(decrypted.address, address);
+            assert_eq!(&decrypted.memo, expected_memo);
+        }
+    }
+
+    #[test]
+    fn recovers_note_with_ovk() {
+        let (xfvk, address) = prepare_recipient();
+        let note = create_note(&address, 1000, rand_scalar()).unwrap();
+        let esk = rand_scalar();
+        let epk: jubjub::ExtendedPoint = (note.g_d * esk).into();
+        let cv: jubjub::ExtendedPoint = (note.g_d * rand_scalar()).into();
+        let cmu = note.cmu();
+
+        let enc_ciphertext = encrypt_note_plaintext(&note, &address, &esk, Some(&[9]), 16).unwrap();
+        let out_ciphertext = encrypt_outgoing_plaintext(&xfvk.fvk.ovk, &note, &esk, &cv, &cmu).unwrap();
+
+        let recovered = recover_note_ciphertext(&xfvk.fvk.ovk, &cv, &cmu, &epk, &enc_ciphertext, &out_ciphertext, 16).unwrap();
+
+        assert_eq!(recovered.note, note);
+        assert_eq!(recovered.address, address);
+        assert_eq!(recovered.memo[0], 9);
+    }
+
+    #[test]
+    fn fails_to_encrypt_note_if_memo_exceeds_memo_size() {
+        let (_, address) = prepare_recipient();
+        let note = create_note(&address, 1000, rand_scalar()).unwrap();
+
+        let error = encrypt_note_plaintext(&note, &address, &rand_scalar(), Some(&[0u8; 9]), 8).unwrap_err();
+
+        assert_eq!(error, SaplingError::caused_by(crate::transaction::errors::MemoError::TooLong(9, 8)));
+    }
+
+    #[test]
+    fn fails_to_decrypt_note_if_memo_size_does_not_match() {
+        let (xfvk, address) = prepare_recipient();
+        let note = create_note(&address, 1000, rand_scalar()).unwrap();
+        let esk = rand_scalar();
+        let epk: jubjub::ExtendedPoint = (note.g_d * esk).into();
+
+        let ciphertext = encrypt_note_plaintext(&note, &address, &esk, None, 8).unwrap();
+        let error = decrypt_note_ciphertext(&xfvk.fvk.vk.ivk(), &epk, &note.cmu(), &ciphertext, 16).unwrap_err();
+
+        assert_eq!(error, SaplingError::caused_by(NoteEncryptionError::InvalidCiphertextLength(84, 76)));
+    }
+
+    #[test]
+    fn serializes_and_deserializes_decrypted_note() {
+        let (_, address) = prepare_recipient();
+        let notes = [
+            create_note(&address, 1000, rand_scalar()).unwrap(),
+            address.create_note(1000, Rseed::AfterZip212([7u8; 32])).unwrap(),
+        ];
+
+        for note in notes.iter() {
+            let expected = DecryptedNote { note: note.clone(), address: address.clone(), memo: vec![1, 2, 3] };
+
+            let bytes = expected.serialize().unwrap();
+            let actual = DecryptedNote::deserialize(bytes).unwrap();
+
+            assert_eq!(actual.note, expected.note);
+            assert_eq!(actual.address, expected.address);
+            assert_eq!(actual.memo, expected.memo);
+        }
+
+        let mut bytes = DecryptedNote { note: notes[0].clone(), address, memo: vec![] }.serialize().unwrap();
+        bytes[51] = 0x03;
+        assert_eq!(DecryptedNote::deserialize(bytes).unwrap_err(), SaplingError::caused_by(NoteEncryptionError::InvalidPlaintext));
+    }
+}
@@ -9,22 +9,25 @@ use group::GroupEncoding;
 use rand_core::OsRng;
 use zcash_primitives::keys::OutgoingViewingKey;
 use zcash_primitives::memo::MemoBytes;
-use zcash_primitives::note_encryption::SaplingNoteEncryption;
+use zcash_primitives::note_encryption::{ENC_CIPHERTEXT_SIZE, OUT_CIPHERTEXT_SIZE, SaplingNoteEncryption};
 use zcash_primitives::primitives::{Diversifier, Note, PaymentAddress};
 use zcash_primitives::transaction::components::{GROTH_PROOF_SIZE, OutputDescription};
-use zcash_proofs::sapling::SaplingProvingContext;
 
 use crate::common::errors::{CausedBy, SaplingError};
 use crate::common::traits::Serializable;
+use crate::common::utils::deserialize_utils::read_all;
+use crate::transaction::memo::DEFAULT_MEMO_SIZE;
 use crate::transaction::note::create_note;
+use crate::transaction::note_encryption::{enc_ciphertext_size, encrypt_note_plaintext, encrypt_outgoing_plaintext};
 use crate::transaction::output::errors::OutputDescriptionError;
 use crate::transaction::output::OutputDetails;
 use crate::transaction::output::proof::create_output_proof;
-use crate::transaction::proof::prepare_zkproof;
+use crate::transaction::proof::{prepare_zkproof, ProvingContext};
+use crate::transaction::rand::rand_scalar;
 
 impl Serializable<Vec<u8>, SaplingError> for OutputDescription {
     fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
-        OutputDescription::read(&mut &serialized[..]).map_err(OutputDescriptionError::ReadFailed).map_err(SaplingError::caused_by)
+        read_all("output description", &serialized, |reader| OutputDescription::read(reader)).map_err(OutputDescriptionError::ReadFailed).map_err(SaplingError::caused_by)
     }
 
     fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
@@ -89,7 +92,99 @@ impl PartialOutputDescription {
 
 impl Serializable<Vec<u8>, SaplingError> for PartialOutputDescription {
     fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
-        PartialOutputDescription::read(&mut &serialized[..]).map_err(OutputDescriptionError::ReadFailed).map_err(SaplingError::caused_by)
+        read_all("output description", &serialized, |reader| PartialOutputDescription::read(reader)).map_err(OutputDescriptionError::ReadFailed).map_err(SaplingError::caused_by)
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
+        let mut bytes: Vec<u8> = vec![];
+        self.write(&mut bytes).map_err(OutputDescriptionError::WriteFailed).map_err(SaplingError::caused_by)?;
+
+        Ok(bytes)
+    }
+}
+
+// An [`OutputDescription`] whose `enc_ciphertext` carries a memo of a contract specific size instead of the fixed 512 bytes.
+pub struct SizedOutputDescription {
+    pub cv: jubjub::ExtendedPoint,
+    pub cmu: bls12_381::Scalar,
+    pub ephemeral_key: jubjub::ExtendedPoint,
+    pub enc_ciphertext: Vec<u8>,
+    pub out_ciphertext: [u8; OUT_CIPHERTEXT_SIZE],
+    pub zkproof: [u8; GROTH_PROOF_SIZE],
+}
+
+// Based on [`OutputDescription`](https://github.com/zcash/librustzcash/blob/master/zcash_primitives/src/transaction/components.rs#L369)
+impl SizedOutputDescription {
+    pub fn read<R: Read>(reader: &mut R, memo_size: usize) -> io::Result<Self> {
+        let cv = {
+            let mut bytes = [0u8; 32];
+            reader.read_exact(&mut bytes)?;
+            let cv = jubjub::ExtendedPoint::from_bytes(&bytes);
+            if cv.is_none().into() {
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, "invalid cv"));
+            }
+            cv.unwrap()
+        };
+
+        let cmu = {
+            let mut f = [0u8; 32];
+            reader.read_exact(&mut f)?;
+            bls12_381::Scalar::from_repr(f)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "cmu not in field"))?
+        };
+
+        let ephemeral_key = {
+            let mut bytes = [0u8; 32];
+            reader.read_exact(&mut bytes)?;
+            let ephemeral_key = jubjub::ExtendedPoint::from_bytes(&bytes);
+            if ephemeral_key.is_none().into() {
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, "invalid ephemeral_key"));
+            }
+            ephemeral_key.unwrap()
+        };
+
+        let mut enc_ciphertext = vec![0u8; enc_ciphertext_size(memo_size)];
+        let mut out_ciphertext = [0u8; OUT_CIPHERTEXT_SIZE];
+        reader.read_exact(&mut enc_ciphertext)?;
+        reader.read_exact(&mut out_ciphertext)?;
+
+        let mut zkproof = [0u8; GROTH_PROOF_SIZE];
+        reader.read_exact(&mut zkproof)?;
+
+        Ok(SizedOutputDescription {
+            cv,
+            cmu,
+            ephemeral_key,
+            enc_ciphertext,
+            out_ciphertext,
+            zkproof,
+        })
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(&self.cv.to_bytes())?;
+        writer.write_all(self.cmu.to_repr().as_ref())?;
+        writer.write_all(&self.ephemeral_key.to_bytes())?;
+        writer.write_all(&self.enc_ciphertext)?;
+        writer.write_all(&self.out_ciphertext)?;
+        writer.write_all(&self.zkproof)
+    }
+
+    pub fn memo_size(&self) -> usize {
+        self.enc_ciphertext.len().saturating_sub(enc_ciphertext_size(0))
+    }
+}
+
+// The memo size is not encoded, it's derived from the length of the serialized description.
+impl Serializable<Vec<u8>, SaplingError> for SizedOutputDescription {
+    fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
+        let fixed_size = 32 + 32 + 32 + enc_ciphertext_size(0) + OUT_CIPHERTEXT_SIZE + GROTH_PROOF_SIZE;
+        let memo_size = serialized.len().checked_sub(fixed_size)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "output description is too short"))
+            .map_err(OutputDescriptionError::ReadFailed)
+            .map_err(SaplingError::caused_by)?;
+
+        SizedOutputDescription::read(&mut &serialized[..], memo_size).map_err(OutputDescriptionError::ReadFailed).map_err(SaplingError::caused_by)
     }
 
     fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
@@ -101,7 +196,7 @@ impl Serializable<Vec<u8>, SaplingError> for PartialOutputDescription {
 }
 
 pub fn prepare_output_description(
-    ctx: &mut SaplingProvingContext,
+    ctx: &mut impl ProvingContext,
     ovk: OutgoingViewingKey,
     output_details: OutputDetails,
     rcm: jubjub::Scalar,
@@ -134,8 +229,80 @@ pub fn prepare_output_description(
     Ok(output_description)
 }
 
+// For an `esk` that can be derived again, e.g. with `derive_rand_scalar`.
+pub fn prepare_output_description_with_esk(
+    ctx: &mut impl ProvingContext,
+    ovk: OutgoingViewingKey,
+    output_details: OutputDetails,
+    rcm: jubjub::Scalar,
+    esk: jubjub::Scalar,
+    memo: Option<&[u8]>,
+    proving_key: &Parameters<Bls12>
+) -> Result<OutputDescription, SaplingError> {
+    let output_description = prepare_sized_output_description_with_esk(ctx, ovk, output_details, rcm, esk, memo, DEFAULT_MEMO_SIZE, proving_key)?;
+    let enc_ciphertext: [u8; ENC_CIPHERTEXT_SIZE] = output_description.enc_ciphertext.try_into().map_err(|_| SaplingError::new())?;
+
+    Ok(OutputDescription {
+        cv: output_description.cv,
+        cmu: output_description.cmu,
+        ephemeral_key: output_description.ephemeral_key,
+        enc_ciphertext,
+        out_ciphertext: output_description.out_ciphertext,
+        zkproof: output_description.zkproof,
+    })
+}
+
+pub fn prepare_sized_output_description(
+    ctx: &mut impl ProvingContext,
+    ovk: OutgoingViewingKey,
+    output_details: OutputDetails,
+    rcm: jubjub::Scalar,
+    memo: Option<&[u8]>,
+    memo_size: usize,
+    proving_key: &Parameters<Bls12>
+) -> Result<SizedOutputDescription, SaplingError> {
+    prepare_sized_output_description_with_esk(ctx, ovk, output_details, rcm, rand_scalar(), memo, memo_size, proving_key)
+}
+
+// For an `esk` that can be derived again, e.g. with `derive_rand_scalar`.
+#[allow(clippy::too_many_arguments)]
+pub fn prepare_sized_output_description_with_esk(
+    ctx: &mut impl ProvingContext,
+    ovk: OutgoingViewingKey,
+    output_details: OutputDetails,
+    rcm: jubjub::Scalar,
+    esk: jubjub::Scalar,
+    memo: Option<&[u8]>,
+    memo_size: usize,
+    proving_key: &Parameters<Bls12>
+) -> Result<SizedOutputDescription, SaplingError> {
+    let to_address = output_details.to_address.clone();
+    let note = create_note(&to_address, output_details.value, rcm)?;
+
+    let enc_ciphertext = encrypt_note_plaintext(&note, &to_address, &esk, memo, memo_size)?;
+
+    let (proof, cv) = create_output_proof(ctx, output_details, esk, rcm, proving_key);
+    let cmu = note.cmu();
+    let ephemeral_key = derive_epk(*to_address.diversifier(), esk)?.into();
+
+    let out_ciphertext = encrypt_outgoing_plaintext(&ovk, &note, &esk, &cv, &cmu)?;
+
+    let zkproof = prepare_zkproof(proof)?;
+
+    let output_description = SizedOutputDescription {
+        cv,
+        cmu,
+        ephemeral_key,
+        enc_ciphertext,
+        out_ciphertext,
+        zkproof,
+    };
+
+    Ok(output_description)
+}
+
 pub fn prepare_partial_output_description(
-    ctx: &mut SaplingProvingContext,
+    ctx: &mut impl ProvingContext,
     output_details: OutputDetails,
     rcm: jubjub::Scalar,
     esk: jubjub::Scalar,
@@ -186,4 +353,64 @@ fn create_encryptor(ovk: OutgoingViewingKey, note: &Note, to: &PaymentAddress, m
 
 fn get_epk(encryptor: &SaplingNoteEncryption<OsRng>) -> Result<jubjub::ExtendedPoint, SaplingError> {
     encryptor.epk().clone().try_into().map_err(|_| SaplingError::new())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use zcash_primitives::zip32::{ExtendedFullViewingKey, ExtendedSpendingKey};
+
+    use crate::key::SaplingKey;
+    use crate::transaction::note_encryption::{decrypt_note_ciphertext, recover_note_ciphertext};
+    use crate::transaction::proof::tests::{dummy_params, UnprovenContext};
+    use crate::transaction::rand::{derive_rand_scalar, DerivedRandKind};
+
+    use super::*;
+
+    #[test]
+    fn recovers_output_prepared_with_derived_randomness() {
+        let sender = ExtendedSpendingKey::from_seed(&[0u8; 32], "m/").unwrap();
+        let recipient = ExtendedFullViewingKey::from(&ExtendedSpendingKey::from_seed(&[9u8; 32], "m/").unwrap());
+        let (_, address) = recipient.default_address().unwrap();
+
+        let rcm = derive_rand_scalar(&sender, DerivedRandKind::Rcm, b"context", 0);
+        let esk = derive_rand_scalar(&sender, DerivedRandKind::Esk, b"context", 0);
+
+        let output = prepare_sized_output_description_with_esk(
+            &mut UnprovenContext,
+            sender.expsk.ovk,
+            OutputDetails { to_address: address.clone(), value: 1000 },
+            rcm,
+            esk,
+            Some(&[1, 2, 3]),
+            DEFAULT_MEMO_SIZE,
+            &dummy_params().output_params,
+        ).unwrap();
+
+        let epk: jubjub::ExtendedPoint = derive_epk(*address.diversifier(), esk).unwrap().into();
+        assert_eq!(output.ephemeral_key, epk);
+
+        let recovered = recover_note_ciphertext(
+            &sender.expsk.ovk,
+            &output.cv,
+            &output.cmu,
+            &output.ephemeral_key,
+            &output.enc_ciphertext,
+            &output.out_ciphertext,
+            DEFAULT_MEMO_SIZE,
+        ).unwrap();
+        let decrypted = decrypt_note_ciphertext(
+            &recipient.fvk.vk.ivk(),
+            &output.ephemeral_key,
+            &output.cmu,
+            &output.enc_ciphertext,
+            DEFAULT_MEMO_SIZE,
+        ).unwrap();
+
+        for note in [recovered, decrypted].iter() {
+            assert_eq!(note.note.rcm(), rcm);
+            assert_eq!(note.note.value, 1000);
+            assert_eq!(note.address, address);
+            assert_eq!(note.memo[..3], [1, 2, 3]);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-pub use description::{derive_epk, PartialOutputDescription, prepare_output_description, prepare_partial_output_description};
+pub use description::{derive_epk, PartialOutputDescription, prepare_output_description, prepare_output_description_with_esk, prepare_partial_output_description, prepare_sized_output_description, prepare_sized_output_description_with_esk, SizedOutputDescription};
 pub use proof::OutputDetails;
 
 mod description;
@@ -1,7 +1,8 @@
 use bellman::groth16::{Parameters, Proof};
 use bls12_381::Bls12;
 use zcash_primitives::primitives::PaymentAddress;
-use zcash_proofs::sapling::SaplingProvingContext;
+
+use crate::transaction::proof::ProvingContext;
 
 pub struct OutputDetails {
     pub to_address: PaymentAddress,
@@ -9,7 +10,7 @@ pub struct OutputDetails {
 }
 
 pub fn create_output_proof(
-    ctx: &mut SaplingProvingContext,
+    ctx: &mut impl ProvingContext,
     output_details: OutputDetails,
     esk: jubjub::Scalar,
     rcm: jubjub::Scalar,
@@ -0,0 +1,319 @@
+use std::convert::TryInto;
+
+use blake2b_simd::Params as Blake2bParams;
+use ff::PrimeField;
+use group::GroupEncoding;
+use zcash_primitives::keys::OutgoingViewingKey;
+use zcash_primitives::note_encryption::prf_ock;
+use zcash_primitives::redjubjub::{PublicKey, Signature};
+
+use crate::common::errors::{CausedBy, DeserializationError, SaplingError};
+use crate::common::traits::Serializable;
+use crate::common::utils::assert_utils::assert_value_or_error;
+use crate::common::utils::deserialize_utils::read_chunks;
+use crate::transaction::errors::PaymentDisclosureError;
+use crate::transaction::note_encryption::{DecryptedNote, recover_note_ciphertext_with_ock};
+use crate::transaction::output::SizedOutputDescription;
+use crate::transaction::spend::{SpendAuthSigner, verify_spend_auth_sig};
+use crate::transaction::value_commitment::compute_value_commitment;
+
+const PAYMENT_DISCLOSURE_PERSONALIZATION: &[u8; 16] = b"AirGapSaplingDis";
+const PAYMENT_DISCLOSURE_FIXED_SIZE: usize = 32 + // rk
+    32 + // ock
+    64 + // signature
+    1; // rcv flag
+
+// Reveals the outgoing cipher key of a single output, which is enough to recover its note and memo
+// without the `ovk` it was derived from. The disclosure is signed with the randomized spend authorizing key
+// of a spend of the same transaction (`rk`), so only the sender can create it.
+// A transaction without spends, e.g. a Tezos shielding operation, has no `rk` to sign with and can't be disclosed.
+// `rcv` is optional, the sender only knows it if it was kept when the output was created, it can't be recovered from `ovk`.
+pub struct PaymentDisclosure {
+    pub rk: PublicKey,
+    pub ock: [u8; 32],
+    pub rcv: Option<jubjub::Scalar>,
+    pub message: Vec<u8>,
+    pub signature: Signature,
+}
+
+// `rk` and `ar` have to belong to one of the spend descriptions of the transaction that created `output`.
+pub fn create_payment_disclosure<S: SpendAuthSigner + ?Sized>(
+    signer: &S,
+    ar: jubjub::Scalar,
+    rk: PublicKey,
+    ovk: &OutgoingViewingKey,
+    output: &SizedOutputDescription,
+    rcv: Option<jubjub::Scalar>,
+    message: &[u8],
+) -> Result<PaymentDisclosure, SaplingError> {
+    let ock = prf_ock(ovk, &output.cv, &output.cmu, &output.ephemeral_key);
+    let ock: [u8; 32] = ock.as_ref().try_into().unwrap();
+    let decrypted = recover_output(&ock, output)?;
+    assert_value_commitment(&decrypted, rcv, output)?;
+
+    let signature = signer.sign_spend_auth(ar, disclosure_hash(&ock, rcv, output, message))?;
+    let disclosure = PaymentDisclosure { rk, ock, rcv, message: message.to_vec(), signature };
+    assert_signature(&disclosure, output)?;
+
+    Ok(disclosure)
+}
+
+// `spend_rks` are the randomized keys of the spend descriptions of the transaction that created `output`.
+// The recovered note is checked against `cmu` (and `epk`), and against `cv` if the disclosure carries `rcv`.
+// Without `rcv` the value is only bound to `cv` by the output proof, so `output` must be taken from a transaction
+// that has been verified (e.g. applied on chain), not from the party presenting the disclosure.
+// `cv` is signed along with the rest of the output, so the disclosure can't be moved to another output.
+pub fn verify_payment_disclosure(
+    disclosure: &PaymentDisclosure,
+    output: &SizedOutputDescription,
+    spend_rks: &[PublicKey],
+) -> Result<DecryptedNote, SaplingError> {
+    let rk = disclosure.rk.0.to_bytes();
+    assert_value_or_error(spend_rks.iter().any(|spend_rk| spend_rk.0.to_bytes() == rk), PaymentDisclosureError::UnknownSpendKey)
+        .map_err(SaplingError::caused_by)?;
+    assert_signature(disclosure, output)?;
+
+    let decrypted = recover_output(&disclosure.ock, output)?;
+    assert_value_commitment(&decrypted, disclosure.rcv, output)?;
+
+    Ok(decrypted)
+}
+
+// Concatenated 32 byte `rk`s, as passed through the bindings.
+pub fn spend_rks_from_bytes(bytes: &[u8]) -> Result<Vec<PublicKey>, SaplingError> {
+    read_chunks("spend rks", bytes, |chunk| jubjub::ExtendedPoint::deserialize(chunk.to_vec()).map(PublicKey))
+}
+
+fn recover_output(ock: &[u8; 32], output: &SizedOutputDescription) -> Result<DecryptedNote, SaplingError> {
+    recover_note_ciphertext_with_ock(
+        ock,
+        &output.cmu,
+        &output.ephemeral_key,
+        &output.enc_ciphertext,
+        &output.out_ciphertext,
+        output.memo_size(),
+    )
+}
+
+// cv = value * G_v + rcv * G_r
+fn assert_value_commitment(decrypted: &DecryptedNote, rcv: Option<jubjub::Scalar>, output: &SizedOutputDescription) -> Result<(), SaplingError> {
+    match rcv {
+        Some(rcv) => assert_value_or_error(
+            compute_value_commitment(decrypted.note.value, rcv) == output.cv,
+            PaymentDisclosureError::ValueCommitmentMismatch,
+        ).map_err(SaplingError::caused_by),
+        None => Ok(()),
+    }
+}
+
+fn assert_signature(disclosure: &PaymentDisclosure, output: &SizedOutputDescription) -> Result<(), SaplingError> {
+    let hash = disclosure_hash(&disclosure.ock, disclosure.rcv, output, &disclosure.message);
+
+    assert_value_or_error(verify_spend_auth_sig(&disclosure.rk, hash, &disclosure.signature), PaymentDisclosureError::InvalidSignature)
+        .map_err(SaplingError::caused_by)
+}
+
+// BLAKE2b-256("AirGapSaplingDis", cv || cmu || epk || ock || rcv flag || [rcv] || message), signed in place of the sighash.
+fn disclosure_hash(ock: &[u8; 32], rcv: Option<jubjub::Scalar>, output: &SizedOutputDescription, message: &[u8]) -> [u8; 32] {
+    let hash = Blake2bParams::new()
+        .hash_length(32)
+        .personal(PAYMENT_DISCLOSURE_PERSONALIZATION)
+        .to_state()
+        .update(&output.cv.to_bytes())
+        .update(output.cmu.to_repr().as_ref())
+        .update(&output.ephemeral_key.to_bytes())
+        .update(ock)
+        .update(&rcv_bytes(rcv))
+        .update(message)
+        .finalize();
+
+    hash.as_bytes().try_into().unwrap()
+}
+
+// rcv flag (1 byte, 0x00 or 0x01) || rcv (32 bytes, if the flag is set)
+fn rcv_bytes(rcv: Option<jubjub::Scalar>) -> Vec<u8> {
+    match rcv {
+        Some(rcv) => [&[0x01], rcv.to_bytes().as_ref()].concat(),
+        None => vec![0x00],
+    }
+}
+
+// rk (32 bytes) || ock (32 bytes) || signature (64 bytes) || rcv flag (1 byte) || [rcv (32 bytes)] || message
+impl Serializable<Vec<u8>, SaplingError> for PaymentDisclosure {
+    fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
+        assert_value_or_error(
+            serialized.len() >= PAYMENT_DISCLOSURE_FIXED_SIZE,
+            DeserializationError::InvalidLength("payment disclosure", PAYMENT_DISCLOSURE_FIXED_SIZE, serialized.len()),
+        ).map_err(SaplingError::caused_by)?;
+
+        let rk = jubjub::ExtendedPoint::deserialize(serialized[0..32].to_vec())?;
+        let ock = serialized[32..64].try_into().unwrap();
+        let signature = Signature::deserialize(serialized[64..128].to_vec())?;
+        let (rcv, message) = match serialized[128] {
+            0x00 => (None, &serialized[PAYMENT_DISCLOSURE_FIXED_SIZE..]),
+            0x01 => {
+                let end = PAYMENT_DISCLOSURE_FIXED_SIZE + 32;
+                let rcv = serialized.get(PAYMENT_DISCLOSURE_FIXED_SIZE..end)
+                    .ok_or(DeserializationError::InvalidLength("payment disclosure", end, serialized.len()))
+                    .map_err(SaplingError::caused_by)?;
+
+                (Some(jubjub::Scalar::deserialize(rcv.to_vec())?), &serialized[end..])
+            },
+            flag => return Err(SaplingError::caused_by(PaymentDisclosureError::InvalidRcvFlag(flag))),
+        };
+
+        Ok(PaymentDisclosure { rk: PublicKey(rk), ock, rcv, message: message.to_vec(), signature })
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
+        let rk = self.rk.0.serialize()?;
+        let signature = self.signature.serialize()?;
+
+        Ok([rk, self.ock.to_vec(), signature, rcv_bytes(self.rcv), self.message.clone()].concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zcash_primitives::constants::SPENDING_KEY_GENERATOR;
+    use zcash_primitives::transaction::components::GROTH_PROOF_SIZE;
+    use zcash_primitives::zip32::{ExtendedFullViewingKey, ExtendedSpendingKey};
+
+    use crate::key::SaplingKey;
+    use crate::transaction::create_note;
+    use crate::transaction::note_encryption::{encrypt_note_plaintext, encrypt_outgoing_plaintext};
+    use crate::transaction::rand::rand_scalar;
+    use crate::transaction::spend::LocalSpendAuthSigner;
+
+    use super::*;
+
+    fn prepare_output(sender: &ExtendedSpendingKey, value: u64, memo: &[u8]) -> SizedOutputDescription {
+        prepare_output_with_rcv(sender, value, memo, rand_scalar())
+    }
+
+    fn prepare_output_with_rcv(sender: &ExtendedSpendingKey, value: u64, memo: &[u8], rcv: jubjub::Scalar) -> SizedOutputDescription {
+        let recipient = ExtendedFullViewingKey::from(&ExtendedSpendingKey::from_seed(&[9u8; 32], "m/").unwrap());
+        let (_, address) = recipient.default_address().unwrap();
+        let note = create_note(&address, value, rand_scalar()).unwrap();
+        let esk = rand_scalar();
+        let cv = compute_value_commitment(value, rcv);
+
+        SizedOutputDescription {
+            cv,
+            cmu: note.cmu(),
+            ephemeral_key: (note.g_d * esk).into(),
+            enc_ciphertext: encrypt_note_plaintext(&note, &address, &esk, Some(memo), 16).unwrap(),
+            out_ciphertext: encrypt_outgoing_plaintext(&sender.expsk.ovk, &note, &esk, &cv, &note.cmu()).unwrap(),
+            zkproof: [0u8; GROTH_PROOF_SIZE],
+        }
+    }
+
+    fn rk(xsk: &ExtendedSpendingKey, ar: jubjub::Scalar) -> PublicKey {
+        PublicKey(xsk.expsk.proof_generation_key().ak.into()).randomize(ar, SPENDING_KEY_GENERATOR)
+    }
+
+    #[test]
+    fn discloses_payment() {
+        let xsk = ExtendedSpendingKey::from_seed(&[0u8; 32], "m/").unwrap();
+        let ar = rand_scalar();
+        let output = prepare_output(&xsk, 1000, &[1, 2, 3]);
+
+        let disclosure = create_payment_disclosure(&xsk, ar, rk(&xsk, ar), &xsk.expsk.ovk, &output, None, b"invoice 42").unwrap();
+        let disclosure = PaymentDisclosure::deserialize(disclosure.serialize().unwrap()).unwrap();
+
+        let decrypted = verify_payment_disclosure(&disclosure, &output, &[rk(&xsk, rand_scalar()), rk(&xsk, ar)]).unwrap();
+
+        assert_eq!(decrypted.note.value, 1000);
+        assert_eq!(decrypted.memo[..3], [1, 2, 3]);
+        assert_eq!(disclosure.message, b"invoice 42");
+    }
+
+    #[test]
+    fn discloses_payment_with_value_commitment_trapdoor() {
+        let xsk = ExtendedSpendingKey::from_seed(&[0u8; 32], "m/").unwrap();
+        let ar = rand_scalar();
+        let rcv = rand_scalar();
+        let output = prepare_output_with_rcv(&xsk, 1000, &[], rcv);
+
+        let disclosure = create_payment_disclosure(&xsk, ar, rk(&xsk, ar), &xsk.expsk.ovk, &output, Some(rcv), b"invoice 42").unwrap();
+        let disclosure = PaymentDisclosure::deserialize(disclosure.serialize().unwrap()).unwrap();
+        assert_eq!(disclosure.rcv, Some(rcv));
+        assert_eq!(disclosure.message, b"invoice 42");
+
+        let decrypted = verify_payment_disclosure(&disclosure, &output, &[rk(&xsk, ar)]).unwrap();
+        assert_eq!(decrypted.note.value, 1000);
+
+        let other_rcv = rand_scalar();
+        let actual = create_payment_disclosure(&xsk, ar, rk(&xsk, ar), &xsk.expsk.ovk, &output, Some(other_rcv), &[]);
+        assert_eq!(actual.err(), Some(SaplingError::caused_by(PaymentDisclosureError::ValueCommitmentMismatch)));
+
+        let mut forged = disclosure;
+        forged.rcv = Some(other_rcv);
+        assert_eq!(
+            verify_payment_disclosure(&forged, &output, &[rk(&xsk, ar)]).err(),
+            Some(SaplingError::caused_by(PaymentDisclosureError::InvalidSignature)),
+        );
+
+        forged.signature = xsk.sign_spend_auth(ar, disclosure_hash(&forged.ock, forged.rcv, &output, &forged.message)).unwrap();
+        assert_eq!(
+            verify_payment_disclosure(&forged, &output, &[rk(&xsk, ar)]).err(),
+            Some(SaplingError::caused_by(PaymentDisclosureError::ValueCommitmentMismatch)),
+        );
+
+        let mut serialized = forged.serialize().unwrap();
+        serialized[128] = 0x02;
+        assert_eq!(
+            PaymentDisclosure::deserialize(serialized).err(),
+            Some(SaplingError::caused_by(PaymentDisclosureError::InvalidRcvFlag(0x02))),
+        );
+    }
+
+    #[test]
+    fn rejects_disclosure_not_bound_to_transaction() {
+        let xsk = ExtendedSpendingKey::from_seed(&[0u8; 32], "m/").unwrap();
+        let ar = rand_scalar();
+        let output = prepare_output(&xsk, 1000, &[]);
+
+        let mut disclosure = create_payment_disclosure(&xsk, ar, rk(&xsk, ar), &xsk.expsk.ovk, &output, None, &[]).unwrap();
+        assert_eq!(
+            verify_payment_disclosure(&disclosure, &output, &[rk(&xsk, rand_scalar())]).err(),
+            Some(SaplingError::caused_by(PaymentDisclosureError::UnknownSpendKey)),
+        );
+
+        let other_output = prepare_output(&xsk, 1000, &[]);
+        assert_eq!(
+            verify_payment_disclosure(&disclosure, &other_output, &[rk(&xsk, ar)]).err(),
+            Some(SaplingError::caused_by(PaymentDisclosureError::InvalidSignature)),
+        );
+
+        let mut modified_output = prepare_output(&xsk, 1000, &[]);
+        modified_output.cmu = output.cmu;
+        modified_output.ephemeral_key = output.ephemeral_key;
+        modified_output.enc_ciphertext = output.enc_ciphertext.clone();
+        modified_output.out_ciphertext = output.out_ciphertext;
+        assert_eq!(
+            verify_payment_disclosure(&disclosure, &modified_output, &[rk(&xsk, ar)]).err(),
+            Some(SaplingError::caused_by(PaymentDisclosureError::InvalidSignature)),
+        );
+
+        disclosure.message = b"forged".to_vec();
+        assert_eq!(
+            verify_payment_disclosure(&disclosure, &output, &[rk(&xsk, ar)]).err(),
+            Some(SaplingError::caused_by(PaymentDisclosureError::InvalidSignature)),
+        );
+    }
+
+    #[test]
+    fn fails_to_create_disclosure_for_other_key() {
+        let xsk = ExtendedSpendingKey::from_seed(&[0u8; 32], "m/").unwrap();
+        let other_xsk = ExtendedSpendingKey::from_seed(&[1u8; 32], "m/").unwrap();
+        let ar = rand_scalar();
+        let output = prepare_output(&xsk, 1000, &[]);
+
+        let other_signer = LocalSpendAuthSigner::from(&other_xsk);
+        let actual = create_payment_disclosure(&other_signer, ar, rk(&xsk, ar), &xsk.expsk.ovk, &output, None, &[]);
+        assert_eq!(actual.err(), Some(SaplingError::caused_by(PaymentDisclosureError::InvalidSignature)));
+
+        assert!(create_payment_disclosure(&xsk, ar, rk(&xsk, ar), &other_xsk.expsk.ovk, &output, None, &[]).is_err());
+    }
+}
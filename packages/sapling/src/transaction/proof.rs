@@ -1,11 +1,88 @@
-use bellman::groth16::Proof;
+use bellman::groth16::{Parameters, PreparedVerifyingKey, Proof};
 use bls12_381::Bls12;
-use zcash_primitives::transaction::components::GROTH_PROOF_SIZE;
+use zcash_primitives::merkle_tree::MerklePath;
+use zcash_primitives::primitives::{Diversifier, PaymentAddress, ProofGenerationKey, Rseed};
+use zcash_primitives::redjubjub::{PublicKey, Signature};
+use zcash_primitives::sapling::Node;
+use zcash_primitives::transaction::components::{Amount, GROTH_PROOF_SIZE};
 use zcash_proofs::{parse_parameters, ZcashParameters};
+use zcash_proofs::sapling::SaplingProvingContext;
 
 use crate::common::errors::{CausedBy, SaplingError};
 use crate::transaction::errors::ProofError;
 
+// Mirrors the API of [`SaplingProvingContext`](https://github.com/zcash/librustzcash/blob/master/zcash_proofs/src/sapling/prover.rs#L21)
+// so descriptions can be proved with any context that accumulates `bsk` and `cv_sum`.
+pub trait ProvingContext {
+    #[allow(clippy::too_many_arguments)]
+    fn spend_proof(
+        &mut self,
+        proof_generation_key: ProofGenerationKey,
+        diversifier: Diversifier,
+        rseed: Rseed,
+        ar: jubjub::Scalar,
+        value: u64,
+        anchor: bls12_381::Scalar,
+        merkle_path: MerklePath<Node>,
+        proving_key: &Parameters<Bls12>,
+        verifying_key: &PreparedVerifyingKey<Bls12>,
+    ) -> Result<(Proof<Bls12>, jubjub::ExtendedPoint, PublicKey), ()>;
+
+    fn output_proof(
+        &mut self,
+        esk: jubjub::Scalar,
+        payment_address: PaymentAddress,
+        rcm: jubjub::Scalar,
+        value: u64,
+        proving_key: &Parameters<Bls12>,
+    ) -> (Proof<Bls12>, jubjub::ExtendedPoint);
+
+    fn binding_sig(&self, value_balance: Amount, sighash: &[u8; 32]) -> Result<Signature, ()>;
+}
+
+impl ProvingContext for SaplingProvingContext {
+    fn spend_proof(
+        &mut self,
+        proof_generation_key: ProofGenerationKey,
+        diversifier: Diversifier,
+        rseed: Rseed,
+        ar: jubjub::Scalar,
+        value: u64,
+        anchor: bls12_381::Scalar,
+        merkle_path: MerklePath<Node>,
+        proving_key: &Parameters<Bls12>,
+        verifying_key: &PreparedVerifyingKey<Bls12>,
+    ) -> Result<(Proof<Bls12>, jubjub::ExtendedPoint, PublicKey), ()> {
+        SaplingProvingContext::spend_proof(
+            self,
+            proof_generation_key,
+            diversifier,
+            rseed,
+            ar,
+            value,
+            anchor,
+            merkle_path,
+            proving_key,
+            verifying_key
+        )
+    }
+
+    fn output_proof(
+        &mut self,
+        esk: jubjub::Scalar,
+        payment_address: PaymentAddress,
+        rcm: jubjub::Scalar,
+        value: u64,
+        proving_key: &Parameters<Bls12>,
+    ) -> (Proof<Bls12>, jubjub::ExtendedPoint) {
+        SaplingProvingContext::output_proof(self, esk, payment_address, rcm, value, proving_key)
+    }
+
+    fn binding_sig(&self, value_balance: Amount, sighash: &[u8; 32]) -> Result<Signature, ()> {
+        SaplingProvingContext::binding_sig(self, value_balance, sighash)
+    }
+}
+
 pub fn prepare_proof_parameters(spend_params: &[u8], output_params: &[u8]) -> ZcashParameters {
     parse_parameters(spend_params, output_params, None)
 }
@@ -18,3 +95,78 @@ pub fn prepare_zkproof(proof: Proof<Bls12>) -> Result<[u8; GROTH_PROOF_SIZE], Sa
 
     Ok(zkproof)
 }
+
+#[cfg(test)]
+pub(super) mod tests {
+    use bellman::groth16::{prepare_verifying_key, VerifyingKey};
+    use bls12_381::{G1Affine, G2Affine};
+    use zcash_primitives::constants::SPENDING_KEY_GENERATOR;
+
+    use super::*;
+
+    // Identity verifying keys no proof can satisfy, there are no real parameters in the tests.
+    pub(crate) fn dummy_params() -> ZcashParameters {
+        let vk = VerifyingKey::<Bls12> {
+            alpha_g1: G1Affine::identity(),
+            beta_g1: G1Affine::identity(),
+            beta_g2: G2Affine::identity(),
+            gamma_g2: G2Affine::identity(),
+            delta_g1: G1Affine::identity(),
+            delta_g2: G2Affine::identity(),
+            ic: vec![],
+        };
+        let params = Parameters {
+            vk: vk.clone(),
+            h: Default::default(),
+            l: Default::default(),
+            a: Default::default(),
+            b_g1: Default::default(),
+            b_g2: Default::default(),
+        };
+
+        ZcashParameters {
+            spend_params: params.clone(),
+            spend_vk: prepare_verifying_key(&vk),
+            output_params: params,
+            output_vk: prepare_verifying_key(&vk),
+            sprout_vk: None,
+        }
+    }
+
+    // Commits to the randomness it is given and outputs an empty proof, so descriptions can be prepared with `dummy_params`.
+    pub(crate) struct UnprovenContext;
+
+    impl ProvingContext for UnprovenContext {
+        fn spend_proof(
+            &mut self,
+            _proof_generation_key: ProofGenerationKey,
+            _diversifier: Diversifier,
+            _rseed: Rseed,
+            _ar: jubjub::Scalar,
+            _value: u64,
+            _anchor: bls12_381::Scalar,
+            _merkle_path: MerklePath<Node>,
+            _proving_key: &Parameters<Bls12>,
+            _verifying_key: &PreparedVerifyingKey<Bls12>,
+        ) -> Result<(Proof<Bls12>, jubjub::ExtendedPoint, PublicKey), ()> {
+            Err(())
+        }
+
+        fn output_proof(
+            &mut self,
+            _esk: jubjub::Scalar,
+            _payment_address: PaymentAddress,
+            rcm: jubjub::Scalar,
+            _value: u64,
+            _proving_key: &Parameters<Bls12>,
+        ) -> (Proof<Bls12>, jubjub::ExtendedPoint) {
+            let proof = Proof { a: G1Affine::identity(), b: G2Affine::identity(), c: G1Affine::identity() };
+
+            (proof, (SPENDING_KEY_GENERATOR * rcm).into())
+        }
+
+        fn binding_sig(&self, _value_balance: Amount, _sighash: &[u8; 32]) -> Result<Signature, ()> {
+            Err(())
+        }
+    }
+}
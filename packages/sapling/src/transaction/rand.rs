@@ -1,10 +1,21 @@
 use std::convert::TryInto;
 
+use blake2b_simd::Params as Blake2bParams;
 use rand_core::{OsRng, RngCore};
 use zcash_primitives::keys::OutgoingViewingKey;
+use zcash_primitives::zip32::ExtendedSpendingKey;
 
 use crate::common::errors::SaplingError;
 
+const DERIVED_RAND_PERSONALIZATION: &[u8; 16] = b"AirGapSaplingRnd";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DerivedRandKind {
+    Rcm = 0,
+    Esk = 1,
+    Ar = 2,
+}
+
 pub fn rand_ovk() -> Result<OutgoingViewingKey, SaplingError> {
     let ovk: [u8; 32] = rand_bytes(32)[..32].try_into().map_err(|_| SaplingError::new())?;
     let ovk = OutgoingViewingKey(ovk);
@@ -31,4 +42,49 @@ pub fn rand_scalar() -> jubjub::Scalar {
 
 pub fn rand_scalar_bytes() -> [u8; 32] {
     rand_scalar().to_bytes()
-}
\ No newline at end of file
+}
+
+// BLAKE2b("AirGapSaplingRnd", expsk || kind || index (4 bytes, LE) || context), reduced to a scalar.
+// `context` should be unique per transaction (e.g. the Tezos anti-replay string and a counter),
+// so the randomness of the notes sent by a wallet can be derived again from its seed.
+pub fn derive_rand_scalar(xsk: &ExtendedSpendingKey, kind: DerivedRandKind, context: &[u8], index: u32) -> jubjub::Scalar {
+    let hash = Blake2bParams::new()
+        .hash_length(64)
+        .personal(DERIVED_RAND_PERSONALIZATION)
+        .to_state()
+        .update(&xsk.expsk.to_bytes())
+        .update(&[kind as u8])
+        .update(&index.to_le_bytes())
+        .update(context)
+        .finalize();
+
+    jubjub::Scalar::from_bytes_wide(hash.as_array())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn derive(seed: u8, kind: DerivedRandKind, context: &[u8], index: u32) -> jubjub::Scalar {
+        derive_rand_scalar(&ExtendedSpendingKey::master(&[seed; 32]), kind, context, index)
+    }
+
+    #[test]
+    fn derives_same_randomness_from_same_inputs() {
+        assert_eq!(derive(1, DerivedRandKind::Rcm, b"context", 0), derive(1, DerivedRandKind::Rcm, b"context", 0));
+        assert_eq!(derive(1, DerivedRandKind::Esk, b"context", 3), derive(1, DerivedRandKind::Esk, b"context", 3));
+        assert_eq!(derive(1, DerivedRandKind::Ar, b"", 7), derive(1, DerivedRandKind::Ar, b"", 7));
+    }
+
+    #[test]
+    fn separates_kinds_keys_contexts_and_indices() {
+        let rcm = derive(1, DerivedRandKind::Rcm, b"context", 0);
+
+        assert_ne!(rcm, derive(1, DerivedRandKind::Esk, b"context", 0));
+        assert_ne!(rcm, derive(1, DerivedRandKind::Ar, b"context", 0));
+        assert_ne!(rcm, derive(2, DerivedRandKind::Rcm, b"context", 0));
+        assert_ne!(rcm, derive(1, DerivedRandKind::Rcm, b"context2", 0));
+        assert_ne!(rcm, derive(1, DerivedRandKind::Rcm, b"context", 1));
+        assert_ne!(rcm, jubjub::Scalar::zero());
+    }
+}
@@ -0,0 +1,456 @@
+use std::collections::{BTreeSet, VecDeque};
+use std::convert::TryInto;
+
+use bellman::groth16::Proof;
+use blake2b_simd::Params as Blake2bParams;
+use bls12_381::Bls12;
+use ff::PrimeField;
+use zcash_primitives::sapling::Node;
+use zcash_primitives::transaction::components::Amount;
+use zcash_proofs::sapling::SaplingVerificationContext;
+use zcash_proofs::ZcashParameters;
+
+use crate::common::errors::{CausedBy, DeserializationError, SaplingError};
+use crate::common::traits::Serializable;
+use crate::common::utils::assert_utils::assert_value_or_error;
+use crate::transaction::errors::SaplingStateError;
+use crate::transaction::frontier::{CommitmentTreeFrontier, node_bytes, nodes_from_bytes};
+use crate::transaction::note_encryption::enc_ciphertext_size;
+use crate::transaction::sapling_transaction::SaplingTransaction;
+use crate::transaction::verify_spend_auth_sig;
+
+// Limits enforced by Tezos to keep the balance from overflowing.
+pub const MAX_SAPLING_INPUTS: usize = 5208;
+pub const MAX_SAPLING_OUTPUTS: usize = 2019;
+
+// Tezos keeps the roots of the last 120 updates so that transactions built against a slightly outdated tree are still accepted.
+pub const SAPLING_ROOTS_HISTORY: usize = 120;
+
+// Local model of the Tezos `sapling_state`, without the commitments and ciphertexts that are only kept for scanning.
+// The roots are ordered oldest first, the last one is always the root of `tree`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SaplingState {
+    memo_size: u16,
+    tree: CommitmentTreeFrontier,
+    roots: VecDeque<Node>,
+    nullifiers: BTreeSet<[u8; 32]>,
+}
+
+// The result of `SAPLING_VERIFY_UPDATE`.
+pub struct SaplingStateUpdate {
+    pub bound_data: Vec<u8>,
+    pub balance: i64,
+    pub state: SaplingState,
+}
+
+impl SaplingState {
+    pub fn empty(memo_size: u16) -> Self {
+        let tree = CommitmentTreeFrontier::empty();
+        let roots = VecDeque::from(vec![tree.root()]);
+
+        SaplingState { memo_size, tree, roots, nullifiers: BTreeSet::new() }
+    }
+
+    pub fn new(memo_size: u16, tree: CommitmentTreeFrontier, roots: Vec<Node>, nullifiers: Vec<[u8; 32]>) -> Result<Self, SaplingError> {
+        assert_value_or_error(!roots.is_empty() && roots.len() <= SAPLING_ROOTS_HISTORY, SaplingStateError::InvalidRootsCount(roots.len()))
+            .map_err(SaplingError::caused_by)?;
+        assert_value_or_error(roots.last() == Some(&tree.root()), SaplingStateError::RootMismatch).map_err(SaplingError::caused_by)?;
+
+        Ok(SaplingState { memo_size, tree, roots: roots.into(), nullifiers: nullifiers.into_iter().collect() })
+    }
+
+    pub fn memo_size(&self) -> u16 {
+        self.memo_size
+    }
+
+    pub fn tree(&self) -> &CommitmentTreeFrontier {
+        &self.tree
+    }
+
+    pub fn root(&self) -> Node {
+        self.tree.root()
+    }
+
+    pub fn has_root(&self, root: &Node) -> bool {
+        self.roots.contains(root)
+    }
+
+    pub fn is_spent(&self, nullifier: &[u8; 32]) -> bool {
+        self.nullifiers.contains(nullifier)
+    }
+
+    fn apply(&self, nullifiers: BTreeSet<[u8; 32]>, commitments: &[Node]) -> Result<Self, SaplingError> {
+        let mut tree = self.tree.clone();
+        commitments.iter().try_for_each(|commitment| tree.append(*commitment))?;
+
+        let mut roots = self.roots.clone();
+        let root = tree.root();
+        if roots.back() != Some(&root) {
+            roots.push_back(root);
+            if roots.len() > SAPLING_ROOTS_HISTORY {
+                roots.pop_front();
+            }
+        }
+
+        Ok(SaplingState { memo_size: self.memo_size, tree, roots, nullifiers })
+    }
+}
+
+// Mirrors the checks of the Michelson `SAPLING_VERIFY_UPDATE` instruction, where Tezos returns `None` this returns the reason as an error.
+// `anti_replay` is the string Tezos builds from the contract address and the chain id, the signatures are checked against
+// the sighash derived from it, so a transaction can't be replayed on another contract or chain.
+// Unlike on a node, the root of every update is kept in the history, not only the last one of each block.
+pub fn verify_update(
+    state: &SaplingState,
+    transaction: &SaplingTransaction,
+    anti_replay: &[u8],
+    params: &ZcashParameters,
+) -> Result<SaplingStateUpdate, SaplingError> {
+    let spends = &transaction.spend_descriptions;
+    let outputs = &transaction.output_descriptions;
+
+    assert_value_or_error(spends.len() <= MAX_SAPLING_INPUTS, SaplingStateError::TooManyInputs(spends.len())).map_err(SaplingError::caused_by)?;
+    assert_value_or_error(outputs.len() <= MAX_SAPLING_OUTPUTS, SaplingStateError::TooManyOutputs(outputs.len())).map_err(SaplingError::caused_by)?;
+
+    let sighash = compute_sighash(transaction, anti_replay)?;
+
+    let payload_size = enc_ciphertext_size(state.memo_size as usize);
+    if let Some(index) = outputs.iter().position(|output| output.ciphertext.payload_enc.len() != payload_size) {
+        return Err(SaplingError::caused_by(SaplingStateError::MemoSizeMismatch(index, state.memo_size)));
+    }
+
+    let mut nullifiers = state.nullifiers.clone();
+    for (index, spend) in spends.iter().enumerate() {
+        assert_value_or_error(!state.is_spent(&spend.nullifier.0), SaplingStateError::NullifierAlreadySpent(index)).map_err(SaplingError::caused_by)?;
+        assert_value_or_error(nullifiers.insert(spend.nullifier.0), SaplingStateError::DuplicateNullifier(index)).map_err(SaplingError::caused_by)?;
+    }
+
+    let anchor = transaction.root;
+    assert_value_or_error(state.has_root(&Node::new(anchor.to_repr())), SaplingStateError::UnknownAnchor).map_err(SaplingError::caused_by)?;
+
+    let mut ctx = SaplingVerificationContext::new();
+    for (index, spend) in spends.iter().enumerate() {
+        let signed = spend.spend_auth_sig.as_ref().is_some_and(|spend_auth_sig| verify_spend_auth_sig(&spend.rk, sighash, spend_auth_sig));
+        assert_value_or_error(signed, SaplingStateError::InvalidSpendAuthSig(index)).map_err(SaplingError::caused_by)?;
+
+        let verified = match (read_proof(&spend.zkproof), spend.spend_auth_sig) {
+            (Some(proof), Some(spend_auth_sig)) => ctx.check_spend(
+                spend.cv,
+                anchor,
+                &spend.nullifier.0,
+                spend.rk.clone(),
+                &sighash,
+                spend_auth_sig,
+                proof,
+                &params.spend_vk,
+            ),
+            _ => false,
+        };
+        assert_value_or_error(verified, SaplingStateError::InvalidSpend(index)).map_err(SaplingError::caused_by)?;
+    }
+
+    for (index, output) in outputs.iter().enumerate() {
+        let verified = match read_proof(&output.zkproof) {
+            Some(proof) => ctx.check_output(output.ciphertext.cv, output.cmu, output.ciphertext.epk, proof, &params.output_vk),
+            None => false,
+        };
+        assert_value_or_error(verified, SaplingStateError::InvalidOutput(index)).map_err(SaplingError::caused_by)?;
+    }
+
+    let balance = Amount::from_i64(transaction.balance)
+        .map_err(|_| SaplingStateError::BalanceOutOfRange(transaction.balance))
+        .map_err(SaplingError::caused_by)?;
+    assert_value_or_error(ctx.final_check(balance, &sighash, transaction.binding_sig), SaplingStateError::InvalidBindingSig)
+        .map_err(SaplingError::caused_by)?;
+
+    let commitments: Vec<Node> = outputs.iter().map(|output| Node::new(output.cmu.to_repr())).collect();
+
+    Ok(SaplingStateUpdate {
+        bound_data: transaction.bound_data.clone(),
+        balance: transaction.balance,
+        state: state.apply(nullifiers, &commitments)?,
+    })
+}
+
+// BLAKE2b-256 keyed with the anti-replay string over the unsigned spend descriptions, the output descriptions and the bound data.
+pub fn compute_sighash(transaction: &SaplingTransaction, anti_replay: &[u8]) -> Result<[u8; 32], SaplingError> {
+    assert_value_or_error(anti_replay.len() <= blake2b_simd::KEYBYTES, SaplingStateError::InvalidAntiReplayLength(anti_replay.len()))
+        .map_err(SaplingError::caused_by)?;
+
+    let mut data: Vec<u8> = vec![];
+    transaction.write_sighash_data(&mut data)?;
+
+    let hash = Blake2bParams::new()
+        .hash_length(32)
+        .key(anti_replay)
+        .hash(&data);
+
+    Ok(hash.as_bytes().try_into().unwrap())
+}
+
+fn read_proof(zkproof: &[u8]) -> Option<Proof<Bls12>> {
+    Proof::read(zkproof).ok()
+}
+
+// memo size (2 bytes, LE) || roots count (4 bytes, LE) || roots || nullifiers count (4 bytes, LE) || nullifiers || tree frontier
+impl Serializable<Vec<u8>, SaplingError> for SaplingState {
+    fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> where Self: Sized {
+        let mut offset: usize = 0;
+        let mut read = |name: &'static str, len: usize| -> Result<&[u8], SaplingError> {
+            let end = offset.saturating_add(len);
+            let bytes = serialized.get(offset..end)
+                .ok_or(DeserializationError::InvalidLength(name, end, serialized.len()))
+                .map_err(SaplingError::caused_by)?;
+            offset = end;
+
+            Ok(bytes)
+        };
+
+        let memo_size = u16::from_le_bytes(read("sapling state", 2)?.try_into().unwrap());
+        let roots_count = u32::from_le_bytes(read("sapling state", 4)?.try_into().unwrap()) as usize;
+        let roots = nodes_from_bytes(read("sapling state roots", roots_count.saturating_mul(32))?)?;
+        let nullifiers_count = u32::from_le_bytes(read("sapling state", 4)?.try_into().unwrap()) as usize;
+        let nullifiers: Vec<[u8; 32]> = read("sapling state nullifiers", nullifiers_count.saturating_mul(32))?
+            .chunks_exact(32)
+            .map(|nullifier| nullifier.try_into().unwrap())
+            .collect();
+        let tree = CommitmentTreeFrontier::deserialize(serialized[offset..].to_vec())?;
+
+        Self::new(memo_size, tree, roots, nullifiers)
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
+        let mut bytes = self.memo_size.to_le_bytes().to_vec();
+        bytes.extend_from_slice(&(self.roots.len() as u32).to_le_bytes());
+        for root in self.roots.iter() {
+            bytes.extend_from_slice(&node_bytes(root));
+        }
+        bytes.extend_from_slice(&(self.nullifiers.len() as u32).to_le_bytes());
+        for nullifier in self.nullifiers.iter() {
+            bytes.extend_from_slice(nullifier);
+        }
+        bytes.append(&mut self.tree.serialize()?);
+
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zcash_primitives::constants::SPENDING_KEY_GENERATOR;
+    use zcash_primitives::primitives::Nullifier;
+    use zcash_primitives::redjubjub::{PublicKey, Signature};
+    use zcash_primitives::transaction::components::{GROTH_PROOF_SIZE, SpendDescription};
+    use zcash_proofs::sapling::SaplingProvingContext;
+
+    use crate::transaction::{create_binding_sig, SaplingTransactionCiphertext, SaplingTransactionOutput};
+    use crate::transaction::proof::tests::dummy_params;
+    use crate::transaction::signature::create_spend_sig_with_ask;
+
+    use super::*;
+
+    const ANTI_REPLAY: &[u8] = b"KT1PwYL1B8hagFeCcByAcsN3KTQHmJFfDwnjNetXdQprcVkpaWU";
+
+    fn point() -> jubjub::ExtendedPoint {
+        jubjub::ExtendedPoint::from(SPENDING_KEY_GENERATOR)
+    }
+
+    fn node(value: u64) -> Node {
+        Node::new(bls12_381::Scalar::from(value).to_repr())
+    }
+
+    fn spend(nullifier: u8, root: bls12_381::Scalar) -> SpendDescription {
+        SpendDescription {
+            cv: point(),
+            anchor: root,
+            nullifier: Nullifier([nullifier; 32]),
+            rk: PublicKey(point()),
+            zkproof: [0u8; GROTH_PROOF_SIZE],
+            spend_auth_sig: Some(Signature::read(&[0u8; 64][..]).unwrap()),
+        }
+    }
+
+    fn output(memo_size: u16) -> SaplingTransactionOutput {
+        SaplingTransactionOutput {
+            cmu: bls12_381::Scalar::one(),
+            zkproof: [0u8; GROTH_PROOF_SIZE],
+            ciphertext: SaplingTransactionCiphertext {
+                cv: point(),
+                epk: point(),
+                payload_enc: vec![0u8; enc_ciphertext_size(memo_size as usize)],
+                nonce_enc: [0u8; 24],
+                payload_out: [0u8; 80],
+                nonce_out: [0u8; 24],
+            },
+        }
+    }
+
+    // Without spends and outputs the binding signature is made with a zero key, which makes the transaction valid.
+    fn transaction(state: &SaplingState) -> SaplingTransaction {
+        let mut transaction = SaplingTransaction {
+            spend_descriptions: vec![],
+            output_descriptions: vec![],
+            binding_sig: Signature::read(&[0u8; 64][..]).unwrap(),
+            balance: 0,
+            root: bls12_381::Scalar::from(state.root()),
+            bound_data: vec![1, 2, 3],
+        };
+        let sighash = compute_sighash(&transaction, ANTI_REPLAY).unwrap();
+        transaction.binding_sig = create_binding_sig(&mut SaplingProvingContext::new(), 0, sighash).unwrap();
+
+        transaction
+    }
+
+    // `rk` of the test spends is the generator itself, their spend authorization key is one.
+    fn sign_spends(transaction: &mut SaplingTransaction) {
+        let sighash = compute_sighash(transaction, ANTI_REPLAY).unwrap();
+        for spend in transaction.spend_descriptions.iter_mut() {
+            spend.spend_auth_sig = Some(create_spend_sig_with_ask(&jubjub::Scalar::one(), jubjub::Scalar::zero(), sighash).unwrap());
+        }
+    }
+
+    fn verify_error(state: &SaplingState, transaction: &SaplingTransaction) -> SaplingError {
+        verify_update(state, transaction, ANTI_REPLAY, &dummy_params()).err().unwrap()
+    }
+
+    #[test]
+    fn applies_empty_transaction() {
+        let state = SaplingState::empty(8);
+
+        let update = verify_update(&state, &transaction(&state), ANTI_REPLAY, &dummy_params()).unwrap();
+
+        assert_eq!(update.bound_data, vec![1, 2, 3]);
+        assert_eq!(update.balance, 0);
+        assert_eq!(update.state, state);
+    }
+
+    #[test]
+    fn rejects_invalid_binding_sig() {
+        let state = SaplingState::empty(8);
+
+        let mut forged = transaction(&state);
+        let sighash = compute_sighash(&forged, ANTI_REPLAY).unwrap();
+        forged.binding_sig = create_spend_sig_with_ask(&jubjub::Scalar::one(), jubjub::Scalar::zero(), sighash).unwrap();
+        assert_eq!(verify_error(&state, &forged), SaplingError::caused_by(SaplingStateError::InvalidBindingSig));
+
+        let mut unbalanced = transaction(&state);
+        unbalanced.balance = 10;
+        assert_eq!(verify_error(&state, &unbalanced), SaplingError::caused_by(SaplingStateError::InvalidBindingSig));
+
+        unbalanced.balance = i64::MAX;
+        assert_eq!(verify_error(&state, &unbalanced), SaplingError::caused_by(SaplingStateError::BalanceOutOfRange(i64::MAX)));
+    }
+
+    #[test]
+    fn rejects_wrong_anti_replay() {
+        let state = SaplingState::empty(8);
+        let mut transaction = transaction(&state);
+        transaction.spend_descriptions = vec![spend(1, transaction.root)];
+        sign_spends(&mut transaction);
+        assert_eq!(verify_error(&state, &transaction), SaplingError::caused_by(SaplingStateError::InvalidSpend(0)));
+
+        let error = verify_update(&state, &transaction, b"KT1PwYL1B8hagFeCcByAcsN3KTQHmJFfDwnjNetXdQprcVkpaWV", &dummy_params()).err().unwrap();
+        assert_eq!(error, SaplingError::caused_by(SaplingStateError::InvalidSpendAuthSig(0)));
+
+        transaction.bound_data = vec![1, 2, 4];
+        assert_eq!(verify_error(&state, &transaction), SaplingError::caused_by(SaplingStateError::InvalidSpendAuthSig(0)));
+
+        let error = verify_update(&state, &transaction, &[0u8; 65], &dummy_params()).err().unwrap();
+        assert_eq!(error, SaplingError::caused_by(SaplingStateError::InvalidAntiReplayLength(65)));
+    }
+
+    #[test]
+    fn rejects_memo_size_mismatch() {
+        let state = SaplingState::empty(8);
+
+        let mut transaction = transaction(&state);
+        transaction.output_descriptions = vec![output(8), output(16)];
+
+        assert_eq!(verify_error(&state, &transaction), SaplingError::caused_by(SaplingStateError::MemoSizeMismatch(1, 8)));
+    }
+
+    #[test]
+    fn rejects_unknown_anchor() {
+        let state = SaplingState::empty(8);
+
+        let mut transaction = transaction(&state);
+        transaction.root = bls12_381::Scalar::one();
+        transaction.spend_descriptions = vec![spend(1, transaction.root)];
+
+        assert_eq!(verify_error(&state, &transaction), SaplingError::caused_by(SaplingStateError::UnknownAnchor));
+    }
+
+    #[test]
+    fn rejects_duplicate_and_spent_nullifiers() {
+        let state = SaplingState::empty(8).apply(vec![[1u8; 32]].into_iter().collect(), &[]).unwrap();
+
+        let mut transaction = transaction(&state);
+        transaction.spend_descriptions = vec![spend(2, transaction.root), spend(2, transaction.root)];
+        assert_eq!(verify_error(&state, &transaction), SaplingError::caused_by(SaplingStateError::DuplicateNullifier(1)));
+
+        transaction.spend_descriptions = vec![spend(2, transaction.root), spend(1, transaction.root)];
+        assert_eq!(verify_error(&state, &transaction), SaplingError::caused_by(SaplingStateError::NullifierAlreadySpent(1)));
+    }
+
+    #[test]
+    fn rejects_invalid_proofs() {
+        let state = SaplingState::empty(8);
+
+        let mut transaction = transaction(&state);
+        transaction.spend_descriptions = vec![spend(1, transaction.root)];
+        assert_eq!(verify_error(&state, &transaction), SaplingError::caused_by(SaplingStateError::InvalidSpendAuthSig(0)));
+
+        sign_spends(&mut transaction);
+        assert_eq!(verify_error(&state, &transaction), SaplingError::caused_by(SaplingStateError::InvalidSpend(0)));
+
+        transaction.spend_descriptions = vec![];
+        transaction.output_descriptions = vec![output(8)];
+        assert_eq!(verify_error(&state, &transaction), SaplingError::caused_by(SaplingStateError::InvalidOutput(0)));
+    }
+
+    #[test]
+    fn keeps_limited_root_history() {
+        let initial = SaplingState::empty(0);
+        let first_root = initial.root();
+
+        let mut state = initial.apply(BTreeSet::new(), &[node(1), node(2)]).unwrap();
+        assert_eq!(state.tree().size(), 2);
+        assert!(state.has_root(&first_root));
+        assert_eq!(state.root(), CommitmentTreeFrontier::from_commitments(&[node(1), node(2)]).unwrap().root());
+
+        // `node(1)` is the empty leaf, appending it wouldn't change the root
+        for value in 0..SAPLING_ROOTS_HISTORY as u64 - 1 {
+            state = state.apply(BTreeSet::new(), &[node(value + 3)]).unwrap();
+        }
+        assert_eq!(state.roots.len(), SAPLING_ROOTS_HISTORY);
+        assert!(!state.has_root(&first_root));
+        assert_eq!(state.roots.back(), Some(&state.root()));
+    }
+
+    #[test]
+    fn serializes_and_deserializes() {
+        let nullifiers = vec![[3u8; 32], [1u8; 32]].into_iter().collect();
+        let state = SaplingState::empty(512).apply(nullifiers, &[node(1), node(2), node(3)]).unwrap();
+
+        let serialized = state.serialize().unwrap();
+        let deserialized = SaplingState::deserialize(serialized.clone()).unwrap();
+
+        assert_eq!(deserialized, state);
+        assert!(deserialized.is_spent(&[3u8; 32]));
+        assert_eq!(deserialized.memo_size(), 512);
+
+        assert!(SaplingState::deserialize(serialized[..serialized.len() - 1].to_vec()).is_err());
+        assert!(SaplingState::deserialize(vec![0u8; 2]).is_err());
+    }
+
+    #[test]
+    fn rejects_roots_not_matching_tree() {
+        let tree = CommitmentTreeFrontier::from_commitments(&[node(1)]).unwrap();
+
+        let error = SaplingState::new(8, tree.clone(), vec![node(1)], vec![]).err().unwrap();
+        assert_eq!(error, SaplingError::caused_by(SaplingStateError::RootMismatch));
+
+        let error = SaplingState::new(8, tree, vec![], vec![]).err().unwrap();
+        assert_eq!(error, SaplingError::caused_by(SaplingStateError::InvalidRootsCount(0)));
+    }
+}
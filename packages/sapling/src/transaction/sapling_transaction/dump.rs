@@ -0,0 +1,53 @@
+use std::fmt;
+
+use ff::PrimeField;
+use group::GroupEncoding;
+use zcash_primitives::redjubjub::Signature;
+use zcash_primitives::transaction::components::SpendDescription;
+
+use crate::transaction::sapling_transaction::transaction::SaplingTransaction;
+
+impl fmt::Display for SaplingTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "spend descriptions ({}):", self.spend_descriptions.len())?;
+        for (index, spend_description) in self.spend_descriptions.iter().enumerate() {
+            writeln!(f, "  [{}]", index)?;
+            writeln!(f, "    cv: {}", hex::encode(spend_description.cv.to_bytes()))?;
+            writeln!(f, "    nullifier: {}", hex::encode(spend_description.nullifier.0))?;
+            writeln!(f, "    rk: {}", hex::encode(spend_description.rk.0.to_bytes()))?;
+            writeln!(f, "    zkproof: {}", hex::encode(spend_description.zkproof))?;
+            writeln!(f, "    spend_auth_sig: {}", spend_auth_sig_to_hex(spend_description))?;
+        }
+
+        writeln!(f, "output descriptions ({}):", self.output_descriptions.len())?;
+        for (index, output_description) in self.output_descriptions.iter().enumerate() {
+            let ciphertext = &output_description.ciphertext;
+
+            writeln!(f, "  [{}]", index)?;
+            writeln!(f, "    cmu: {}", hex::encode(output_description.cmu.to_repr().as_ref()))?;
+            writeln!(f, "    zkproof: {}", hex::encode(output_description.zkproof))?;
+            writeln!(f, "    cv: {}", hex::encode(ciphertext.cv.to_bytes()))?;
+            writeln!(f, "    epk: {}", hex::encode(ciphertext.epk.to_bytes()))?;
+            writeln!(f, "    payload_enc ({} bytes): {}", ciphertext.payload_enc.len(), hex::encode(&ciphertext.payload_enc))?;
+            writeln!(f, "    nonce_enc: {}", hex::encode(ciphertext.nonce_enc))?;
+            writeln!(f, "    payload_out: {}", hex::encode(ciphertext.payload_out))?;
+            writeln!(f, "    nonce_out: {}", hex::encode(ciphertext.nonce_out))?;
+        }
+
+        writeln!(f, "binding sig: {}", signature_to_hex(&self.binding_sig))?;
+        writeln!(f, "balance: {}", self.balance)?;
+        writeln!(f, "root: {}", hex::encode(self.root.to_repr().as_ref()))?;
+        write!(f, "bound data ({} bytes): {}", self.bound_data.len(), hex::encode(&self.bound_data))
+    }
+}
+
+fn spend_auth_sig_to_hex(spend_description: &SpendDescription) -> String {
+    spend_description.spend_auth_sig.as_ref().map(signature_to_hex).unwrap_or_default()
+}
+
+fn signature_to_hex(signature: &Signature) -> String {
+    let mut bytes: Vec<u8> = vec![];
+    signature.write(&mut bytes).expect("writing to a vec should not fail");
+
+    hex::encode(bytes)
+}
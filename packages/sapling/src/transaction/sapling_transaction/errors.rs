@@ -0,0 +1,51 @@
+use crate::common::errors::DetailedError;
+
+#[derive(Debug, PartialEq)]
+pub enum SaplingTransactionError {
+    UnexpectedEnd { field: &'static str, offset: usize, expected: usize, available: usize },
+    InvalidLength { field: &'static str, offset: usize, length: usize },
+    InvalidField { field: &'static str, offset: usize, cause: String },
+    UnexpectedTag { field: &'static str, offset: usize, tag: u8 },
+    TrailingBytes { offset: usize, length: usize },
+}
+
+impl DetailedError for SaplingTransactionError {
+    fn details(&self) -> String {
+        use SaplingTransactionError::*;
+
+        match self {
+            UnexpectedEnd { field, offset, expected, available } => format!(
+                "Unexpected end of sapling transaction while reading `{}` at offset {}, expected {} bytes, {} available",
+                field,
+                offset,
+                expected,
+                available
+            ),
+            InvalidLength { field, offset, length } => format!("Invalid length {} of `{}` at offset {}", length, field, offset),
+            InvalidField { field, offset, cause } => format!("Invalid `{}` at offset {}: {}", field, offset, cause),
+            UnexpectedTag { field, offset, tag } => format!("Unexpected tag 0x{:02x} of `{}` at offset {}", tag, field, offset),
+            TrailingBytes { offset, length } => format!("Unexpected {} trailing bytes at offset {}", length, offset),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum KeyHashError {
+    InvalidLength(usize),
+    UnknownTag(u8),
+    InvalidEncoding(String, String),
+    InvalidPrefix(String),
+}
+
+impl DetailedError for KeyHashError {
+    fn details(&self) -> String {
+        use KeyHashError::*;
+
+        match self {
+            InvalidLength(len) => format!("Invalid key hash length {}, expected 21", len),
+            UnknownTag(tag) => format!("Unknown key hash tag {}", tag),
+            InvalidEncoding(key_hash, cause) => format!("Invalid key hash {}: {}", key_hash, cause),
+            InvalidPrefix(key_hash) => format!("Invalid key hash prefix {}", key_hash),
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use std::convert::TryInto;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::traits::Serializable;
+use crate::transaction::sapling_transaction::errors::KeyHashError;
+
+const KEY_HASH_SIZE: usize = 20;
+
+const TZ1_PREFIX: [u8; 3] = [6, 161, 159];
+const TZ2_PREFIX: [u8; 3] = [6, 161, 161];
+const TZ3_PREFIX: [u8; 3] = [6, 161, 164];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KeyHash {
+    Ed25519([u8; KEY_HASH_SIZE]),
+    Secp256k1([u8; KEY_HASH_SIZE]),
+    P256([u8; KEY_HASH_SIZE]),
+}
+
+impl KeyHash {
+    fn tag(&self) -> u8 {
+        match self {
+            KeyHash::Ed25519(_) => 0,
+            KeyHash::Secp256k1(_) => 1,
+            KeyHash::P256(_) => 2,
+        }
+    }
+
+    fn prefix(&self) -> [u8; 3] {
+        match self {
+            KeyHash::Ed25519(_) => TZ1_PREFIX,
+            KeyHash::Secp256k1(_) => TZ2_PREFIX,
+            KeyHash::P256(_) => TZ3_PREFIX,
+        }
+    }
+
+    fn hash(&self) -> &[u8; KEY_HASH_SIZE] {
+        match self {
+            KeyHash::Ed25519(hash) | KeyHash::Secp256k1(hash) | KeyHash::P256(hash) => hash,
+        }
+    }
+}
+
+// Binary form: tag (1 byte) ‖ hash (20 bytes).
+impl Serializable<Vec<u8>, SaplingError> for KeyHash {
+    fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
+        if serialized.len() != 1 + KEY_HASH_SIZE {
+            return Err(SaplingError::caused_by(KeyHashError::InvalidLength(serialized.len())));
+        }
+
+        let hash: [u8; KEY_HASH_SIZE] = serialized[1..].try_into().unwrap();
+        match serialized[0] {
+            0 => Ok(KeyHash::Ed25519(hash)),
+            1 => Ok(KeyHash::Secp256k1(hash)),
+            2 => Ok(KeyHash::P256(hash)),
+            tag => Err(SaplingError::caused_by(KeyHashError::UnknownTag(tag))),
+        }
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
+        let mut bytes = vec![self.tag()];
+        bytes.extend_from_slice(self.hash());
+
+        Ok(bytes)
+    }
+}
+
+impl fmt::Display for KeyHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut bytes = self.prefix().to_vec();
+        bytes.extend_from_slice(self.hash());
+
+        write!(f, "{}", bs58::encode(bytes).with_check().into_string())
+    }
+}
+
+impl FromStr for KeyHash {
+    type Err = SaplingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = bs58::decode(s).with_check(None).into_vec()
+            .map_err(|err| SaplingError::caused_by(KeyHashError::InvalidEncoding(s.to_string(), err.to_string())))?;
+
+        if bytes.len() != 3 + KEY_HASH_SIZE {
+            return Err(SaplingError::caused_by(KeyHashError::InvalidEncoding(s.to_string(), format!("expected {} bytes, got {}", 3 + KEY_HASH_SIZE, bytes.len()))));
+        }
+
+        let hash: [u8; KEY_HASH_SIZE] = bytes[3..].try_into().unwrap();
+        match [bytes[0], bytes[1], bytes[2]] {
+            TZ1_PREFIX => Ok(KeyHash::Ed25519(hash)),
+            TZ2_PREFIX => Ok(KeyHash::Secp256k1(hash)),
+            TZ3_PREFIX => Ok(KeyHash::P256(hash)),
+            _ => Err(SaplingError::caused_by(KeyHashError::InvalidPrefix(s.to_string()))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_key_hash_between_forms() {
+        let test_data = [
+            (KeyHash::Ed25519([1u8; 20]), "tz1", 0u8),
+            (KeyHash::Secp256k1([2u8; 20]), "tz2", 1u8),
+            (KeyHash::P256([3u8; 20]), "tz3", 2u8),
+        ];
+
+        for (key_hash, prefix, tag) in test_data.iter() {
+            let encoded = key_hash.to_string();
+            assert!(encoded.starts_with(prefix));
+            assert_eq!(encoded.len(), 36);
+            assert_eq!(&encoded.parse::<KeyHash>().unwrap(), key_hash);
+
+            let bytes = key_hash.serialize().unwrap();
+            assert_eq!(bytes[0], *tag);
+            assert_eq!(&KeyHash::deserialize(bytes).unwrap(), key_hash);
+        }
+    }
+
+    #[test]
+    fn parses_known_key_hash() {
+        let encoded = "tz1burnburnburnburnburnburnburjAYjjX";
+        let key_hash: KeyHash = encoded.parse().unwrap();
+
+        assert!(matches!(key_hash, KeyHash::Ed25519(_)));
+        assert_eq!(key_hash.to_string(), encoded);
+    }
+
+    #[test]
+    fn fails_on_invalid_key_hash() {
+        assert_eq!(
+            "KT1BEqzn5Wx8uJrZNvuS9DVHmLvG9td3fDLi".parse::<KeyHash>().unwrap_err(),
+            SaplingError::caused_by(KeyHashError::InvalidPrefix(String::from("KT1BEqzn5Wx8uJrZNvuS9DVHmLvG9td3fDLi"))),
+        );
+        assert!("tz1burnburnburnburnburnburnburjAYjjx".parse::<KeyHash>().is_err());
+        assert_eq!(KeyHash::deserialize(vec![3u8; 21]).unwrap_err(), SaplingError::caused_by(KeyHashError::UnknownTag(3)));
+        assert_eq!(KeyHash::deserialize(vec![0u8; 20]).unwrap_err(), SaplingError::caused_by(KeyHashError::InvalidLength(20)));
+    }
+}
@@ -0,0 +1,282 @@
+use std::io::Write;
+use std::str;
+
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::traits::Serializable;
+use crate::transaction::sapling_transaction::errors::SaplingTransactionError;
+use crate::transaction::sapling_transaction::key_hash::KeyHash;
+use crate::transaction::sapling_transaction::reader::{OffsetReader, write_dynamic};
+use crate::transaction::sapling_transaction::transaction::SaplingTransaction;
+
+const PACKED_PREFIX: u8 = 0x05;
+
+const TAG_STRING: u8 = 0x01;
+const TAG_SEQUENCE: u8 = 0x02;
+const TAG_PRIM_0: u8 = 0x03;
+const TAG_PRIM_1: u8 = 0x05;
+const TAG_PRIM_2: u8 = 0x07;
+const TAG_BYTES: u8 = 0x0a;
+
+const PRIM_NONE: u8 = 0x06;
+const PRIM_PAIR: u8 = 0x07;
+const PRIM_SOME: u8 = 0x09;
+
+pub struct ShieldedTransfer {
+    pub transaction: SaplingTransaction,
+    pub unshield_to: Option<KeyHash>,
+}
+
+// The `list (pair sapling_transaction (option key_hash))` parameter of a shielded pool contract.
+pub struct ShieldedPoolParameters {
+    pub transfers: Vec<ShieldedTransfer>,
+}
+
+impl ShieldedPoolParameters {
+    pub fn read(bytes: &[u8]) -> Result<Self, SaplingTransactionError> {
+        let mut reader = OffsetReader::new(bytes);
+        reader.expect_u8("parameters", TAG_SEQUENCE)?;
+
+        let mut sequence = reader.read_dynamic("parameters")?;
+        let mut transfers = vec![];
+        while !sequence.is_empty() {
+            transfers.push(ShieldedTransfer::read(&mut sequence)?);
+        }
+
+        reader.assert_empty()?;
+
+        Ok(ShieldedPoolParameters { transfers })
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W) -> Result<(), SaplingError> {
+        let mut sequence: Vec<u8> = vec![];
+        for transfer in self.transfers.iter() {
+            transfer.write(&mut sequence)?;
+        }
+
+        let mut bytes: Vec<u8> = vec![TAG_SEQUENCE];
+        write_dynamic(&mut bytes, &sequence);
+
+        writer.write_all(&bytes).map_err(|err| SaplingError::caused_by(err.to_string()))
+    }
+
+    pub fn pack(&self) -> Result<Vec<u8>, SaplingError> {
+        let mut bytes: Vec<u8> = vec![PACKED_PREFIX];
+        self.write(&mut bytes)?;
+
+        Ok(bytes)
+    }
+
+    pub fn unpack(bytes: &[u8]) -> Result<Self, SaplingError> {
+        match bytes.split_first() {
+            Some((&PACKED_PREFIX, bytes)) => ShieldedPoolParameters::read(bytes)
+                .map_err(|err| shift_offset(err, 1))
+                .map_err(SaplingError::caused_by),
+            Some((&tag, _)) => Err(SaplingError::caused_by(SaplingTransactionError::UnexpectedTag { field: "packed prefix", offset: 0, tag })),
+            None => Err(SaplingError::caused_by(SaplingTransactionError::UnexpectedEnd { field: "packed prefix", offset: 0, expected: 1, available: 0 })),
+        }
+    }
+}
+
+impl Serializable<Vec<u8>, SaplingError> for ShieldedPoolParameters {
+    fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
+        ShieldedPoolParameters::read(&serialized).map_err(SaplingError::caused_by)
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
+        let mut bytes: Vec<u8> = vec![];
+        self.write(&mut bytes)?;
+
+        Ok(bytes)
+    }
+}
+
+impl ShieldedTransfer {
+    fn read(reader: &mut OffsetReader) -> Result<Self, SaplingTransactionError> {
+        reader.expect_u8("pair", TAG_PRIM_2)?;
+        reader.expect_u8("pair", PRIM_PAIR)?;
+
+        reader.expect_u8("sapling_transaction", TAG_BYTES)?;
+        let transaction = SaplingTransaction::read_from(reader.read_dynamic("sapling_transaction")?)?;
+
+        let offset = reader.offset();
+        let unshield_to = match reader.read_u8("option")? {
+            TAG_PRIM_0 => {
+                reader.expect_u8("None", PRIM_NONE)?;
+                None
+            },
+            TAG_PRIM_1 => {
+                reader.expect_u8("Some", PRIM_SOME)?;
+                Some(read_key_hash(reader)?)
+            },
+            tag => return Err(SaplingTransactionError::UnexpectedTag { field: "option", offset, tag }),
+        };
+
+        Ok(ShieldedTransfer { transaction, unshield_to })
+    }
+
+    fn write(&self, bytes: &mut Vec<u8>) -> Result<(), SaplingError> {
+        bytes.push(TAG_PRIM_2);
+        bytes.push(PRIM_PAIR);
+
+        bytes.push(TAG_BYTES);
+        write_dynamic(bytes, &self.transaction.serialize()?);
+
+        match &self.unshield_to {
+            Some(key_hash) => {
+                bytes.push(TAG_PRIM_1);
+                bytes.push(PRIM_SOME);
+                bytes.push(TAG_BYTES);
+                write_dynamic(bytes, &key_hash.serialize()?);
+            },
+            None => {
+                bytes.push(TAG_PRIM_0);
+                bytes.push(PRIM_NONE);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// The key hash can be either in the optimized (bytes) or the readable (string) form.
+fn read_key_hash(reader: &mut OffsetReader) -> Result<KeyHash, SaplingTransactionError> {
+    let offset = reader.offset();
+    match reader.read_u8("key_hash")? {
+        TAG_BYTES => {
+            let key_hash = reader.read_dynamic("key_hash")?;
+            let offset = key_hash.offset();
+
+            KeyHash::deserialize(key_hash.into_remaining().to_vec())
+                .map_err(|err| SaplingTransactionError::InvalidField { field: "key_hash", offset, cause: err.to_string() })
+        },
+        TAG_STRING => {
+            let key_hash = reader.read_dynamic("key_hash")?;
+            let offset = key_hash.offset();
+
+            str::from_utf8(key_hash.into_remaining())
+                .map_err(|err| SaplingError::caused_by(err.to_string()))
+                .and_then(|key_hash| key_hash.parse())
+                .map_err(|err| SaplingTransactionError::InvalidField { field: "key_hash", offset, cause: err.to_string() })
+        },
+        tag => Err(SaplingTransactionError::UnexpectedTag { field: "key_hash", offset, tag }),
+    }
+}
+
+fn shift_offset(error: SaplingTransactionError, shift: usize) -> SaplingTransactionError {
+    use SaplingTransactionError::*;
+
+    match error {
+        UnexpectedEnd { field, offset, expected, available } => UnexpectedEnd { field, offset: offset + shift, expected, available },
+        InvalidLength { field, offset, length } => InvalidLength { field, offset: offset + shift, length },
+        InvalidField { field, offset, cause } => InvalidField { field, offset: offset + shift, cause },
+        UnexpectedTag { field, offset, tag } => UnexpectedTag { field, offset: offset + shift, tag },
+        TrailingBytes { offset, length } => TrailingBytes { offset: offset + shift, length },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_transaction_bytes() -> Vec<u8> {
+        let mut bytes = vec![0u8; 8];
+        bytes.extend_from_slice(&[9u8; 64]);
+        bytes.extend_from_slice(&(-5i64).to_be_bytes());
+        bytes.extend_from_slice(&[0u8; 32]);
+        bytes.extend_from_slice(&[0, 0, 0, 1, 0xAB]);
+
+        bytes
+    }
+
+    fn transfer(unshield_to: Option<KeyHash>) -> ShieldedTransfer {
+        ShieldedTransfer { transaction: SaplingTransaction::read(&empty_transaction_bytes()).unwrap(), unshield_to }
+    }
+
+    fn expected_bytes(key_hash: &[u8]) -> Vec<u8> {
+        let transaction = empty_transaction_bytes();
+
+        let mut transfers = vec![0x07, 0x07, 0x0a];
+        transfers.extend_from_slice(&(transaction.len() as u32).to_be_bytes());
+        transfers.extend_from_slice(&transaction);
+        transfers.extend_from_slice(&[0x03, 0x06]);
+        transfers.extend_from_slice(&[0x07, 0x07, 0x0a]);
+        transfers.extend_from_slice(&(transaction.len() as u32).to_be_bytes());
+        transfers.extend_from_slice(&transaction);
+        transfers.extend_from_slice(key_hash);
+
+        let mut bytes = vec![0x02];
+        bytes.extend_from_slice(&(transfers.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(&transfers);
+
+        bytes
+    }
+
+    #[test]
+    fn encodes_and_decodes_shielded_pool_parameters() {
+        let key_hash = KeyHash::Ed25519([1u8; 20]);
+        let parameters = ShieldedPoolParameters { transfers: vec![transfer(None), transfer(Some(key_hash))] };
+
+        let mut key_hash_bytes = vec![0x05, 0x09, 0x0a, 0, 0, 0, 21, 0];
+        key_hash_bytes.extend_from_slice(&[1u8; 20]);
+        let expected = expected_bytes(&key_hash_bytes);
+
+        let serialized = parameters.serialize().unwrap();
+        assert_eq!(serialized, expected);
+
+        let decoded = ShieldedPoolParameters::deserialize(serialized).unwrap();
+        assert_eq!(decoded.transfers.len(), 2);
+        assert_eq!(decoded.transfers[0].unshield_to, None);
+        assert_eq!(decoded.transfers[1].unshield_to, Some(key_hash));
+        assert_eq!(decoded.transfers[1].transaction.balance, -5);
+        assert_eq!(decoded.transfers[1].transaction.bound_data, vec![0xAB]);
+
+        let packed = parameters.pack().unwrap();
+        assert_eq!(packed[0], 0x05);
+        assert_eq!(&packed[1..], &expected[..]);
+        assert_eq!(ShieldedPoolParameters::unpack(&packed).unwrap().transfers.len(), 2);
+        assert_eq!(
+            ShieldedPoolParameters::unpack(&expected).err().unwrap(),
+            SaplingError::caused_by(SaplingTransactionError::UnexpectedTag { field: "packed prefix", offset: 0, tag: 0x02 }),
+        );
+    }
+
+    #[test]
+    fn decodes_readable_key_hash() {
+        let key_hash = "tz1burnburnburnburnburnburnburjAYjjX";
+
+        let mut key_hash_bytes = vec![0x05, 0x09, 0x01, 0, 0, 0, key_hash.len() as u8];
+        key_hash_bytes.extend_from_slice(key_hash.as_bytes());
+
+        let decoded = ShieldedPoolParameters::read(&expected_bytes(&key_hash_bytes)).unwrap();
+
+        assert_eq!(decoded.transfers[1].unshield_to.unwrap().to_string(), key_hash);
+    }
+
+    #[test]
+    fn reports_offsets_of_malformed_parameters() {
+        let mut invalid_option = expected_bytes(&[0x03, 0x06]);
+        let option_offset = 5 + 3 + 4 + empty_transaction_bytes().len();
+        invalid_option[option_offset] = 0x04;
+
+        let mut invalid_transaction = expected_bytes(&[0x03, 0x06]);
+        invalid_transaction[5 + 3 + 4 + 3] = 1;
+
+        let test_data = [
+            (vec![0x05], SaplingTransactionError::UnexpectedTag { field: "parameters", offset: 0, tag: 0x05 }),
+            (invalid_option, SaplingTransactionError::UnexpectedTag { field: "option", offset: option_offset, tag: 0x04 }),
+            (invalid_transaction, SaplingTransactionError::InvalidLength { field: "spend_descriptions", offset: 5 + 3 + 4 + 4, length: 1 }),
+        ];
+
+        for (bytes, expected) in test_data.iter() {
+            let actual = ShieldedPoolParameters::read(bytes).err().unwrap();
+            assert_eq!(&actual, expected);
+        }
+
+        let mut packed = vec![0x05];
+        packed.extend_from_slice(&test_data[1].0);
+        assert_eq!(
+            ShieldedPoolParameters::unpack(&packed).err().unwrap(),
+            SaplingError::caused_by(SaplingTransactionError::UnexpectedTag { field: "option", offset: option_offset + 1, tag: 0x04 })
+        );
+    }
+}
@@ -0,0 +1,11 @@
+pub use key_hash::KeyHash;
+pub use micheline::{ShieldedPoolParameters, ShieldedTransfer};
+pub use transaction::{SaplingTransaction, SaplingTransactionCiphertext, SaplingTransactionOutput};
+
+mod dump;
+mod key_hash;
+mod micheline;
+mod reader;
+mod transaction;
+
+mod errors;
@@ -0,0 +1,119 @@
+use std::convert::{TryFrom, TryInto};
+
+use crate::common::errors::SaplingError;
+use crate::common::traits::Serializable;
+use crate::transaction::sapling_transaction::errors::SaplingTransactionError;
+
+// Keeps track of the absolute offset in the sapling transaction, so malformed input can be precisely reported.
+pub(super) struct OffsetReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    base: usize,
+}
+
+impl<'a> OffsetReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> OffsetReader<'a> {
+        OffsetReader { bytes, position: 0, base: 0 }
+    }
+
+    fn nested(bytes: &'a [u8], base: usize) -> OffsetReader<'a> {
+        OffsetReader { bytes, position: 0, base }
+    }
+
+    pub fn offset(&self) -> usize {
+        self.base + self.position
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.position == self.bytes.len()
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.position
+    }
+
+    pub fn read_bytes(&mut self, field: &'static str, len: usize) -> Result<&'a [u8], SaplingTransactionError> {
+        if self.remaining() < len {
+            return Err(SaplingTransactionError::UnexpectedEnd {
+                field,
+                offset: self.offset(),
+                expected: len,
+                available: self.remaining(),
+            });
+        }
+
+        let bytes = &self.bytes[self.position..self.position + len];
+        self.position += len;
+
+        Ok(bytes)
+    }
+
+    pub fn read_array<T>(&mut self, field: &'static str, len: usize) -> Result<T, SaplingTransactionError>
+        where for<'b> T: TryFrom<&'b [u8]> {
+
+        let offset = self.offset();
+        self.read_bytes(field, len)?
+            .try_into()
+            .map_err(|_| SaplingTransactionError::InvalidLength { field, offset, length: len })
+    }
+
+    pub fn into_remaining(self) -> &'a [u8] {
+        &self.bytes[self.position..]
+    }
+
+    pub fn read_u8(&mut self, field: &'static str) -> Result<u8, SaplingTransactionError> {
+        Ok(self.read_bytes(field, 1)?[0])
+    }
+
+    pub fn expect_u8(&mut self, field: &'static str, expected: u8) -> Result<(), SaplingTransactionError> {
+        let offset = self.offset();
+        let tag = self.read_u8(field)?;
+        if tag == expected {
+            Ok(())
+        } else {
+            Err(SaplingTransactionError::UnexpectedTag { field, offset, tag })
+        }
+    }
+
+    pub fn read_u32(&mut self, field: &'static str) -> Result<u32, SaplingTransactionError> {
+        let bytes: [u8; 4] = self.read_array(field, 4)?;
+
+        Ok(u32::from_be_bytes(bytes))
+    }
+
+    pub fn read_i64(&mut self, field: &'static str) -> Result<i64, SaplingTransactionError> {
+        let bytes: [u8; 8] = self.read_array(field, 8)?;
+
+        Ok(i64::from_be_bytes(bytes))
+    }
+
+    pub fn read_dynamic(&mut self, field: &'static str) -> Result<OffsetReader<'a>, SaplingTransactionError> {
+        let len = self.read_u32(field)? as usize;
+        let base = self.offset();
+        let bytes = self.read_bytes(field, len)?;
+
+        Ok(OffsetReader::nested(bytes, base))
+    }
+
+    pub fn read_serializable<S>(&mut self, field: &'static str, len: usize) -> Result<S, SaplingTransactionError>
+        where S: Serializable<Vec<u8>, SaplingError> {
+
+        let offset = self.offset();
+        let bytes = self.read_bytes(field, len)?;
+
+        S::deserialize(bytes.to_vec()).map_err(|err| SaplingTransactionError::InvalidField { field, offset, cause: err.to_string() })
+    }
+
+    pub fn assert_empty(&self) -> Result<(), SaplingTransactionError> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(SaplingTransactionError::TrailingBytes { offset: self.offset(), length: self.remaining() })
+        }
+    }
+}
+
+pub(super) fn write_dynamic(bytes: &mut Vec<u8>, dynamic: &[u8]) {
+    bytes.extend_from_slice(&(dynamic.len() as u32).to_be_bytes());
+    bytes.extend_from_slice(dynamic);
+}
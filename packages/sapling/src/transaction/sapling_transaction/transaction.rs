@@ -0,0 +1,342 @@
+use std::io::Write;
+
+use ff::PrimeField;
+use group::GroupEncoding;
+use zcash_primitives::primitives::Nullifier;
+use zcash_primitives::redjubjub::{PublicKey, Signature};
+use zcash_primitives::transaction::components::{GROTH_PROOF_SIZE, SpendDescription};
+
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::traits::Serializable;
+use crate::transaction::sapling_transaction::errors::SaplingTransactionError;
+use crate::transaction::sapling_transaction::reader::{OffsetReader, write_dynamic};
+
+pub const SPEND_DESCRIPTION_SIZE: usize = 32 + 32 + 32 + GROTH_PROOF_SIZE + 64;
+pub const NONCE_SIZE: usize = 24;
+pub const PAYLOAD_OUT_SIZE: usize = 80;
+
+// Based on the Tezos [`UTXO.transaction`](https://gitlab.com/tezos/tezos/-/blob/master/src/lib_sapling/core.ml) encoding.
+// The spend descriptions don't carry an anchor, `root` is shared by all of them.
+pub struct SaplingTransaction {
+    pub spend_descriptions: Vec<SpendDescription>,
+    pub output_descriptions: Vec<SaplingTransactionOutput>,
+    pub binding_sig: Signature,
+    pub balance: i64,
+    pub root: bls12_381::Scalar,
+    pub bound_data: Vec<u8>,
+}
+
+pub struct SaplingTransactionOutput {
+    pub cmu: bls12_381::Scalar,
+    pub zkproof: [u8; GROTH_PROOF_SIZE],
+    pub ciphertext: SaplingTransactionCiphertext,
+}
+
+pub struct SaplingTransactionCiphertext {
+    pub cv: jubjub::ExtendedPoint,
+    pub epk: jubjub::ExtendedPoint,
+    pub payload_enc: Vec<u8>,
+    pub nonce_enc: [u8; NONCE_SIZE],
+    pub payload_out: [u8; PAYLOAD_OUT_SIZE],
+    pub nonce_out: [u8; NONCE_SIZE],
+}
+
+impl SaplingTransaction {
+    pub fn read(bytes: &[u8]) -> Result<Self, SaplingTransactionError> {
+        SaplingTransaction::read_from(OffsetReader::new(bytes))
+    }
+
+    pub(super) fn read_from(mut reader: OffsetReader) -> Result<Self, SaplingTransactionError> {
+        let mut spends_reader = reader.read_dynamic("spend_descriptions")?;
+        if spends_reader.remaining() % SPEND_DESCRIPTION_SIZE != 0 {
+            return Err(SaplingTransactionError::InvalidLength {
+                field: "spend_descriptions",
+                offset: spends_reader.offset(),
+                length: spends_reader.remaining(),
+            });
+        }
+
+        // The anchor is only known after all descriptions have been read.
+        let mut spends = vec![];
+        while !spends_reader.is_empty() {
+            let offset = spends_reader.offset();
+            let bytes = spends_reader.read_bytes("spend_description", SPEND_DESCRIPTION_SIZE)?;
+            spends.push((offset, bytes));
+        }
+
+        let mut outputs_reader = reader.read_dynamic("output_descriptions")?;
+        let mut output_descriptions = vec![];
+        while !outputs_reader.is_empty() {
+            output_descriptions.push(SaplingTransactionOutput::read(&mut outputs_reader)?);
+        }
+
+        let binding_sig: Signature = reader.read_serializable("binding_sig", 64)?;
+        let balance = reader.read_i64("balance")?;
+
+        let root_offset = reader.offset();
+        let root: [u8; 32] = reader.read_array("root", 32)?;
+        let root = bls12_381::Scalar::from_repr(root).ok_or_else(|| SaplingTransactionError::InvalidField {
+            field: "root",
+            offset: root_offset,
+            cause: String::from("root is not in field"),
+        })?;
+
+        let bound_data = reader.read_dynamic("bound_data")?;
+        let bound_data = bound_data.into_remaining().to_vec();
+
+        reader.assert_empty()?;
+
+        let spend_descriptions = spends.into_iter()
+            .map(|(offset, bytes)| read_spend_description(offset, bytes, &root))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(SaplingTransaction {
+            spend_descriptions,
+            output_descriptions,
+            binding_sig,
+            balance,
+            root,
+            bound_data,
+        })
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W) -> Result<(), SaplingError> {
+        let mut spends: Vec<u8> = vec![];
+        for spend_description in self.spend_descriptions.iter() {
+            write_spend_description(&mut spends, spend_description)?;
+        }
+
+        let mut outputs: Vec<u8> = vec![];
+        for output_description in self.output_descriptions.iter() {
+            output_description.write(&mut outputs);
+        }
+
+        let mut bytes: Vec<u8> = vec![];
+        write_dynamic(&mut bytes, &spends);
+        write_dynamic(&mut bytes, &outputs);
+        bytes.append(&mut self.binding_sig.serialize()?);
+        bytes.extend_from_slice(&self.balance.to_be_bytes());
+        bytes.extend_from_slice(self.root.to_repr().as_ref());
+        write_dynamic(&mut bytes, &self.bound_data);
+
+        writer.write_all(&bytes).map_err(|err| SaplingError::caused_by(err.to_string()))
+    }
+
+    // The data covered by the spend authorization and binding signatures,
+    // the spend descriptions without their signatures, the output descriptions and the bound data.
+    pub(crate) fn write_sighash_data(&self, bytes: &mut Vec<u8>) -> Result<(), SaplingError> {
+        for spend_description in self.spend_descriptions.iter() {
+            write_unsigned_spend_description(bytes, spend_description)?;
+        }
+        for output_description in self.output_descriptions.iter() {
+            output_description.write(bytes);
+        }
+        bytes.extend_from_slice(&self.bound_data);
+
+        Ok(())
+    }
+}
+
+impl Serializable<Vec<u8>, SaplingError> for SaplingTransaction {
+    fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
+        SaplingTransaction::read(&serialized).map_err(SaplingError::caused_by)
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
+        let mut bytes: Vec<u8> = vec![];
+        self.write(&mut bytes)?;
+
+        Ok(bytes)
+    }
+}
+
+impl SaplingTransactionOutput {
+    fn read(reader: &mut OffsetReader) -> Result<Self, SaplingTransactionError> {
+        let cmu: bls12_381::Scalar = reader.read_serializable("cmu", 32)?;
+        let zkproof: [u8; GROTH_PROOF_SIZE] = reader.read_array("zkproof", GROTH_PROOF_SIZE)?;
+        let ciphertext = SaplingTransactionCiphertext::read(reader)?;
+
+        Ok(SaplingTransactionOutput { cmu, zkproof, ciphertext })
+    }
+
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(self.cmu.to_repr().as_ref());
+        bytes.extend_from_slice(&self.zkproof);
+        self.ciphertext.write(bytes);
+    }
+}
+
+impl SaplingTransactionCiphertext {
+    fn read(reader: &mut OffsetReader) -> Result<Self, SaplingTransactionError> {
+        let cv: jubjub::ExtendedPoint = reader.read_serializable("cv", 32)?;
+        let epk: jubjub::ExtendedPoint = reader.read_serializable("epk", 32)?;
+        let payload_enc = reader.read_dynamic("payload_enc")?;
+        let payload_enc = payload_enc.into_remaining().to_vec();
+        let nonce_enc: [u8; NONCE_SIZE] = reader.read_array("nonce_enc", NONCE_SIZE)?;
+        let payload_out: [u8; PAYLOAD_OUT_SIZE] = reader.read_array("payload_out", PAYLOAD_OUT_SIZE)?;
+        let nonce_out: [u8; NONCE_SIZE] = reader.read_array("nonce_out", NONCE_SIZE)?;
+
+        Ok(SaplingTransactionCiphertext { cv, epk, payload_enc, nonce_enc, payload_out, nonce_out })
+    }
+
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.cv.to_bytes());
+        bytes.extend_from_slice(&self.epk.to_bytes());
+        write_dynamic(bytes, &self.payload_enc);
+        bytes.extend_from_slice(&self.nonce_enc);
+        bytes.extend_from_slice(&self.payload_out);
+        bytes.extend_from_slice(&self.nonce_out);
+    }
+}
+
+// Tezos encodes spend descriptions as `cv ‖ nf ‖ rk ‖ proof ‖ signature`,
+// they are reordered and completed with the transaction root to be read as [`SpendDescription`].
+fn read_spend_description(offset: usize, bytes: &[u8], root: &bls12_381::Scalar) -> Result<SpendDescription, SaplingTransactionError> {
+    let mut spend_description: Vec<u8> = Vec::with_capacity(SPEND_DESCRIPTION_SIZE + 32);
+    spend_description.extend_from_slice(&bytes[..32]);
+    spend_description.extend_from_slice(root.to_repr().as_ref());
+    spend_description.extend_from_slice(&bytes[32..]);
+
+    SpendDescription::deserialize(spend_description)
+        .map_err(|err| SaplingTransactionError::InvalidField { field: "spend_description", offset, cause: err.to_string() })
+}
+
+fn write_spend_description(bytes: &mut Vec<u8>, spend_description: &SpendDescription) -> Result<(), SaplingError> {
+    write_unsigned_spend_description(bytes, spend_description)?;
+
+    let spend_auth_sig = spend_description.spend_auth_sig.as_ref()
+        .ok_or_else(|| SaplingError::caused_by("spend description is not signed"))?;
+    bytes.append(&mut spend_auth_sig.serialize()?);
+
+    Ok(())
+}
+
+fn write_unsigned_spend_description(bytes: &mut Vec<u8>, spend_description: &SpendDescription) -> Result<(), SaplingError> {
+    let nullifier: &Nullifier = &spend_description.nullifier;
+    let rk: &PublicKey = &spend_description.rk;
+
+    bytes.extend_from_slice(&spend_description.cv.to_bytes());
+    bytes.extend_from_slice(&nullifier.0);
+    rk.write(&mut *bytes).map_err(|err| SaplingError::caused_by(err.to_string()))?;
+    bytes.extend_from_slice(&spend_description.zkproof);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use zcash_primitives::constants::SPENDING_KEY_GENERATOR;
+
+    use super::*;
+
+    fn point() -> jubjub::ExtendedPoint {
+        jubjub::ExtendedPoint::from(SPENDING_KEY_GENERATOR)
+    }
+
+    fn signature(byte: u8) -> Signature {
+        Signature::read(&[byte; 64][..]).unwrap()
+    }
+
+    fn sapling_transaction() -> SaplingTransaction {
+        let root = bls12_381::Scalar::from(7u64);
+
+        SaplingTransaction {
+            spend_descriptions: vec![SpendDescription {
+                cv: point(),
+                anchor: root,
+                nullifier: Nullifier([1u8; 32]),
+                rk: PublicKey(point()),
+                zkproof: [2u8; GROTH_PROOF_SIZE],
+                spend_auth_sig: Some(signature(3)),
+            }],
+            output_descriptions: vec![SaplingTransactionOutput {
+                cmu: bls12_381::Scalar::from(5u64),
+                zkproof: [4u8; GROTH_PROOF_SIZE],
+                ciphertext: SaplingTransactionCiphertext {
+                    cv: point(),
+                    epk: point(),
+                    payload_enc: vec![5u8; 10],
+                    nonce_enc: [6u8; NONCE_SIZE],
+                    payload_out: [7u8; PAYLOAD_OUT_SIZE],
+                    nonce_out: [8u8; NONCE_SIZE],
+                },
+            }],
+            binding_sig: signature(9),
+            balance: -100,
+            root,
+            bound_data: vec![10u8; 3],
+        }
+    }
+
+    #[test]
+    fn reads_and_writes_sapling_transaction() {
+        let serialized = sapling_transaction().serialize().unwrap();
+        let output_size = 32 + GROTH_PROOF_SIZE + 32 + 32 + 4 + 10 + NONCE_SIZE + PAYLOAD_OUT_SIZE + NONCE_SIZE;
+        assert_eq!(serialized.len(), 4 + SPEND_DESCRIPTION_SIZE + 4 + output_size + 64 + 8 + 32 + 4 + 3);
+
+        let transaction = SaplingTransaction::deserialize(serialized.clone()).unwrap();
+        assert_eq!(transaction.spend_descriptions.len(), 1);
+        assert_eq!(transaction.spend_descriptions[0].anchor, transaction.root);
+        assert_eq!(transaction.spend_descriptions[0].nullifier, Nullifier([1u8; 32]));
+        assert_eq!(transaction.output_descriptions.len(), 1);
+        assert_eq!(transaction.output_descriptions[0].ciphertext.payload_enc, vec![5u8; 10]);
+        assert_eq!(transaction.balance, -100);
+        assert_eq!(transaction.bound_data, vec![10u8; 3]);
+
+        assert_eq!(transaction.serialize().unwrap(), serialized);
+    }
+
+    #[test]
+    fn reads_empty_sapling_transaction() {
+        let mut serialized = vec![0u8; 8];
+        serialized.extend_from_slice(&[9u8; 64]);
+        serialized.extend_from_slice(&5i64.to_be_bytes());
+        serialized.extend_from_slice(&[0u8; 32]);
+        serialized.extend_from_slice(&[0u8; 4]);
+
+        let transaction = SaplingTransaction::read(&serialized).unwrap();
+
+        assert!(transaction.spend_descriptions.is_empty());
+        assert!(transaction.output_descriptions.is_empty());
+        assert_eq!(transaction.balance, 5);
+        assert_eq!(transaction.root, bls12_381::Scalar::zero());
+        assert!(transaction.bound_data.is_empty());
+    }
+
+    #[test]
+    fn reports_offsets_of_malformed_sapling_transaction() {
+        let serialized = sapling_transaction().serialize().unwrap();
+        let outputs_offset = 4 + SPEND_DESCRIPTION_SIZE + 4;
+
+        let mut invalid_spends_length = serialized.clone();
+        invalid_spends_length[3] -= 1;
+
+        let mut invalid_output_cv = serialized.clone();
+        let cv_offset = outputs_offset + 32 + GROTH_PROOF_SIZE;
+        invalid_output_cv[cv_offset..cv_offset + 32].copy_from_slice(&[0xFFu8; 32]);
+
+        let mut trailing_bytes = serialized.clone();
+        trailing_bytes.push(0);
+
+        let test_data = [
+            (serialized[..2].to_vec(), SaplingTransactionError::UnexpectedEnd { field: "spend_descriptions", offset: 0, expected: 4, available: 2 }),
+            (serialized[..100].to_vec(), SaplingTransactionError::UnexpectedEnd { field: "spend_descriptions", offset: 4, expected: SPEND_DESCRIPTION_SIZE, available: 96 }),
+            (invalid_spends_length, SaplingTransactionError::InvalidLength { field: "spend_descriptions", offset: 4, length: SPEND_DESCRIPTION_SIZE - 1 }),
+            (invalid_output_cv, SaplingTransactionError::InvalidField { field: "cv", offset: cv_offset, cause: String::from("sapling error, invalid jubjub point encoding") }),
+            (trailing_bytes, SaplingTransactionError::TrailingBytes { offset: serialized.len(), length: 1 }),
+        ];
+
+        for (bytes, expected) in test_data.iter() {
+            let actual = SaplingTransaction::read(bytes).err().unwrap();
+            assert_eq!(&actual, expected);
+        }
+    }
+
+    #[test]
+    fn dumps_sapling_transaction() {
+        let dump = sapling_transaction().to_string();
+        assert!(dump.contains("spend descriptions (1):"));
+        assert!(dump.contains("output descriptions (1):"));
+        assert!(dump.contains("payload_enc (10 bytes): 05050505050505050505"));
+    }
+}
@@ -1,17 +1,20 @@
+use group::GroupEncoding;
 use rand_core::OsRng;
-use zcash_primitives::redjubjub::{PrivateKey, Signature};
+use zcash_primitives::constants::VALUE_COMMITMENT_RANDOMNESS_GENERATOR;
+use zcash_primitives::redjubjub::{PrivateKey, PublicKey, Signature};
 use zcash_primitives::sapling::spend_sig;
 use zcash_primitives::transaction::components::Amount;
 use zcash_primitives::zip32::ExtendedSpendingKey;
-use zcash_proofs::sapling::SaplingProvingContext;
 
 use crate::common::errors::{CausedBy, SaplingError};
 use crate::common::traits::Serializable;
+use crate::common::utils::deserialize_utils::read_all;
 use crate::transaction::errors::SignatureError;
+use crate::transaction::proof::ProvingContext;
 
 impl Serializable<Vec<u8>, SaplingError> for Signature {
     fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> where Self: Sized {
-        Signature::read(&serialized[..]).map_err(SignatureError::ReadFailed).map_err(SaplingError::caused_by)
+        read_all("signature", &serialized, |reader| Signature::read(reader)).map_err(SignatureError::ReadFailed).map_err(SaplingError::caused_by)
     }
 
     fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
@@ -23,8 +26,12 @@ impl Serializable<Vec<u8>, SaplingError> for Signature {
 }
 
 pub fn create_spend_sig(xsk: &ExtendedSpendingKey, ar: jubjub::Scalar, sighash: [u8; 32]) -> Result<Signature, SaplingError> {
+    create_spend_sig_with_ask(&xsk.expsk.ask, ar, sighash)
+}
+
+pub fn create_spend_sig_with_ask(ask: &jubjub::Scalar, ar: jubjub::Scalar, sighash: [u8; 32]) -> Result<Signature, SaplingError> {
     let mut rng = OsRng;
-    let ask = PrivateKey::read(&xsk.expsk.ask.to_bytes()[..])
+    let ask = PrivateKey::read(&ask.to_bytes()[..])
         .map_err(SignatureError::PrivateKeyReadFailed)
         .map_err(SaplingError::caused_by)?;
     let signature = spend_sig(ask, ar, &sighash, &mut rng);
@@ -33,7 +40,7 @@ pub fn create_spend_sig(xsk: &ExtendedSpendingKey, ar: jubjub::Scalar, sighash:
 }
 
 pub fn create_binding_sig(
-    ctx: &mut SaplingProvingContext,
+    ctx: &mut impl ProvingContext,
     value_balance: i64,
     sighash: [u8; 32]
 ) -> Result<Signature, SaplingError> {
@@ -44,6 +51,20 @@ pub fn create_binding_sig(
         .map_err(SaplingError::caused_by)
 }
 
+// Signs without a proving context, for value commitments computed with explicit trapdoors.
+pub fn create_binding_sig_with_bsk(bsk: &jubjub::Scalar, sighash: [u8; 32]) -> Signature {
+    let mut rng = OsRng;
+
+    let bsk = PrivateKey(*bsk);
+    let bvk = PublicKey::from_private(&bsk, VALUE_COMMITMENT_RANDOMNESS_GENERATOR);
+
+    let mut data_to_be_signed = [0u8; 64];
+    data_to_be_signed[0..32].copy_from_slice(&bvk.0.to_bytes());
+    data_to_be_signed[32..64].copy_from_slice(&sighash[..]);
+
+    bsk.sign(&data_to_be_signed, &mut rng, VALUE_COMMITMENT_RANDOMNESS_GENERATOR)
+}
+
 fn get_amount(balance: i64) -> Result<Amount, SaplingError> {
     Amount::from_i64(balance).map_err(|_| SignatureError::ValueBalanceOutsideRange).map_err(SaplingError::caused_by)
 }
\ No newline at end of file
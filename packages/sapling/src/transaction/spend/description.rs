@@ -8,16 +8,15 @@ use zcash_primitives::primitives::{Nullifier, PaymentAddress, ViewingKey};
 use zcash_primitives::redjubjub::PublicKey;
 use zcash_primitives::sapling::Node;
 use zcash_primitives::transaction::components::{GROTH_PROOF_SIZE, SpendDescription};
-use zcash_primitives::zip32::ExtendedSpendingKey;
-use zcash_proofs::sapling::SaplingProvingContext;
 
 use crate::common::errors::{CausedBy, SaplingError};
 use crate::common::traits::Serializable;
+use crate::common::utils::deserialize_utils::read_all;
 use crate::transaction::note::create_note;
-use crate::transaction::proof::prepare_zkproof;
-use crate::transaction::signature::create_spend_sig;
+use crate::transaction::proof::{prepare_zkproof, ProvingContext};
 use crate::transaction::spend::errors::SpendDescriptionError;
 use crate::transaction::spend::proof::{create_spend_proof, SpendDetails, SpendParameters};
+use crate::transaction::spend::signer::{SpendAuthSigner, verify_spend_auth_sig};
 
 pub struct UnsignedSpendDescription {
     pub cv: jubjub::ExtendedPoint,
@@ -87,7 +86,7 @@ impl UnsignedSpendDescription {
 
 impl Serializable<Vec<u8>, SaplingError> for UnsignedSpendDescription {
     fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
-        UnsignedSpendDescription::read(&mut &serialized[..]).map_err(SpendDescriptionError::ReadFailed).map_err(SaplingError::caused_by)
+        read_all("spend description", &serialized, |reader| UnsignedSpendDescription::read(reader)).map_err(SpendDescriptionError::ReadFailed).map_err(SaplingError::caused_by)
     }
 
     fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
@@ -100,7 +99,7 @@ impl Serializable<Vec<u8>, SaplingError> for UnsignedSpendDescription {
 
 impl Serializable<Vec<u8>, SaplingError> for SpendDescription {
     fn deserialize(serialized: Vec<u8>) -> Result<Self, SaplingError> {
-        SpendDescription::read(&mut &serialized[..]).map_err(SpendDescriptionError::ReadFailed).map_err(SaplingError::caused_by)
+        read_all("spend description", &serialized, |reader| SpendDescription::read(reader)).map_err(SpendDescriptionError::ReadFailed).map_err(SaplingError::caused_by)
     }
 
     fn serialize(&self) -> Result<Vec<u8>, SaplingError> {
@@ -112,7 +111,7 @@ impl Serializable<Vec<u8>, SaplingError> for SpendDescription {
 }
 
 pub fn prepare_spend_description(
-    ctx: &mut SaplingProvingContext,
+    ctx: &mut impl ProvingContext,
     spend_details: SpendDetails,
     rcm: jubjub::Scalar,
     ar: jubjub::Scalar,
@@ -152,8 +151,17 @@ pub fn prepare_spend_description(
     Ok(spend_description)
 }
 
-pub fn sign_spend_description(spend_description: UnsignedSpendDescription, xsk: ExtendedSpendingKey, ar: jubjub::Scalar, sighash: [u8; 32]) -> Result<SpendDescription, SaplingError> {
-    let spend_sig = create_spend_sig(&xsk, ar, sighash)?;
+// Signatures that don't verify against `rk` are rejected, they would only be caught once the transaction is submitted.
+pub fn sign_spend_description<S: SpendAuthSigner + ?Sized>(
+    spend_description: UnsignedSpendDescription,
+    signer: &S,
+    ar: jubjub::Scalar,
+    sighash: [u8; 32]
+) -> Result<SpendDescription, SaplingError> {
+    let spend_sig = signer.sign_spend_auth(ar, sighash)?;
+    if !verify_spend_auth_sig(&spend_description.rk, sighash, &spend_sig) {
+        return Err(SaplingError::caused_by(SpendDescriptionError::InvalidSpendAuthSig));
+    }
 
     let spend_description = SpendDescription {
         cv: spend_description.cv,
@@ -5,6 +5,7 @@ use crate::common::errors::DetailedError;
 #[derive(Debug)]
 pub enum SpendDescriptionError {
     CreateSpendProofFailed,
+    InvalidSpendAuthSig,
     WriteFailed(io::Error),
     ReadFailed(io::Error),
 }
@@ -15,6 +16,7 @@ impl DetailedError for SpendDescriptionError {
 
         match self {
             CreateSpendProofFailed => String::from("Could not create a spend proof"),
+            InvalidSpendAuthSig => String::from("The spend authorization signature does not match the randomized key"),
             WriteFailed(err) => err.to_string(),
             ReadFailed(err) => err.to_string(),
         }
@@ -1,6 +1,8 @@
 pub use description::{compute_nullifier, prepare_spend_description, sign_spend_description, UnsignedSpendDescription};
 pub use proof::{SpendDetails, SpendParameters};
+pub use signer::{LocalSpendAuthSigner, SpendAuthSigner, verify_spend_auth_sig};
 
 mod description;
 mod proof;
+mod signer;
 mod errors;
\ No newline at end of file
@@ -4,9 +4,9 @@ use zcash_primitives::merkle_tree::MerklePath;
 use zcash_primitives::primitives::{PaymentAddress, ProofGenerationKey, Rseed};
 use zcash_primitives::redjubjub::PublicKey;
 use zcash_primitives::sapling::Node;
-use zcash_proofs::sapling::SaplingProvingContext;
 
 use crate::common::errors::{CausedBy, SaplingError};
+use crate::transaction::proof::ProvingContext;
 use crate::transaction::spend::errors::SpendDescriptionError;
 
 pub struct SpendDetails<'a> {
@@ -21,7 +21,7 @@ pub struct SpendParameters<'a> {
 }
 
 pub fn create_spend_proof(
-    ctx: &mut SaplingProvingContext,
+    ctx: &mut impl ProvingContext,
     spend_details: &SpendDetails,
     rcm: jubjub::Scalar,
     ar: jubjub::Scalar,
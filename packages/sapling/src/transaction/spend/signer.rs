@@ -0,0 +1,108 @@
+use group::GroupEncoding;
+use zcash_primitives::constants::SPENDING_KEY_GENERATOR;
+use zcash_primitives::redjubjub::{PublicKey, Signature};
+use zcash_primitives::zip32::ExtendedSpendingKey;
+
+use crate::common::errors::SaplingError;
+use crate::transaction::signature::{create_spend_sig, create_spend_sig_with_ask};
+
+// Creates the spend authorization signature with the randomized key `ask + ar` over `rk || sighash`
+// (see `zcash_primitives::sapling::spend_sig`), `ask` doesn't have to be available to the library.
+pub trait SpendAuthSigner {
+    fn sign_spend_auth(&self, ar: jubjub::Scalar, sighash: [u8; 32]) -> Result<Signature, SaplingError>;
+}
+
+impl SpendAuthSigner for ExtendedSpendingKey {
+    fn sign_spend_auth(&self, ar: jubjub::Scalar, sighash: [u8; 32]) -> Result<Signature, SaplingError> {
+        create_spend_sig(self, ar, sighash)
+    }
+}
+
+// Holds only the spend authorizing key.
+pub struct LocalSpendAuthSigner {
+    ask: jubjub::Scalar,
+}
+
+impl LocalSpendAuthSigner {
+    pub fn new(ask: jubjub::Scalar) -> LocalSpendAuthSigner {
+        LocalSpendAuthSigner { ask }
+    }
+}
+
+impl From<&ExtendedSpendingKey> for LocalSpendAuthSigner {
+    fn from(xsk: &ExtendedSpendingKey) -> Self {
+        LocalSpendAuthSigner::new(xsk.expsk.ask)
+    }
+}
+
+impl SpendAuthSigner for LocalSpendAuthSigner {
+    fn sign_spend_auth(&self, ar: jubjub::Scalar, sighash: [u8; 32]) -> Result<Signature, SaplingError> {
+        create_spend_sig_with_ask(&self.ask, ar, sighash)
+    }
+}
+
+pub fn verify_spend_auth_sig(rk: &PublicKey, sighash: [u8; 32], spend_auth_sig: &Signature) -> bool {
+    let mut message = [0u8; 64];
+    message[..32].copy_from_slice(&rk.0.to_bytes());
+    message[32..].copy_from_slice(&sighash);
+
+    rk.verify(&message, spend_auth_sig, SPENDING_KEY_GENERATOR)
+}
+
+#[cfg(test)]
+mod tests {
+    use zcash_primitives::primitives::Nullifier;
+    use zcash_primitives::redjubjub::PrivateKey;
+    use zcash_primitives::transaction::components::GROTH_PROOF_SIZE;
+
+    use crate::common::errors::CausedBy;
+    use crate::key::SaplingKey;
+    use crate::transaction::rand::rand_scalar;
+    use crate::transaction::spend::description::{sign_spend_description, UnsignedSpendDescription};
+    use crate::transaction::spend::errors::SpendDescriptionError;
+
+    use super::*;
+
+    fn unsigned_spend_description(xsk: &ExtendedSpendingKey, ar: jubjub::Scalar) -> UnsignedSpendDescription {
+        let rsk = PrivateKey(xsk.expsk.ask).randomize(ar);
+
+        UnsignedSpendDescription {
+            cv: jubjub::ExtendedPoint::identity(),
+            anchor: bls12_381::Scalar::zero(),
+            nullifier: Nullifier([0u8; 32]),
+            rk: PublicKey::from_private(&rsk, SPENDING_KEY_GENERATOR),
+            zkproof: [0u8; GROTH_PROOF_SIZE],
+        }
+    }
+
+    #[test]
+    fn signs_spend_description_with_any_signer() {
+        let xsk = ExtendedSpendingKey::from_seed(&[0u8; 32], "m/").unwrap();
+        let ar = rand_scalar();
+        let sighash = [7u8; 32];
+
+        let signers: Vec<Box<dyn SpendAuthSigner>> = vec![
+            Box::new(ExtendedSpendingKey::from_seed(&[0u8; 32], "m/").unwrap()),
+            Box::new(LocalSpendAuthSigner::from(&xsk)),
+        ];
+
+        for signer in signers.iter() {
+            let spend_description = sign_spend_description(unsigned_spend_description(&xsk, ar), signer.as_ref(), ar, sighash).unwrap();
+            let spend_auth_sig = spend_description.spend_auth_sig.unwrap();
+
+            assert!(verify_spend_auth_sig(&spend_description.rk, sighash, &spend_auth_sig));
+            assert!(!verify_spend_auth_sig(&spend_description.rk, [8u8; 32], &spend_auth_sig));
+        }
+    }
+
+    #[test]
+    fn rejects_signature_of_other_key() {
+        let xsk = ExtendedSpendingKey::from_seed(&[0u8; 32], "m/").unwrap();
+        let other_signer = LocalSpendAuthSigner::from(&ExtendedSpendingKey::from_seed(&[1u8; 32], "m/").unwrap());
+        let ar = rand_scalar();
+
+        let actual = sign_spend_description(unsigned_spend_description(&xsk, ar), &other_signer, ar, [7u8; 32]);
+
+        assert_eq!(actual.err(), Some(SaplingError::caused_by(SpendDescriptionError::InvalidSpendAuthSig)));
+    }
+}
@@ -0,0 +1,122 @@
+use zcash_primitives::constants::VALUE_COMMITMENT_VALUE_GENERATOR;
+use zcash_primitives::primitives::ValueCommitment;
+use zcash_primitives::transaction::components::Amount;
+
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::traits::Serializable;
+use crate::common::utils::deserialize_utils::read_chunks;
+use crate::transaction::errors::SignatureError;
+
+// cv = value * G_v + rcv * G_r, as computed by the proving contexts for each description.
+pub fn compute_value_commitment(value: u64, rcv: jubjub::Scalar) -> jubjub::ExtendedPoint {
+    ValueCommitment { value, randomness: rcv }.commitment().into()
+}
+
+pub fn sum_value_commitments(cvs: &[jubjub::ExtendedPoint]) -> jubjub::ExtendedPoint {
+    cvs.iter().sum()
+}
+
+pub fn sum_trapdoors(rcvs: &[jubjub::Scalar]) -> jubjub::Scalar {
+    rcvs.iter().fold(jubjub::Scalar::zero(), |sum, rcv| sum + rcv)
+}
+
+// Outputs subtract from the total, so the binding key is the difference of the spend and output trapdoors.
+pub fn compute_bsk(spend_rcvs: &[jubjub::Scalar], output_rcvs: &[jubjub::Scalar]) -> jubjub::Scalar {
+    sum_trapdoors(spend_rcvs) - sum_trapdoors(output_rcvs)
+}
+
+// bvk = Σ cv_spend - Σ cv_output - value_balance * G_v, which equals bsk * G_r if the transaction is balanced.
+pub fn compute_bvk(
+    spend_cvs: &[jubjub::ExtendedPoint],
+    output_cvs: &[jubjub::ExtendedPoint],
+    value_balance: i64,
+) -> Result<jubjub::ExtendedPoint, SaplingError> {
+    let value_balance = Amount::from_i64(value_balance)
+        .ok()
+        .and_then(compute_value_balance)
+        .ok_or(SignatureError::ValueBalanceOutsideRange)
+        .map_err(SaplingError::caused_by)?;
+
+    Ok(sum_value_commitments(spend_cvs) - sum_value_commitments(output_cvs) - value_balance)
+}
+
+pub(crate) fn compute_value_balance(value: Amount) -> Option<jubjub::ExtendedPoint> {
+    let abs = i64::from(value).checked_abs()? as u64;
+    let value_balance = VALUE_COMMITMENT_VALUE_GENERATOR * jubjub::Scalar::from(abs);
+
+    if value.is_negative() {
+        Some((-value_balance).into())
+    } else {
+        Some(value_balance.into())
+    }
+}
+
+// Concatenated 32 byte value commitments, as passed through the bindings.
+pub fn value_commitments_from_bytes(bytes: &[u8]) -> Result<Vec<jubjub::ExtendedPoint>, SaplingError> {
+    read_chunks("value commitments", bytes, |chunk| jubjub::ExtendedPoint::deserialize(chunk.to_vec()))
+}
+
+// Concatenated 32 byte trapdoors, as passed through the bindings.
+pub fn trapdoors_from_bytes(bytes: &[u8]) -> Result<Vec<jubjub::Scalar>, SaplingError> {
+    read_chunks("value commitment trapdoors", bytes, |chunk| jubjub::Scalar::deserialize(chunk.to_vec()))
+}
+
+#[cfg(test)]
+mod tests {
+    use group::GroupEncoding;
+    use zcash_primitives::constants::VALUE_COMMITMENT_RANDOMNESS_GENERATOR;
+    use zcash_primitives::redjubjub::PublicKey;
+
+    use crate::transaction::{create_binding_sig_with_bsk, rand_scalar};
+
+    use super::*;
+
+    #[test]
+    fn value_commitments_are_homomorphic() {
+        let (rcv1, rcv2) = (rand_scalar(), rand_scalar());
+
+        let sum = sum_value_commitments(&[compute_value_commitment(30, rcv1), compute_value_commitment(12, rcv2)]);
+
+        assert_eq!(sum, compute_value_commitment(42, sum_trapdoors(&[rcv1, rcv2])));
+    }
+
+    #[test]
+    fn computes_matching_binding_keys() {
+        let sighash = [3u8; 32];
+        let spend_rcvs = vec![rand_scalar(), rand_scalar()];
+        let output_rcvs = vec![rand_scalar()];
+
+        let spend_cvs = vec![compute_value_commitment(100, spend_rcvs[0]), compute_value_commitment(50, spend_rcvs[1])];
+        let output_cvs = vec![compute_value_commitment(120, output_rcvs[0])];
+
+        let bsk = compute_bsk(&spend_rcvs, &output_rcvs);
+        let bvk = compute_bvk(&spend_cvs, &output_cvs, 30).unwrap();
+        assert_eq!(bvk, jubjub::ExtendedPoint::from(VALUE_COMMITMENT_RANDOMNESS_GENERATOR * bsk));
+        assert_ne!(compute_bvk(&spend_cvs, &output_cvs, 29).unwrap(), bvk);
+
+        let binding_sig = create_binding_sig_with_bsk(&bsk, sighash);
+        let mut data_to_be_signed = [0u8; 64];
+        data_to_be_signed[0..32].copy_from_slice(&bvk.to_bytes());
+        data_to_be_signed[32..64].copy_from_slice(&sighash);
+
+        assert!(PublicKey(bvk).verify(&data_to_be_signed, &binding_sig, VALUE_COMMITMENT_RANDOMNESS_GENERATOR));
+    }
+
+    #[test]
+    fn rejects_value_balance_out_of_range() {
+        let error = compute_bvk(&[], &[], i64::MIN).err().unwrap();
+
+        assert_eq!(error, SaplingError::caused_by(SignatureError::ValueBalanceOutsideRange));
+    }
+
+    #[test]
+    fn reads_lists_from_bytes() {
+        let rcvs = vec![rand_scalar(), rand_scalar()];
+        let bytes: Vec<u8> = rcvs.iter().flat_map(|rcv| rcv.to_bytes().to_vec()).collect();
+
+        assert_eq!(trapdoors_from_bytes(&bytes).unwrap(), rcvs);
+        assert_eq!(trapdoors_from_bytes(&[]).unwrap(), vec![]);
+        assert!(trapdoors_from_bytes(&bytes[1..]).is_err());
+        assert!(value_commitments_from_bytes(&[0xffu8; 32]).is_err());
+    }
+}
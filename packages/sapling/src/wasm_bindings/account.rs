@@ -0,0 +1,72 @@
+use std::convert::TryFrom;
+
+use wasm_bindgen::prelude::*;
+
+use crate::common::errors::{CausedBy, SaplingError};
+use crate::common::traits::Serializable;
+use crate::common::utils::wasm_utils::{js_error_from, js_serialize, js_serialize_res};
+use crate::key::{Account, account_path, CoinType, get_accounts, split_bip32_path, validate_zip32_path};
+use crate::wasm_bindings::init::wasm_init_lib;
+
+#[wasm_bindgen(catch, js_name = "accountXsk")]
+pub fn wasm_account_xsk(seed: &[u8], coin_type: u32, account: u32) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let coin_type = CoinType::try_from(coin_type).map_err(SaplingError::caused_by).map_err(js_error_from)?;
+    let account = Account::from_seed(seed, coin_type, account).map_err(js_error_from)?;
+
+    js_serialize(account.xsk)
+}
+
+#[wasm_bindgen(catch, js_name = "accountXfvk")]
+pub fn wasm_account_xfvk(seed: &[u8], coin_type: u32, account: u32) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let coin_type = CoinType::try_from(coin_type).map_err(SaplingError::caused_by).map_err(js_error_from)?;
+    let account = Account::from_seed(seed, coin_type, account).map_err(js_error_from)?;
+
+    js_serialize(account.xfvk())
+}
+
+#[wasm_bindgen(catch, js_name = "accountDefaultAddress")]
+pub fn wasm_account_default_address(seed: &[u8], coin_type: u32, account: u32) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let coin_type = CoinType::try_from(coin_type).map_err(SaplingError::caused_by).map_err(js_error_from)?;
+    let account = Account::from_seed(seed, coin_type, account).map_err(js_error_from)?;
+
+    js_serialize_res(account.default_address())
+}
+
+// Returns the serialized default addresses (54 bytes each) of the accounts `start..start + count` concatenated.
+#[wasm_bindgen(catch, js_name = "accountDefaultAddresses")]
+pub fn wasm_account_default_addresses(seed: &[u8], coin_type: u32, start: u32, count: u32) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let coin_type = CoinType::try_from(coin_type).map_err(SaplingError::caused_by).map_err(js_error_from)?;
+    let accounts = get_accounts(seed, coin_type, start, count as usize).map_err(js_error_from)?;
+    let addresses = accounts.iter()
+        .map(|account| account.default_address()?.serialize())
+        .collect::<Result<Vec<Vec<u8>>, SaplingError>>()
+        .map_err(js_error_from)?;
+
+    Ok(addresses.concat())
+}
+
+#[wasm_bindgen(catch, js_name = "accountPath")]
+pub fn wasm_account_path(coin_type: u32, account: u32) -> Result<String, JsValue> {
+    wasm_init_lib();
+
+    let coin_type = CoinType::try_from(coin_type).map_err(SaplingError::caused_by).map_err(js_error_from)?;
+
+    Ok(account_path(coin_type, account))
+}
+
+#[wasm_bindgen(catch, js_name = "validateZip32Path")]
+pub fn wasm_validate_zip32_path(derivation_path: &str) -> Result<bool, JsValue> {
+    wasm_init_lib();
+
+    let path = split_bip32_path(derivation_path).map_err(js_error_from)?;
+
+    Ok(validate_zip32_path(&path).is_ok())
+}
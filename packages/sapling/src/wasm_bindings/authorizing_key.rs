@@ -13,4 +13,13 @@ pub fn wasm_pak_from_xsk(xsk: &[u8]) -> Result<Vec<u8>, JsValue> {
     let pak = xsk.expsk.proof_generation_key();
 
     js_serialize(pak)
+}
+
+#[wasm_bindgen(catch, js_name = "askFromXsk")]
+pub fn wasm_ask_from_xsk(xsk: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let xsk: ExtendedSpendingKey = js_deserialize(xsk)?;
+
+    js_serialize(xsk.expsk.ask)
 }
\ No newline at end of file
@@ -0,0 +1,55 @@
+use wasm_bindgen::prelude::*;
+
+use crate::common::utils::wasm_utils::{js_deserialize, js_error_from, js_serialize, js_serialize_res};
+use crate::transaction::{CommitmentTreeFrontier, node_bytes, node_from_bytes, nodes_from_bytes};
+use crate::wasm_bindings::init::wasm_init_lib;
+
+#[wasm_bindgen(catch, js_name = "frontierFromCommitments")]
+pub fn wasm_frontier_from_commitments(commitments: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let commitments = nodes_from_bytes(commitments).map_err(js_error_from)?;
+
+    js_serialize_res(CommitmentTreeFrontier::from_commitments(&commitments))
+}
+
+#[wasm_bindgen(catch, js_name = "frontierFromRightmostPath")]
+pub fn wasm_frontier_from_rightmost_path(size: u64, leaf: &[u8], path: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let leaf = node_from_bytes(leaf).map_err(js_error_from)?;
+    let path = nodes_from_bytes(path).map_err(js_error_from)?;
+
+    js_serialize_res(CommitmentTreeFrontier::from_rightmost_path(size, leaf, &path))
+}
+
+#[wasm_bindgen(catch, js_name = "frontierAppend")]
+pub fn wasm_frontier_append(frontier: &[u8], commitments: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let mut frontier: CommitmentTreeFrontier = js_deserialize(frontier)?;
+    let commitments = nodes_from_bytes(commitments).map_err(js_error_from)?;
+
+    commitments.into_iter().try_for_each(|commitment| frontier.append(commitment)).map_err(js_error_from)?;
+
+    js_serialize(frontier)
+}
+
+#[wasm_bindgen(catch, js_name = "frontierRoot")]
+pub fn wasm_frontier_root(frontier: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let frontier: CommitmentTreeFrontier = js_deserialize(frontier)?;
+
+    Ok(node_bytes(&frontier.root()).to_vec())
+}
+
+#[wasm_bindgen(catch, js_name = "frontierValidateTezosState")]
+pub fn wasm_frontier_validate_tezos_state(frontier: &[u8], size: u64, root: &[u8]) -> Result<bool, JsValue> {
+    wasm_init_lib();
+
+    let frontier: CommitmentTreeFrontier = js_deserialize(frontier)?;
+    let root = node_from_bytes(root).map_err(js_error_from)?;
+
+    Ok(frontier.validate(size, root).is_ok())
+}
@@ -0,0 +1,171 @@
+use std::convert::TryInto;
+
+use wasm_bindgen::prelude::*;
+
+use crate::common::utils::wasm_utils::{js_dereference, js_deserialize, js_drop_reference, js_error_from, js_reference, js_result_from, js_serialize, js_serialize_res, js_take_reference};
+use crate::key::{
+    dkg_public_key_package,
+    dkg_round1,
+    dkg_round2,
+    dkg_round3,
+    DkgRound1Secret,
+    FrostSpendAuthSigner,
+    frost_aggregate,
+    frost_sign,
+    KeyPackage,
+    PublicKeyPackage,
+    read_frost_messages,
+    signing_nonces,
+    SigningNonces,
+    SigningPackage,
+};
+use crate::transaction::{sign_spend_description, UnsignedSpendDescription};
+use crate::wasm_bindings::init::wasm_init_lib;
+
+#[wasm_bindgen(catch, js_name = "frostDkgRound1")]
+pub fn wasm_frost_dkg_round1(identifier: u16, min_signers: u16, max_signers: u16) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    js_serialize_res(dkg_round1(identifier, min_signers, max_signers))
+}
+
+#[wasm_bindgen(catch, js_name = "frostDkgRound1Package")]
+pub fn wasm_frost_dkg_round1_package(secret: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let secret: DkgRound1Secret = js_deserialize(secret)?;
+
+    js_serialize(secret.package())
+}
+
+#[wasm_bindgen(catch, js_name = "frostDkgRound2")]
+pub fn wasm_frost_dkg_round2(secret: &[u8], round1_packages: &[u8], receiver: u16) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let secret: DkgRound1Secret = js_deserialize(secret)?;
+    let round1_packages = read_frost_messages(round1_packages).map_err(js_error_from)?;
+
+    let package = dkg_round2(&secret, &round1_packages).map_err(js_error_from)?
+        .into_iter()
+        .find(|package| package.receiver() == receiver);
+
+    match package {
+        Some(package) => js_serialize(package),
+        None => js_result_from("frostDkgRound2: unknown receiver"),
+    }
+}
+
+#[wasm_bindgen(catch, js_name = "frostDkgRound3")]
+pub fn wasm_frost_dkg_round3(secret: &[u8], round1_packages: &[u8], round2_packages: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let secret: DkgRound1Secret = js_deserialize(secret)?;
+    let round1_packages = read_frost_messages(round1_packages).map_err(js_error_from)?;
+    let round2_packages = read_frost_messages(round2_packages).map_err(js_error_from)?;
+
+    js_serialize_res(dkg_round3(&secret, &round1_packages, &round2_packages))
+}
+
+#[wasm_bindgen(catch, js_name = "frostDkgPublicKeyPackage")]
+pub fn wasm_frost_dkg_public_key_package(secret: &[u8], round1_packages: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let secret: DkgRound1Secret = js_deserialize(secret)?;
+    let round1_packages = read_frost_messages(round1_packages).map_err(js_error_from)?;
+
+    js_serialize_res(dkg_public_key_package(&secret, &round1_packages))
+}
+
+#[wasm_bindgen(catch, js_name = "frostGroupAk")]
+pub fn wasm_frost_group_ak(public_key_package: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let public_key_package: PublicKeyPackage = js_deserialize(public_key_package)?;
+
+    js_serialize(public_key_package.ak())
+}
+
+#[wasm_bindgen(catch, js_name = "frostProofGenerationKey")]
+pub fn wasm_frost_proof_generation_key(public_key_package: &[u8], nsk: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let public_key_package: PublicKeyPackage = js_deserialize(public_key_package)?;
+    let nsk: jubjub::Scalar = js_deserialize(nsk)?;
+
+    js_serialize(public_key_package.proof_generation_key(nsk))
+}
+
+// The nonces are freed by `frostSign`, `dropFrostSigningNonces` is only needed if signing is abandoned.
+#[wasm_bindgen(catch, js_name = "frostSigningNonces")]
+pub fn wasm_frost_signing_nonces(key_package: &[u8]) -> Result<u32, JsValue> {
+    wasm_init_lib();
+
+    let key_package: KeyPackage = js_deserialize(key_package)?;
+
+    Ok(js_reference(signing_nonces(&key_package)))
+}
+
+#[wasm_bindgen(catch, js_name = "dropFrostSigningNonces")]
+pub fn wasm_drop_frost_signing_nonces(nonces: u32) {
+    wasm_init_lib();
+    unsafe { js_drop_reference::<SigningNonces>(nonces) }
+}
+
+#[wasm_bindgen(catch, js_name = "frostSigningCommitments")]
+pub fn wasm_frost_signing_commitments(nonces: u32) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let nonces: &mut SigningNonces = unsafe { js_dereference(nonces) };
+
+    js_serialize(nonces.commitments())
+}
+
+#[wasm_bindgen(catch, js_name = "frostSigningPackage")]
+pub fn wasm_frost_signing_package(ar: &[u8], sighash: &[u8], commitments: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let ar: jubjub::Scalar = js_deserialize(ar)?;
+    let sighash: [u8; 32] = sighash.try_into()
+        .or_else(|_| js_result_from("frostSigningPackage: sighash must be an array of 32 bytes"))?;
+    let commitments = read_frost_messages(commitments).map_err(js_error_from)?;
+
+    js_serialize_res(SigningPackage::new(ar, sighash, commitments))
+}
+
+// Frees `nonces`, whether signing succeeds or not.
+#[wasm_bindgen(catch, js_name = "frostSign")]
+pub fn wasm_frost_sign(key_package: &[u8], nonces: u32, package: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let nonces: SigningNonces = unsafe { js_take_reference(nonces) };
+    let key_package: KeyPackage = js_deserialize(key_package)?;
+    let package: SigningPackage = js_deserialize(package)?;
+
+    js_serialize_res(frost_sign(&key_package, nonces, &package))
+}
+
+#[wasm_bindgen(catch, js_name = "frostAggregate")]
+pub fn wasm_frost_aggregate(package: &[u8], shares: &[u8], public_key_package: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let package: SigningPackage = js_deserialize(package)?;
+    let shares = read_frost_messages(shares).map_err(js_error_from)?;
+    let public_key_package: PublicKeyPackage = js_deserialize(public_key_package)?;
+
+    js_serialize_res(frost_aggregate(&package, &shares, &public_key_package))
+}
+
+#[wasm_bindgen(catch, js_name = "frostSignSpendDescription")]
+pub fn wasm_frost_sign_spend_description(spend_description: &[u8], package: &[u8], shares: &[u8], public_key_package: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let spend_description: UnsignedSpendDescription = js_deserialize(spend_description)?;
+    let package: SigningPackage = js_deserialize(package)?;
+    let shares = read_frost_messages(shares).map_err(js_error_from)?;
+    let public_key_package: PublicKeyPackage = js_deserialize(public_key_package)?;
+
+    let signer = FrostSpendAuthSigner::new(&package, &shares, &public_key_package);
+    let spend_description = sign_spend_description(spend_description, &signer, package.ar(), package.sighash());
+
+    js_serialize_res(spend_description)
+}
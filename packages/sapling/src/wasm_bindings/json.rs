@@ -0,0 +1,130 @@
+use wasm_bindgen::prelude::*;
+use zcash_primitives::keys::OutgoingViewingKey;
+use zcash_primitives::primitives::{PaymentAddress, ProofGenerationKey};
+use zcash_primitives::transaction::components::{OutputDescription, SpendDescription};
+use zcash_primitives::zip32::{ExtendedFullViewingKey, ExtendedSpendingKey};
+
+use crate::address::IndexedAddress;
+use crate::common::utils::wasm_utils::{js_deserialize, js_from_object, js_serialize, js_to_object};
+use crate::transaction::{PartialOutputDescription, UnsignedSpendDescription};
+use crate::wasm_bindings::init::wasm_init_lib;
+
+#[wasm_bindgen(catch, js_name = "unsignedSpendDescriptionToObject")]
+pub fn wasm_unsigned_spend_description_to_object(description: &[u8]) -> Result<JsValue, JsValue> {
+    wasm_init_lib();
+    js_to_object(&js_deserialize::<UnsignedSpendDescription, _>(description)?)
+}
+
+#[wasm_bindgen(catch, js_name = "unsignedSpendDescriptionFromObject")]
+pub fn wasm_unsigned_spend_description_from_object(object: JsValue) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+    js_serialize(js_from_object::<UnsignedSpendDescription>(&object)?)
+}
+
+#[wasm_bindgen(catch, js_name = "spendDescriptionToObject")]
+pub fn wasm_spend_description_to_object(description: &[u8]) -> Result<JsValue, JsValue> {
+    wasm_init_lib();
+    js_to_object(&js_deserialize::<SpendDescription, _>(description)?)
+}
+
+#[wasm_bindgen(catch, js_name = "spendDescriptionFromObject")]
+pub fn wasm_spend_description_from_object(object: JsValue) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+    js_serialize(js_from_object::<SpendDescription>(&object)?)
+}
+
+#[wasm_bindgen(catch, js_name = "outputDescriptionToObject")]
+pub fn wasm_output_description_to_object(description: &[u8]) -> Result<JsValue, JsValue> {
+    wasm_init_lib();
+    js_to_object(&js_deserialize::<OutputDescription, _>(description)?)
+}
+
+#[wasm_bindgen(catch, js_name = "outputDescriptionFromObject")]
+pub fn wasm_output_description_from_object(object: JsValue) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+    js_serialize(js_from_object::<OutputDescription>(&object)?)
+}
+
+#[wasm_bindgen(catch, js_name = "partialOutputDescriptionToObject")]
+pub fn wasm_partial_output_description_to_object(description: &[u8]) -> Result<JsValue, JsValue> {
+    wasm_init_lib();
+    js_to_object(&js_deserialize::<PartialOutputDescription, _>(description)?)
+}
+
+#[wasm_bindgen(catch, js_name = "partialOutputDescriptionFromObject")]
+pub fn wasm_partial_output_description_from_object(object: JsValue) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+    js_serialize(js_from_object::<PartialOutputDescription>(&object)?)
+}
+
+#[wasm_bindgen(catch, js_name = "paymentAddressToObject")]
+pub fn wasm_payment_address_to_object(address: &[u8]) -> Result<JsValue, JsValue> {
+    wasm_init_lib();
+    js_to_object(&js_deserialize::<PaymentAddress, _>(address)?)
+}
+
+#[wasm_bindgen(catch, js_name = "paymentAddressFromObject")]
+pub fn wasm_payment_address_from_object(object: JsValue) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+    js_serialize(js_from_object::<PaymentAddress>(&object)?)
+}
+
+#[wasm_bindgen(catch, js_name = "indexedAddressToObject")]
+pub fn wasm_indexed_address_to_object(address: &[u8]) -> Result<JsValue, JsValue> {
+    wasm_init_lib();
+    js_to_object(&js_deserialize::<IndexedAddress, _>(address)?)
+}
+
+#[wasm_bindgen(catch, js_name = "indexedAddressFromObject")]
+pub fn wasm_indexed_address_from_object(object: JsValue) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+    js_serialize(js_from_object::<IndexedAddress>(&object)?)
+}
+
+#[wasm_bindgen(catch, js_name = "xskToObject")]
+pub fn wasm_xsk_to_object(xsk: &[u8]) -> Result<JsValue, JsValue> {
+    wasm_init_lib();
+    js_to_object(&js_deserialize::<ExtendedSpendingKey, _>(xsk)?)
+}
+
+#[wasm_bindgen(catch, js_name = "xskFromObject")]
+pub fn wasm_xsk_from_object(object: JsValue) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+    js_serialize(js_from_object::<ExtendedSpendingKey>(&object)?)
+}
+
+#[wasm_bindgen(catch, js_name = "xfvkToObject")]
+pub fn wasm_xfvk_to_object(xfvk: &[u8]) -> Result<JsValue, JsValue> {
+    wasm_init_lib();
+    js_to_object(&js_deserialize::<ExtendedFullViewingKey, _>(xfvk)?)
+}
+
+#[wasm_bindgen(catch, js_name = "xfvkFromObject")]
+pub fn wasm_xfvk_from_object(object: JsValue) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+    js_serialize(js_from_object::<ExtendedFullViewingKey>(&object)?)
+}
+
+#[wasm_bindgen(catch, js_name = "pakToObject")]
+pub fn wasm_pak_to_object(pak: &[u8]) -> Result<JsValue, JsValue> {
+    wasm_init_lib();
+    js_to_object(&js_deserialize::<ProofGenerationKey, _>(pak)?)
+}
+
+#[wasm_bindgen(catch, js_name = "pakFromObject")]
+pub fn wasm_pak_from_object(object: JsValue) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+    js_serialize(js_from_object::<ProofGenerationKey>(&object)?)
+}
+
+#[wasm_bindgen(catch, js_name = "ovkToObject")]
+pub fn wasm_ovk_to_object(ovk: &[u8]) -> Result<JsValue, JsValue> {
+    wasm_init_lib();
+    js_to_object(&js_deserialize::<OutgoingViewingKey, _>(ovk)?)
+}
+
+#[wasm_bindgen(catch, js_name = "ovkFromObject")]
+pub fn wasm_ovk_from_object(object: JsValue) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+    js_serialize(js_from_object::<OutgoingViewingKey>(&object)?)
+}
@@ -0,0 +1,53 @@
+use wasm_bindgen::prelude::*;
+
+use zcash_primitives::zip32::ExtendedSpendingKey;
+
+use crate::common::utils::wasm_utils::{js_deserialize, js_error_from, js_serialize_res};
+use crate::key::{EncryptedKeystore, KeystoreContent};
+use crate::wasm_bindings::init::wasm_init_lib;
+
+#[wasm_bindgen(catch, js_name = "keystoreEncryptXsk")]
+pub fn wasm_keystore_encrypt_xsk(xsk: &[u8], password: &str) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let xsk: ExtendedSpendingKey = js_deserialize(xsk)?;
+    let keystore = EncryptedKeystore::encrypt(&KeystoreContent::Xsk(xsk), password.as_bytes());
+
+    js_serialize_res(keystore)
+}
+
+#[wasm_bindgen(catch, js_name = "keystoreEncryptSeed")]
+pub fn wasm_keystore_encrypt_seed(seed: &[u8], password: &str) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let keystore = EncryptedKeystore::encrypt(&KeystoreContent::Seed(seed.to_vec()), password.as_bytes());
+
+    js_serialize_res(keystore)
+}
+
+#[wasm_bindgen(catch, js_name = "keystoreDecryptXsk")]
+pub fn wasm_keystore_decrypt_xsk(keystore: &[u8], password: &str) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let keystore: EncryptedKeystore = js_deserialize(keystore)?;
+
+    js_serialize_res(keystore.decrypt_xsk(password.as_bytes()))
+}
+
+#[wasm_bindgen(catch, js_name = "keystoreDecryptSeed")]
+pub fn wasm_keystore_decrypt_seed(keystore: &[u8], password: &str) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let keystore: EncryptedKeystore = js_deserialize(keystore)?;
+
+    keystore.decrypt_seed(password.as_bytes()).map_err(js_error_from)
+}
+
+#[wasm_bindgen(catch, js_name = "keystoreFingerprint")]
+pub fn wasm_keystore_fingerprint(keystore: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let keystore: EncryptedKeystore = js_deserialize(keystore)?;
+
+    Ok(keystore.fingerprint.to_vec())
+}
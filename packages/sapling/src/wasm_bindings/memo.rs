@@ -0,0 +1,55 @@
+use wasm_bindgen::prelude::*;
+
+use crate::common::utils::wasm_utils::js_error_from;
+use crate::transaction::Memo;
+use crate::wasm_bindings::init::wasm_init_lib;
+
+#[wasm_bindgen(catch, js_name = "emptyMemo")]
+pub fn wasm_empty_memo(memo_size: usize) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    Memo::Empty.encode(memo_size).map_err(js_error_from)
+}
+
+#[wasm_bindgen(catch, js_name = "textMemo")]
+pub fn wasm_text_memo(text: &str, memo_size: usize) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    Memo::Text(String::from(text)).encode(memo_size).map_err(js_error_from)
+}
+
+#[wasm_bindgen(catch, js_name = "arbitraryMemo")]
+pub fn wasm_arbitrary_memo(data: &[u8], memo_size: usize) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    Memo::Arbitrary(data.to_vec()).encode(memo_size).map_err(js_error_from)
+}
+
+// Returns one of `empty`, `text`, `arbitrary` or `future`.
+#[wasm_bindgen(catch, js_name = "memoType")]
+pub fn wasm_memo_type(memo: &[u8]) -> Result<String, JsValue> {
+    wasm_init_lib();
+
+    let memo = Memo::decode(memo).map_err(js_error_from)?;
+
+    Ok(String::from(memo.type_name()))
+}
+
+#[wasm_bindgen(catch, js_name = "memoText")]
+pub fn wasm_memo_text(memo: &[u8]) -> Result<String, JsValue> {
+    wasm_init_lib();
+
+    match Memo::decode(memo).map_err(js_error_from)? {
+        Memo::Text(text) => Ok(text),
+        memo => Err(js_error_from(format!("memoText: expected a text memo, got {}", memo.type_name()))),
+    }
+}
+
+#[wasm_bindgen(catch, js_name = "memoContent")]
+pub fn wasm_memo_content(memo: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let memo = Memo::decode(memo).map_err(js_error_from)?;
+
+    Ok(memo.content().to_vec())
+}
@@ -0,0 +1,38 @@
+use wasm_bindgen::prelude::*;
+use zcash_primitives::primitives::PaymentAddress;
+use zcash_primitives::zip32::ExtendedFullViewingKey;
+
+use crate::common::utils::wasm_utils::{js_deserialize, js_error_from, js_serialize_res};
+use crate::key::IncomingViewingKey;
+use crate::transaction::{decrypt_message, EncryptedMessage, encrypt_message};
+use crate::wasm_bindings::init::wasm_init_lib;
+
+#[wasm_bindgen(catch, js_name = "encryptMessage")]
+pub fn wasm_encrypt_message(address: &[u8], message: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let address: PaymentAddress = js_deserialize(address)?;
+    let encrypted = encrypt_message(&address, message);
+
+    js_serialize_res(encrypted)
+}
+
+#[wasm_bindgen(catch, js_name = "decryptMessageWithXfvk")]
+pub fn wasm_decrypt_message_with_xfvk(xfvk: &[u8], encrypted: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let xfvk: ExtendedFullViewingKey = js_deserialize(xfvk)?;
+    let encrypted: EncryptedMessage = js_deserialize(encrypted)?;
+
+    decrypt_message(&xfvk.fvk.vk.ivk(), &encrypted).map_err(js_error_from)
+}
+
+#[wasm_bindgen(catch, js_name = "decryptMessageWithIvk")]
+pub fn wasm_decrypt_message_with_ivk(ivk: &[u8], encrypted: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let ivk: IncomingViewingKey = js_deserialize(ivk)?;
+    let encrypted: EncryptedMessage = js_deserialize(encrypted)?;
+
+    decrypt_message(&ivk.to_sapling_ivk(), &encrypted).map_err(js_error_from)
+}
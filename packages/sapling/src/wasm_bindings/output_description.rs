@@ -10,8 +10,9 @@ use zcash_proofs::sapling::SaplingProvingContext;
 use zcash_proofs::ZcashParameters;
 
 use crate::common::utils::wasm_utils::{js_dereference, js_deserialize, js_error_from, js_result_from, js_serialize_res};
+use crate::key::IncomingViewingKey;
 use crate::State;
-use crate::transaction::{derive_epk, OutputDetails, prepare_output_description, prepare_partial_output_description};
+use crate::transaction::{decrypt_note_ciphertext, derive_epk, OutputDetails, prepare_output_description, prepare_partial_output_description, prepare_sized_output_description, prepare_sized_output_description_with_esk, SizedOutputDescription};
 use crate::wasm_bindings::init::wasm_init_lib;
 
 #[wasm_bindgen(catch, js_name = "outputDescriptionFromXfvk")]
@@ -66,6 +67,81 @@ pub fn wasm_output_description_from_xfvk_with_memo(ctx: u32, xfvk: &[u8], to: &[
     js_serialize_res(output_description)
 }
 
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(catch, js_name = "outputDescriptionFromXfvkWithMemoSize")]
+pub fn wasm_output_description_from_xfvk_with_memo_size(
+    ctx: u32,
+    xfvk: &[u8],
+    to: &[u8],
+    rcm: &[u8],
+    value: &str,
+    memo: &[u8],
+    memo_size: usize,
+) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let xfvk: ExtendedFullViewingKey = js_deserialize(xfvk)?;
+    let address: PaymentAddress = js_deserialize(to)?;
+    let rcm: jubjub::Scalar = js_deserialize(rcm)?;
+    let value: u64 = value.parse().or_else(|_| js_result_from("outputDescriptionFromXfvkWithMemoSize: invalid value"))?;
+
+    let ctx: &mut SaplingProvingContext = unsafe { js_dereference(ctx) };
+
+    let params: &ZcashParameters = State::proof_params().map_err(js_error_from)?;
+    let proving_key: &Parameters<Bls12> = &params.output_params;
+
+    let output_description = prepare_sized_output_description(
+        ctx,
+        xfvk.fvk.ovk,
+        OutputDetails { to_address: address, value },
+        rcm,
+        Some(memo).filter(|memo| !memo.is_empty()),
+        memo_size,
+        proving_key
+    );
+
+    js_serialize_res(output_description)
+}
+
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(catch, js_name = "outputDescriptionFromXfvkWithEsk")]
+pub fn wasm_output_description_from_xfvk_with_esk(
+    ctx: u32,
+    xfvk: &[u8],
+    to: &[u8],
+    rcm: &[u8],
+    esk: &[u8],
+    value: &str,
+    memo: &[u8],
+    memo_size: usize,
+) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let xfvk: ExtendedFullViewingKey = js_deserialize(xfvk)?;
+    let address: PaymentAddress = js_deserialize(to)?;
+    let rcm: jubjub::Scalar = js_deserialize(rcm)?;
+    let esk: jubjub::Scalar = js_deserialize(esk)?;
+    let value: u64 = value.parse().or_else(|_| js_result_from("outputDescriptionFromXfvkWithEsk: invalid value"))?;
+
+    let ctx: &mut SaplingProvingContext = unsafe { js_dereference(ctx) };
+
+    let params: &ZcashParameters = State::proof_params().map_err(js_error_from)?;
+    let proving_key: &Parameters<Bls12> = &params.output_params;
+
+    let output_description = prepare_sized_output_description_with_esk(
+        ctx,
+        xfvk.fvk.ovk,
+        OutputDetails { to_address: address, value },
+        rcm,
+        esk,
+        Some(memo).filter(|memo| !memo.is_empty()),
+        memo_size,
+        proving_key
+    );
+
+    js_serialize_res(output_description)
+}
+
 #[wasm_bindgen(catch, js_name = "outputDescriptionFromOvk")]
 pub fn wasm_output_description_from_ovk(ctx: u32, ovk: &[u8], to: &[u8], rcm: &[u8], value: &str) -> Result<Vec<u8>, JsValue> {
     wasm_init_lib();
@@ -129,4 +205,40 @@ pub fn wasm_derive_epk_from_esk(diversifier: &[u8], esk: &[u8]) -> Result<Vec<u8
     let epk = derive_epk(diversifier, esk);
 
     js_serialize_res(epk)
+}
+
+#[wasm_bindgen(catch, js_name = "decryptOutputDescriptionWithXfvk")]
+pub fn wasm_decrypt_output_description_with_xfvk(xfvk: &[u8], description: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let xfvk: ExtendedFullViewingKey = js_deserialize(xfvk)?;
+    let description: SizedOutputDescription = js_deserialize(description)?;
+
+    let note = decrypt_note_ciphertext(
+        &xfvk.fvk.vk.ivk(),
+        &description.ephemeral_key,
+        &description.cmu,
+        &description.enc_ciphertext,
+        description.memo_size()
+    );
+
+    js_serialize_res(note)
+}
+
+#[wasm_bindgen(catch, js_name = "decryptOutputDescriptionWithIvk")]
+pub fn wasm_decrypt_output_description_with_ivk(ivk: &[u8], description: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let ivk: IncomingViewingKey = js_deserialize(ivk)?;
+    let description: SizedOutputDescription = js_deserialize(description)?;
+
+    let note = decrypt_note_ciphertext(
+        &ivk.to_sapling_ivk(),
+        &description.ephemeral_key,
+        &description.cmu,
+        &description.enc_ciphertext,
+        description.memo_size()
+    );
+
+    js_serialize_res(note)
 }
\ No newline at end of file
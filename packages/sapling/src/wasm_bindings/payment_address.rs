@@ -3,10 +3,12 @@ use std::convert::TryInto;
 use wasm_bindgen::JsValue;
 use wasm_bindgen::prelude::*;
 use zcash_primitives::primitives::{Diversifier, PaymentAddress};
-use zcash_primitives::zip32::ExtendedFullViewingKey;
+use zcash_primitives::zip32::{ExtendedFullViewingKey, ExtendedSpendingKey};
 
-use crate::address::{get_ivk_address, get_next_xfvk_address, get_xfvk_address};
-use crate::common::utils::wasm_utils::{js_deserialize, js_result_from, js_serialize, js_serialize_res};
+use crate::address::{get_ivk_address, get_next_xfvk_address, get_xfvk_address, get_xfvk_addresses, get_xfvk_diversifier_index, OwnershipProof, sign_address_ownership, verify_address_ownership};
+use crate::common::errors::SaplingError;
+use crate::common::traits::Serializable;
+use crate::common::utils::wasm_utils::{js_deserialize, js_error_from, js_result_from, js_serialize, js_serialize_res};
 use crate::wasm_bindings::init::wasm_init_lib;
 
 #[wasm_bindgen(catch, js_name = "defaultPaymentAddressFromXfvk")]
@@ -45,6 +47,37 @@ pub fn wasm_payment_address_from_xfvk(xfvk: &[u8], index: &[u8]) -> Result<Vec<u
     js_serialize_res(xfvk_address)
 }
 
+// Returns the serialized indexed addresses (54 bytes each) concatenated.
+#[wasm_bindgen(catch, js_name = "paymentAddressesFromXfvk")]
+pub fn wasm_payment_addresses_from_xfvk(xfvk: &[u8], index: &[u8], count: u32) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let index: [u8; 11] = index.try_into()
+        .or_else(|_| js_result_from("paymentAddressesFromXfvk: index must be an array of 11 bytes"))?;
+
+    let xfvk: ExtendedFullViewingKey = js_deserialize(xfvk)?;
+    let addresses = get_xfvk_addresses(&xfvk, Some(index), count as usize).map_err(js_error_from)?;
+    let addresses = addresses.iter()
+        .map(|address| address.serialize())
+        .collect::<Result<Vec<Vec<u8>>, SaplingError>>()
+        .map_err(js_error_from)?;
+
+    Ok(addresses.concat())
+}
+
+#[wasm_bindgen(catch, js_name = "diversifierIndexFromXfvk")]
+pub fn wasm_diversifier_index_from_xfvk(xfvk: &[u8], diversifier: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let diversifier: [u8; 11] = diversifier.try_into()
+        .or_else(|_| js_result_from("diversifierIndexFromXfvk: diversifier must be an array of 11 bytes"))?;
+
+    let xfvk: ExtendedFullViewingKey = js_deserialize(xfvk)?;
+    let index = get_xfvk_diversifier_index(&xfvk, &Diversifier(diversifier)).map_err(js_error_from)?;
+
+    Ok(index.to_vec())
+}
+
 #[wasm_bindgen(catch, js_name = "paymentAddressFromIvk")]
 pub fn wasm_payment_address_from_ivk(ivk: &[u8], diversifier: &[u8]) -> Result<Vec<u8>, JsValue> {
     wasm_init_lib();
@@ -76,4 +109,25 @@ pub fn wasm_pkd_from_payment_address(address: &[u8]) -> Result<Vec<u8>, JsValue>
     let address: PaymentAddress = js_deserialize(address)?;
 
     js_serialize(*address.pk_d())
-}
\ No newline at end of file
+}
+#[wasm_bindgen(catch, js_name = "signAddressOwnership")]
+pub fn wasm_sign_address_ownership(xsk: &[u8], address: &[u8], message: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let xsk: ExtendedSpendingKey = js_deserialize(xsk)?;
+    let address: PaymentAddress = js_deserialize(address)?;
+
+    let proof = sign_address_ownership(&xsk, &address, message).map_err(js_error_from)?;
+
+    js_serialize(proof)
+}
+
+#[wasm_bindgen(catch, js_name = "verifyAddressOwnership")]
+pub fn wasm_verify_address_ownership(address: &[u8], message: &[u8], proof: &[u8]) -> Result<bool, JsValue> {
+    wasm_init_lib();
+
+    let address: PaymentAddress = js_deserialize(address)?;
+    let proof: OwnershipProof = js_deserialize(proof)?;
+
+    Ok(verify_address_ownership(&address, message, &proof).is_ok())
+}
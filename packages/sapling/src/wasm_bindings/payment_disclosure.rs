@@ -0,0 +1,33 @@
+use wasm_bindgen::prelude::*;
+use zcash_primitives::constants::SPENDING_KEY_GENERATOR;
+use zcash_primitives::redjubjub::PublicKey;
+use zcash_primitives::zip32::ExtendedSpendingKey;
+
+use crate::common::utils::wasm_utils::{js_deserialize, js_error_from, js_serialize_res};
+use crate::transaction::{create_payment_disclosure, PaymentDisclosure, SizedOutputDescription, spend_rks_from_bytes, verify_payment_disclosure};
+use crate::wasm_bindings::init::wasm_init_lib;
+
+#[wasm_bindgen(catch, js_name = "paymentDisclosureFromXsk")]
+pub fn wasm_payment_disclosure_from_xsk(xsk: &[u8], ar: &[u8], output_description: &[u8], rcv: Option<Vec<u8>>, message: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let xsk: ExtendedSpendingKey = js_deserialize(xsk)?;
+    let ar: jubjub::Scalar = js_deserialize(ar)?;
+    let output_description: SizedOutputDescription = js_deserialize(output_description)?;
+    let rcv: Option<jubjub::Scalar> = rcv.map(|rcv| js_deserialize(&rcv)).transpose()?;
+
+    let rk = PublicKey(xsk.expsk.proof_generation_key().ak.into()).randomize(ar, SPENDING_KEY_GENERATOR);
+
+    js_serialize_res(create_payment_disclosure(&xsk, ar, rk, &xsk.expsk.ovk, &output_description, rcv, message))
+}
+
+#[wasm_bindgen(catch, js_name = "verifyPaymentDisclosure")]
+pub fn wasm_verify_payment_disclosure(disclosure: &[u8], output_description: &[u8], spend_rks: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let disclosure: PaymentDisclosure = js_deserialize(disclosure)?;
+    let output_description: SizedOutputDescription = js_deserialize(output_description)?;
+    let spend_rks = spend_rks_from_bytes(spend_rks).map_err(js_error_from)?;
+
+    js_serialize_res(verify_payment_disclosure(&disclosure, &output_description, &spend_rks))
+}
@@ -0,0 +1,75 @@
+use wasm_bindgen::prelude::*;
+use zcash_primitives::primitives::PaymentAddress;
+
+use crate::address::{decode_payment_address, encode_payment_address, Payment, PaymentRequest};
+use crate::common::utils::wasm_utils::{js_dereference, js_deserialize, js_drop_reference, js_error_from, js_reference, js_result_from, js_serialize_res};
+use crate::json::to_json;
+use crate::wasm_bindings::init::wasm_init_lib;
+
+#[wasm_bindgen(catch, js_name = "initPaymentRequest")]
+pub fn wasm_init_payment_request() -> u32 {
+    wasm_init_lib();
+    js_reference(PaymentRequest::new())
+}
+
+#[wasm_bindgen(catch, js_name = "dropPaymentRequest")]
+pub fn wasm_drop_payment_request(request: u32) {
+    wasm_init_lib();
+    unsafe { js_drop_reference::<PaymentRequest>(request) }
+}
+
+// `amount` is in mutez.
+#[wasm_bindgen(catch, js_name = "paymentRequestAddPayment")]
+pub fn wasm_payment_request_add_payment(
+    request: u32,
+    address: &[u8],
+    amount: Option<String>,
+    memo: Option<Vec<u8>>,
+    label: Option<String>,
+    message: Option<String>,
+) -> Result<(), JsValue> {
+    wasm_init_lib();
+
+    let address: PaymentAddress = js_deserialize(address)?;
+    let amount: Option<u64> = match amount {
+        Some(amount) => Some(amount.parse().or_else(|_| js_result_from("paymentRequestAddPayment: invalid amount"))?),
+        None => None,
+    };
+
+    let request: &mut PaymentRequest = unsafe { js_dereference(request) };
+    request.add_payment(Payment { address, amount, memo, label, message }).map_err(js_error_from)
+}
+
+#[wasm_bindgen(catch, js_name = "paymentRequestToUri")]
+pub fn wasm_payment_request_to_uri(request: u32) -> Result<String, JsValue> {
+    wasm_init_lib();
+
+    let request: &mut PaymentRequest = unsafe { js_dereference(request) };
+
+    request.to_uri().map_err(js_error_from)
+}
+
+#[wasm_bindgen(catch, js_name = "parsePaymentUri")]
+pub fn wasm_parse_payment_uri(uri: &str) -> Result<String, JsValue> {
+    wasm_init_lib();
+
+    let request: PaymentRequest = uri.parse().map_err(js_error_from)?;
+
+    to_json(&request).map_err(js_error_from)
+}
+
+#[wasm_bindgen(catch, js_name = "encodePaymentAddress")]
+pub fn wasm_encode_payment_address(address: &[u8]) -> Result<String, JsValue> {
+    wasm_init_lib();
+
+    let address: PaymentAddress = js_deserialize(address)?;
+
+    Ok(encode_payment_address(&address))
+}
+
+#[wasm_bindgen(catch, js_name = "decodePaymentAddress")]
+pub fn wasm_decode_payment_address(encoded: &str) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    js_serialize_res(decode_payment_address(encoded))
+}
@@ -0,0 +1,166 @@
+use std::convert::TryInto;
+
+use js_sys::Function;
+use wasm_bindgen::prelude::*;
+use zcash_primitives::keys::OutgoingViewingKey;
+use zcash_primitives::merkle_tree::MerklePath;
+use zcash_primitives::primitives::{PaymentAddress, ProofGenerationKey};
+use zcash_primitives::sapling::Node;
+use zcash_proofs::ZcashParameters;
+
+use crate::common::traits::Serializable;
+use crate::common::utils::wasm_utils::{js_dereference, js_deserialize, js_drop_reference, js_error_from, js_reference, js_result_from, js_serialize_res};
+use crate::State;
+use crate::transaction::{CancellationToken, create_binding_sig, OutputInput, ProvedDescriptions, ProvingBuilder, SpendInput};
+use crate::wasm_bindings::init::wasm_init_lib;
+
+#[wasm_bindgen(catch, js_name = "initProvingBuilder")]
+pub fn wasm_init_proving_builder() -> u32 {
+    wasm_init_lib();
+    js_reference(ProvingBuilder::new())
+}
+
+#[wasm_bindgen(catch, js_name = "dropProvingBuilder")]
+pub fn wasm_drop_proving_builder(builder: u32) {
+    wasm_init_lib();
+    unsafe { js_drop_reference::<ProvingBuilder>(builder) }
+}
+
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(catch, js_name = "provingBuilderAddSpend")]
+pub fn wasm_proving_builder_add_spend(
+    builder: u32,
+    pak: &[u8],
+    address: &[u8],
+    rcm: &[u8],
+    ar: &[u8],
+    value: &str,
+    anchor: &[u8],
+    merkle_path: &[u8],
+) -> Result<(), JsValue> {
+    wasm_init_lib();
+
+    let pak: ProofGenerationKey = js_deserialize(pak)?;
+    let address: PaymentAddress = js_deserialize(address)?;
+    let rcm: jubjub::Scalar = js_deserialize(rcm)?;
+    let ar: jubjub::Scalar = js_deserialize(ar)?;
+    let value: u64 = value.parse().or_else(|_| js_result_from("provingBuilderAddSpend: invalid value"))?;
+    let anchor: bls12_381::Scalar = js_deserialize(anchor)?;
+    let merkle_path: MerklePath<Node> = js_deserialize(merkle_path)?;
+
+    let builder: &mut ProvingBuilder = unsafe { js_dereference(builder) };
+    builder.add_spend(SpendInput { pak, address, value, rcm, ar, anchor, merkle_path });
+
+    Ok(())
+}
+
+#[wasm_bindgen(catch, js_name = "provingBuilderAddOutput")]
+pub fn wasm_proving_builder_add_output(
+    builder: u32,
+    ovk: &[u8],
+    address: &[u8],
+    rcm: &[u8],
+    value: &str,
+    esk: Option<Vec<u8>>,
+    memo: Option<Vec<u8>>,
+) -> Result<(), JsValue> {
+    wasm_init_lib();
+
+    let ovk: [u8; 32] = ovk.try_into().or_else(|_| js_result_from("provingBuilderAddOutput: ovk must be an array of 32 bytes"))?;
+    let address: PaymentAddress = js_deserialize(address)?;
+    let rcm: jubjub::Scalar = js_deserialize(rcm)?;
+    let value: u64 = value.parse().or_else(|_| js_result_from("provingBuilderAddOutput: invalid value"))?;
+    let esk: Option<jubjub::Scalar> = esk.map(|esk| js_deserialize(&esk)).transpose()?;
+
+    let builder: &mut ProvingBuilder = unsafe { js_dereference(builder) };
+    builder.add_output(OutputInput { ovk: OutgoingViewingKey(ovk), address, value, rcm, esk, memo });
+
+    Ok(())
+}
+
+// `on_progress` is called with the number of completed and total descriptions,
+// the cancellation token can be cancelled from within the callback to abort proving before the next proof.
+#[wasm_bindgen(catch, js_name = "provingBuilderProve")]
+pub fn wasm_proving_builder_prove(builder: u32, on_progress: Option<Function>, cancellation_token: Option<u32>) -> Result<u32, JsValue> {
+    wasm_init_lib();
+
+    let builder: &mut ProvingBuilder = unsafe { js_dereference(builder) };
+    let cancellation_token: CancellationToken = match cancellation_token {
+        Some(cancellation_token) => unsafe { js_dereference::<CancellationToken>(cancellation_token) }.clone(),
+        None => CancellationToken::new(),
+    };
+    let params: &ZcashParameters = State::proof_params().map_err(js_error_from)?;
+
+    let proved = builder.prove(
+        params,
+        |completed, total| {
+            if let Some(on_progress) = &on_progress {
+                let _ = on_progress.call2(&JsValue::NULL, &JsValue::from(completed as u32), &JsValue::from(total as u32));
+            }
+        },
+        &cancellation_token
+    ).map_err(js_error_from)?;
+
+    Ok(js_reference(proved))
+}
+
+#[wasm_bindgen(catch, js_name = "dropProvedDescriptions")]
+pub fn wasm_drop_proved_descriptions(proved: u32) {
+    wasm_init_lib();
+    unsafe { js_drop_reference::<ProvedDescriptions>(proved) }
+}
+
+#[wasm_bindgen(catch, js_name = "provedDescriptionsSpendDescription")]
+pub fn wasm_proved_descriptions_spend_description(proved: u32, index: usize) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let proved: &mut ProvedDescriptions = unsafe { js_dereference(proved) };
+    let spend_description = proved.spend_descriptions.get(index)
+        .map_or_else(|| js_result_from("provedDescriptionsSpendDescription: index out of range"), Ok)?;
+
+    spend_description.serialize().map_err(js_error_from)
+}
+
+#[wasm_bindgen(catch, js_name = "provedDescriptionsOutputDescription")]
+pub fn wasm_proved_descriptions_output_description(proved: u32, index: usize) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let proved: &mut ProvedDescriptions = unsafe { js_dereference(proved) };
+    let output_description = proved.output_descriptions.get(index)
+        .map_or_else(|| js_result_from("provedDescriptionsOutputDescription: index out of range"), Ok)?;
+
+    output_description.serialize().map_err(js_error_from)
+}
+
+#[wasm_bindgen(catch, js_name = "provedDescriptionsBindingSignature")]
+pub fn wasm_proved_descriptions_binding_signature(proved: u32, value_balance: &str, sighash: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let proved: &mut ProvedDescriptions = unsafe { js_dereference(proved) };
+    let value_balance: i64 = value_balance.parse().or_else(|_| js_result_from("provedDescriptionsBindingSignature: invalid value balance"))?;
+    let sighash: [u8; 32] = sighash.try_into().or_else(|_| js_result_from("provedDescriptionsBindingSignature: sighash must be an array of 32 bytes"))?;
+
+    let binding_sig = create_binding_sig(&mut proved.ctx, value_balance, sighash);
+
+    js_serialize_res(binding_sig)
+}
+
+#[wasm_bindgen(catch, js_name = "initCancellationToken")]
+pub fn wasm_init_cancellation_token() -> u32 {
+    wasm_init_lib();
+    js_reference(CancellationToken::new())
+}
+
+#[wasm_bindgen(catch, js_name = "cancelCancellationToken")]
+pub fn wasm_cancel_cancellation_token(cancellation_token: u32) {
+    wasm_init_lib();
+
+    let cancellation_token: &mut CancellationToken = unsafe { js_dereference(cancellation_token) };
+    cancellation_token.cancel();
+}
+
+#[wasm_bindgen(catch, js_name = "dropCancellationToken")]
+pub fn wasm_drop_cancellation_token(cancellation_token: u32) {
+    wasm_init_lib();
+    unsafe { js_drop_reference::<CancellationToken>(cancellation_token) }
+}
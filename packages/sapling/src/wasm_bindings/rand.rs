@@ -2,8 +2,10 @@ extern crate console_error_panic_hook;
 
 use wasm_bindgen::prelude::*;
 
-use crate::common::utils::wasm_utils::js_serialize;
-use crate::transaction::rand_scalar;
+use zcash_primitives::zip32::ExtendedSpendingKey;
+
+use crate::common::utils::wasm_utils::{js_deserialize, js_serialize};
+use crate::transaction::{derive_rand_scalar, DerivedRandKind, rand_scalar};
 use crate::wasm_bindings::init::wasm_init_lib;
 
 #[wasm_bindgen(catch, js_name = "randR")]
@@ -13,4 +15,29 @@ pub fn wasm_rand_r() -> Result<Vec<u8>, JsValue> {
     let scalar = rand_scalar();
 
     js_serialize(scalar)
-}
\ No newline at end of file
+}
+
+#[wasm_bindgen(catch, js_name = "deriveRcm")]
+pub fn wasm_derive_rcm(xsk: &[u8], context: &[u8], index: u32) -> Result<Vec<u8>, JsValue> {
+    wasm_derive_rand_scalar(DerivedRandKind::Rcm, xsk, context, index)
+}
+
+#[wasm_bindgen(catch, js_name = "deriveEsk")]
+pub fn wasm_derive_esk(xsk: &[u8], context: &[u8], index: u32) -> Result<Vec<u8>, JsValue> {
+    wasm_derive_rand_scalar(DerivedRandKind::Esk, xsk, context, index)
+}
+
+#[wasm_bindgen(catch, js_name = "deriveAr")]
+pub fn wasm_derive_ar(xsk: &[u8], context: &[u8], index: u32) -> Result<Vec<u8>, JsValue> {
+    wasm_derive_rand_scalar(DerivedRandKind::Ar, xsk, context, index)
+}
+
+fn wasm_derive_rand_scalar(kind: DerivedRandKind, xsk: &[u8], context: &[u8], index: u32) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let xsk: ExtendedSpendingKey = js_deserialize(xsk)?;
+
+    let scalar = derive_rand_scalar(&xsk, kind, context, index);
+
+    js_serialize(scalar)
+}
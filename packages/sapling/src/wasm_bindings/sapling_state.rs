@@ -0,0 +1,36 @@
+use wasm_bindgen::prelude::*;
+use zcash_proofs::ZcashParameters;
+
+use crate::common::utils::wasm_utils::{js_deserialize, js_error_from, js_serialize};
+use crate::State;
+use crate::transaction::{node_bytes, SaplingState, SaplingTransaction, verify_update};
+use crate::wasm_bindings::init::wasm_init_lib;
+
+#[wasm_bindgen(catch, js_name = "saplingStateEmpty")]
+pub fn wasm_sapling_state_empty(memo_size: u16) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    js_serialize(SaplingState::empty(memo_size))
+}
+
+#[wasm_bindgen(catch, js_name = "saplingStateRoot")]
+pub fn wasm_sapling_state_root(state: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let state: SaplingState = js_deserialize(state)?;
+
+    Ok(node_bytes(&state.root()).to_vec())
+}
+
+#[wasm_bindgen(catch, js_name = "saplingVerifyUpdate")]
+pub fn wasm_sapling_verify_update(state: &[u8], transaction: &[u8], anti_replay: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let state: SaplingState = js_deserialize(state)?;
+    let transaction: SaplingTransaction = js_deserialize(transaction)?;
+
+    let params: &ZcashParameters = State::proof_params().map_err(js_error_from)?;
+    let update = verify_update(&state, &transaction, anti_replay, params).map_err(js_error_from)?;
+
+    js_serialize(update.state)
+}
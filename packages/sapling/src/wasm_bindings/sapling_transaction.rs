@@ -0,0 +1,37 @@
+use wasm_bindgen::prelude::*;
+
+use crate::common::utils::wasm_utils::{js_deserialize, js_error_from, js_serialize};
+use crate::json::to_json;
+use crate::transaction::{KeyHash, SaplingTransaction, ShieldedPoolParameters, ShieldedTransfer};
+use crate::wasm_bindings::init::wasm_init_lib;
+
+#[wasm_bindgen(catch, js_name = "inspectSaplingTransaction")]
+pub fn wasm_inspect_sapling_transaction(transaction: &[u8]) -> Result<String, JsValue> {
+    wasm_init_lib();
+
+    let transaction: SaplingTransaction = js_deserialize(transaction)?;
+
+    to_json(&transaction).map_err(js_error_from)
+}
+
+#[wasm_bindgen(catch, js_name = "shieldedPoolParameters")]
+pub fn wasm_shielded_pool_parameters(transaction: &[u8], unshield_to: Option<String>) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let transaction: SaplingTransaction = js_deserialize(transaction)?;
+    let unshield_to: Option<KeyHash> = match unshield_to {
+        Some(unshield_to) => Some(unshield_to.parse().map_err(js_error_from)?),
+        None => None,
+    };
+
+    js_serialize(ShieldedPoolParameters { transfers: vec![ShieldedTransfer { transaction, unshield_to }] })
+}
+
+#[wasm_bindgen(catch, js_name = "inspectShieldedPoolParameters")]
+pub fn wasm_inspect_shielded_pool_parameters(parameters: &[u8]) -> Result<String, JsValue> {
+    wasm_init_lib();
+
+    let parameters: ShieldedPoolParameters = js_deserialize(parameters)?;
+
+    to_json(&parameters).map_err(js_error_from)
+}
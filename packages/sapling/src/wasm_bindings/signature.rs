@@ -3,8 +3,8 @@ use std::convert::TryInto;
 use wasm_bindgen::prelude::*;
 use zcash_proofs::sapling::SaplingProvingContext;
 
-use crate::common::utils::wasm_utils::{js_dereference, js_result_from, js_serialize_res};
-use crate::transaction::create_binding_sig;
+use crate::common::utils::wasm_utils::{js_dereference, js_deserialize, js_result_from, js_serialize, js_serialize_res};
+use crate::transaction::{create_binding_sig, create_binding_sig_with_bsk};
 use crate::wasm_bindings::init::wasm_init_lib;
 
 #[wasm_bindgen(catch, js_name = "bindingSignature")]
@@ -19,4 +19,16 @@ pub fn wasm_binding_signature(ctx: u32, value_balance: &str, sighash: &[u8]) ->
     let binding_sig = create_binding_sig(ctx, value_balance, sighash);
 
     js_serialize_res(binding_sig)
-}
\ No newline at end of file
+}
+#[wasm_bindgen(catch, js_name = "bindingSignatureWithBsk")]
+pub fn wasm_binding_signature_with_bsk(bsk: &[u8], sighash: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let bsk: jubjub::Scalar = js_deserialize(bsk)?;
+    let sighash: [u8; 32] = sighash.try_into()
+        .or_else(|_| js_result_from("bindingSignatureWithBsk: sighash must be an array of 32 bytes"))?;
+
+    let binding_sig = create_binding_sig_with_bsk(&bsk, sighash);
+
+    js_serialize(binding_sig)
+}
@@ -2,17 +2,28 @@ use std::convert::TryInto;
 
 use bellman::groth16::{Parameters, PreparedVerifyingKey};
 use bls12_381::Bls12;
+use js_sys::{Function, Uint8Array};
 use wasm_bindgen::prelude::*;
 use zcash_primitives::merkle_tree::MerklePath;
 use zcash_primitives::primitives::{PaymentAddress, ProofGenerationKey};
+use zcash_primitives::redjubjub::Signature;
 use zcash_primitives::sapling::Node;
 use zcash_primitives::zip32::ExtendedSpendingKey;
 use zcash_proofs::sapling::SaplingProvingContext;
 use zcash_proofs::ZcashParameters;
 
+use crate::common::errors::{CausedBy, SaplingError};
 use crate::common::utils::wasm_utils::{js_dereference, js_deserialize, js_error_from, js_result_from, js_serialize_res};
 use crate::State;
-use crate::transaction::{prepare_spend_description, sign_spend_description, SpendDetails, SpendParameters, UnsignedSpendDescription};
+use crate::transaction::{
+    LocalSpendAuthSigner,
+    prepare_spend_description,
+    sign_spend_description,
+    SpendAuthSigner,
+    SpendDetails,
+    SpendParameters,
+    UnsignedSpendDescription,
+};
 use crate::wasm_bindings::init::wasm_init_lib;
 
 #[allow(clippy::too_many_arguments)]
@@ -108,7 +119,56 @@ pub fn wasm_sign_spend_description_with_xsk(spend_description: &[u8], xsk: &[u8]
     let sighash: [u8; 32] = sighash.try_into()
         .or_else(|_| js_result_from("signSpendDescriptionWithXsk: sighash must be an array of 32 bytes"))?;
 
-    let spend_description = sign_spend_description(spend_description, xks, ar, sighash);
+    let spend_description = sign_spend_description(spend_description, &xks, ar, sighash);
+
+    js_serialize_res(spend_description)
+}
+
+#[wasm_bindgen(catch, js_name = "signSpendDescriptionWithAsk")]
+pub fn wasm_sign_spend_description_with_ask(spend_description: &[u8], ask: &[u8], ar: &[u8], sighash: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let spend_description: UnsignedSpendDescription = js_deserialize(spend_description)?;
+    let ask: jubjub::Scalar = js_deserialize(ask)?;
+    let ar: jubjub::Scalar = js_deserialize(ar)?;
+
+    let sighash: [u8; 32] = sighash.try_into()
+        .or_else(|_| js_result_from("signSpendDescriptionWithAsk: sighash must be an array of 32 bytes"))?;
+
+    let spend_description = sign_spend_description(spend_description, &LocalSpendAuthSigner::new(ask), ar, sighash);
+
+    js_serialize_res(spend_description)
+}
+
+struct FunctionSpendAuthSigner {
+    sign: Function,
+}
+
+impl SpendAuthSigner for FunctionSpendAuthSigner {
+    fn sign_spend_auth(&self, ar: jubjub::Scalar, sighash: [u8; 32]) -> Result<Signature, SaplingError> {
+        let ar = Uint8Array::from(&ar.to_bytes()[..]);
+        let sighash = Uint8Array::from(&sighash[..]);
+
+        let signature = self.sign.call2(&JsValue::NULL, &ar, &sighash)
+            .map_err(|err| SaplingError::caused_by(err.as_string().unwrap_or_else(|| String::from("signSpendDescriptionWithSigner: the signer failed"))))?;
+        let signature = Uint8Array::new(&signature).to_vec();
+
+        Signature::read(&signature[..]).map_err(|err| SaplingError::caused_by(err.to_string()))
+    }
+}
+
+// `sign` is called with `ar` and the sighash and must synchronously return the 64 byte signature.
+#[wasm_bindgen(catch, js_name = "signSpendDescriptionWithSigner")]
+pub fn wasm_sign_spend_description_with_signer(spend_description: &[u8], sign: Function, ar: &[u8], sighash: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let spend_description: UnsignedSpendDescription = js_deserialize(spend_description)?;
+    let ar: jubjub::Scalar = js_deserialize(ar)?;
+
+    let sighash: [u8; 32] = sighash.try_into()
+        .or_else(|_| js_result_from("signSpendDescriptionWithSigner: sighash must be an array of 32 bytes"))?;
+
+    let spend_description = sign_spend_description(spend_description, &FunctionSpendAuthSigner { sign }, ar, sighash);
 
     js_serialize_res(spend_description)
 }
\ No newline at end of file
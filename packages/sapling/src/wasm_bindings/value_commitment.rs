@@ -0,0 +1,62 @@
+use wasm_bindgen::prelude::*;
+
+use crate::common::utils::wasm_utils::{js_deserialize, js_error_from, js_result_from, js_serialize, js_serialize_res};
+use crate::transaction::{
+    compute_bsk,
+    compute_bvk,
+    compute_value_commitment,
+    sum_trapdoors,
+    sum_value_commitments,
+    trapdoors_from_bytes,
+    value_commitments_from_bytes,
+};
+use crate::wasm_bindings::init::wasm_init_lib;
+
+#[wasm_bindgen(catch, js_name = "computeValueCommitment")]
+pub fn wasm_compute_value_commitment(value: &str, rcv: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let value: u64 = value.parse().or_else(|_| js_result_from("computeValueCommitment: invalid value"))?;
+    let rcv: jubjub::Scalar = js_deserialize(rcv)?;
+
+    js_serialize(compute_value_commitment(value, rcv))
+}
+
+#[wasm_bindgen(catch, js_name = "sumValueCommitments")]
+pub fn wasm_sum_value_commitments(cvs: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let cvs = value_commitments_from_bytes(cvs).map_err(js_error_from)?;
+
+    js_serialize(sum_value_commitments(&cvs))
+}
+
+#[wasm_bindgen(catch, js_name = "sumTrapdoors")]
+pub fn wasm_sum_trapdoors(rcvs: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let rcvs = trapdoors_from_bytes(rcvs).map_err(js_error_from)?;
+
+    js_serialize(sum_trapdoors(&rcvs))
+}
+
+#[wasm_bindgen(catch, js_name = "computeBsk")]
+pub fn wasm_compute_bsk(spend_rcvs: &[u8], output_rcvs: &[u8]) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let spend_rcvs = trapdoors_from_bytes(spend_rcvs).map_err(js_error_from)?;
+    let output_rcvs = trapdoors_from_bytes(output_rcvs).map_err(js_error_from)?;
+
+    js_serialize(compute_bsk(&spend_rcvs, &output_rcvs))
+}
+
+#[wasm_bindgen(catch, js_name = "computeBvk")]
+pub fn wasm_compute_bvk(spend_cvs: &[u8], output_cvs: &[u8], value_balance: &str) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    let spend_cvs = value_commitments_from_bytes(spend_cvs).map_err(js_error_from)?;
+    let output_cvs = value_commitments_from_bytes(output_cvs).map_err(js_error_from)?;
+    let value_balance: i64 = value_balance.parse().or_else(|_| js_result_from("computeBvk: invalid value_balance"))?;
+
+    js_serialize_res(compute_bvk(&spend_cvs, &output_cvs, value_balance))
+}
@@ -4,7 +4,7 @@ use wasm_bindgen::prelude::*;
 use zcash_primitives::zip32::{ExtendedFullViewingKey, ExtendedSpendingKey};
 
 use crate::common::utils::wasm_utils::{js_deserialize, js_serialize, js_serialize_res};
-use crate::key::{crh_ivk, SaplingKey};
+use crate::key::{crh_ivk, IncomingViewingKey, SaplingKey};
 use crate::wasm_bindings::init::wasm_init_lib;
 
 #[wasm_bindgen(catch, js_name = "xfvk")]
@@ -43,4 +43,20 @@ pub fn wasm_xfvk_to_ivk(xfvk: &[u8]) -> Result<Vec<u8>, JsValue> {
     let ivk = crh_ivk(&xfvk);
 
     Ok(ivk)
+}
+
+#[wasm_bindgen(catch, js_name = "ivkToBech32")]
+pub fn wasm_ivk_to_bech32(ivk: &[u8]) -> Result<String, JsValue> {
+    wasm_init_lib();
+
+    let ivk: IncomingViewingKey = js_deserialize(ivk)?;
+
+    Ok(ivk.encode())
+}
+
+#[wasm_bindgen(catch, js_name = "ivkFromBech32")]
+pub fn wasm_ivk_from_bech32(encoded: &str) -> Result<Vec<u8>, JsValue> {
+    wasm_init_lib();
+
+    js_serialize_res(IncomingViewingKey::decode(encoded))
 }
\ No newline at end of file